@@ -26,4 +26,6 @@ pub enum AllovrError {
     StakePoolRebalanceRequired,
     #[msg("Stake already initialised")]
     StakeAlreadyInitialised,
+    #[msg("Invalid ALLOVR Mint Authority")]
+    InvalidMintAuthority,
 }
@@ -1,6 +1,7 @@
 mod constants;
 mod errors;
 mod known_addresses;
+pub mod pda;
 mod processors;
 mod state;
 mod utils;
@@ -0,0 +1,14 @@
+//! Program derived addresses of the AOVR program, shared by its processors and by clients.
+//! Account constraints have to spell their seeds out as array literals, so they are built
+//! from the same prefixes in constants.
+
+use crate::constants::ALLOVR_MINT_SEED_PREFIX;
+use anchor_lang::prelude::Pubkey;
+
+pub fn allovr_mint_authority_seeds(bump: &[u8]) -> [&[u8]; 2] {
+    [ALLOVR_MINT_SEED_PREFIX.as_bytes(), bump]
+}
+
+pub fn find_allovr_mint_authority(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ALLOVR_MINT_SEED_PREFIX.as_bytes()], program_id)
+}
@@ -7,6 +7,7 @@ use std::str::FromStr;
 use crate::constants::{ALLOVR_MINT_SEED_PREFIX, ALLOVR_AOVR_DECIMAL_PLACES};
 use crate::errors::AllovrError;
 use crate::known_addresses::{ALLOVR_STATE_ID, ALLOVR_MINT_ID, KnownAddress};
+use crate::pda;
 use crate::state::{ InitAovrArgs, AllovrTokenState};
 use anchor_spl::token::{ Token, Mint };
 
@@ -71,12 +72,10 @@ pub fn handle_initialise_aovr(
         current_authority: ctx.accounts.initialiser.to_account_info(),
     };
 
-    let seeds = vec![ALLOVR_MINT_SEED_PREFIX.as_bytes()];
-    let seeds = vec![seeds.as_slice()];
-    let seeds = seeds.as_slice();
-
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds);
-    set_authority(cpi_ctx, AuthorityType::MintTokens, Some(ctx.accounts.mint_authority.key()))?;
+    // the initialiser is still the authority and signs, the PDA only takes over
+    let (mint_authority, _bump) = pda::find_allovr_mint_authority(ctx.program_id);
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    set_authority(cpi_ctx, AuthorityType::MintTokens, Some(mint_authority))?;
 
     Ok(())
 }
\ No newline at end of file
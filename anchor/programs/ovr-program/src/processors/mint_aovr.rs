@@ -1,8 +1,7 @@
-use crate::constants::{
-    ALLOVR_AOVR_DECIMAL_PLACES, ALLOVR_MINT_SEED_PREFIX, INFLATION_INTERVAL_IN_SECONDS,
-};
+use crate::constants::{ALLOVR_AOVR_DECIMAL_PLACES, INFLATION_INTERVAL_IN_SECONDS};
 use crate::errors::AllovrError;
 use crate::known_addresses::KnownAddress;
+use crate::pda;
 use crate::state::{AllovrTokenState, InitAovrArgs};
 use anchor_lang::prelude::*;
 use anchor_spl::token::spl_token::instruction::AuthorityType;
@@ -21,7 +20,7 @@ pub struct MintAovr<'info> {
     aovr_state: Account<'info, AllovrTokenState>,
     #[account(mut, address = KnownAddress::allovr_mint(), mint::authority = mint_authority)]
     aovr_mint: Account<'info, Mint>,
-    #[account(seeds = [ALLOVR_MINT_SEED_PREFIX.as_ref()], bump)]
+    // checked against pda::find_allovr_mint_authority, which also gives the signing bump
     mint_authority: Account<'info, Auth>,
     #[account(mut, token::mint = KnownAddress::allovr_mint())]
     aovr_treasury: Account<'info, TokenAccount>,
@@ -33,6 +32,13 @@ pub struct MintAovr<'info> {
 }
 
 pub fn handle_mint_aovr(ctx: Context<MintAovr>) -> Result<()> {
+    let (mint_authority, bump) = pda::find_allovr_mint_authority(ctx.program_id);
+    require_keys_eq!(
+        ctx.accounts.mint_authority.key(),
+        mint_authority,
+        AllovrError::InvalidMintAuthority
+    );
+
     let aovr_state = ctx.accounts.aovr_state.borrow_mut();
 
     aovr_state.minted = true;
@@ -45,7 +51,11 @@ pub fn handle_mint_aovr(ctx: Context<MintAovr>) -> Result<()> {
         authority: ctx.accounts.mint_authority.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+    // the mint authority is a PDA, the program signs for it
+    let bump = [bump];
+    let seeds = pda::allovr_mint_authority_seeds(&bump);
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &[&seeds[..]]);
     mint_to(cpi_ctx, 100_000_000)?;
 
     Ok(())
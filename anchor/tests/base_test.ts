@@ -3,6 +3,7 @@ import { Program } from "@project-serum/anchor";
 import { createAssociatedTokenAccountInstruction } from "@solana/spl-token";
 import { OvrProgram } from "../target/types/ovr_program";
 import {
+  ALLOVR_MINT_SEED_PREFIX,
   FOUNDER_1,
   FOUNDER_2,
   FOUNDER_3,
@@ -62,6 +63,10 @@ export const getPda = async (
   return stakePoolRegistryPda;
 };
 
+/** Mint authority PDA, derived as pda::find_allovr_mint_authority does in the program */
+export const findAllovrMintAuthority = async (): Promise<anchor.web3.PublicKey> =>
+  getPda([Buffer.from(ALLOVR_MINT_SEED_PREFIX)]);
+
 export const awaitTransaction = async (
   txSignature: string
 ): Promise<anchor.web3.RpcResponseAndContext<anchor.web3.SignatureResult>> => {
@@ -1,17 +1,15 @@
 import * as anchor from "@project-serum/anchor";
-import { utf8 } from "@project-serum/anchor/dist/cjs/utils/bytes";
 import { expect } from "chai";
 import { allovrMintKey, allovrStateKey } from "../test-keys/test-keys";
 import {
   awaitTransaction,
+  findAllovrMintAuthority,
   getFounders,
-  getPda,
   getProgram,
   getRandomPayer,
 } from "../base_test";
 import {
   ALLOVR_AOVR_DECIMAL_PLACES,
-  ALLOVR_MINT_SEED_PREFIX,
   COST_INIT_AOVR,
 } from "../constants";
 import { getMint, TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -29,9 +27,7 @@ const tryInitialise = async (): Promise<{
   const allovrMintKeypair = allovrMintKey();
 
   try {
-    const mintAuthorityPda = await getPda([
-      utf8.encode(ALLOVR_MINT_SEED_PREFIX),
-    ]);
+    const mintAuthorityPda = await findAllovrMintAuthority();
 
     const txSignature = await program.methods
       .initialiseAovr(getFounders())
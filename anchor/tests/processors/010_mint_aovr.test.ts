@@ -1,5 +1,4 @@
 import * as anchor from "@project-serum/anchor";
-import { utf8 } from "@project-serum/anchor/dist/cjs/utils/bytes";
 import { expect } from "chai";
 import {
   allovrAovrTreasury,
@@ -9,13 +8,13 @@ import {
 } from "../test-keys/test-keys";
 import {
   awaitTransaction,
+  findAllovrMintAuthority,
   getFounders,
-  getPda,
   getProgram,
   getRandomPayer,
   initialiseAllovrTreasury,
 } from "../base_test";
-import { ALLOVR_MINT_SEED_PREFIX, COST_INIT_AOVR } from "../constants";
+import { COST_INIT_AOVR } from "../constants";
 import {
   createAssociatedTokenAccountInstruction,
   createInitializeMintInstruction,
@@ -35,9 +34,7 @@ const tryMint = async (): Promise<{
   const allovrAovrTreasuryAta = await allovrAovrTreasury();
 
   try {
-    const mintAuthorityPda = await getPda([
-      utf8.encode(ALLOVR_MINT_SEED_PREFIX),
-    ]);
+    const mintAuthorityPda = await findAllovrMintAuthority();
 
     const txSignature = await program.methods
       .mintAovr()
@@ -48,6 +48,26 @@ pub enum AllovrError {
     InvalidArtworkSymbol,
     #[error("Artwork Description Max Length Exceeded")]
     ArtworkDescriptionMaxLengthExceeded,
+    #[error("Invalid Artwork Name")]
+    InvalidArtworkName,
+    #[error("Invalid Artwork Uri")]
+    InvalidArtworkUri,
+    #[error("Invalid Seller Fee Basis Points")]
+    InvalidSellerFeeBasisPoints,
+    #[error("Invalid Max Editions")]
+    InvalidMaxEditions,
+    #[error("Max Editions Reached")]
+    MaxEditionsReached,
+    #[error("Artwork Is Print")]
+    ArtworkIsPrint,
+    #[error("Invalid Artwork Content Uri")]
+    InvalidArtworkContentUri,
+    #[error("Invalid Artwork Content Hash")]
+    InvalidArtworkContentHash,
+    #[error("Invalid Artwork Creators")]
+    InvalidArtworkCreators,
+    #[error("Incorrect Creator Payment Account")]
+    IncorrectCreatorPaymentAccount,
 
     // Artist
     #[error("Invalid Artist Name")]
@@ -59,6 +79,16 @@ pub enum AllovrError {
     #[error("Invalid Artist Uri")]
     InvalidArtistUri,
 
+    // Artist Collection
+    #[error("Invalid Collection Symbol")]
+    InvalidCollectionSymbol,
+    #[error("Invalid Collection Name")]
+    InvalidCollectionName,
+    #[error("Invalid Collection Uri")]
+    InvalidCollectionUri,
+    #[error("Missing Collection Accounts")]
+    MissingCollectionAccounts,
+
     #[error("Artwork Under Offer")]
     ArtworkUnderOffer,
     #[error("Incorrect Artwork Buyer")]
@@ -69,6 +99,70 @@ pub enum AllovrError {
     IncorrectArtworkHolder,
     #[error("Invalid Offer")]
     InvalidOffer,
+    #[error("Invalid Dutch Auction")]
+    InvalidDutchAuction,
+    #[error("Dutch Auction Not Started")]
+    DutchAuctionNotStarted,
+    #[error("Price Above Max Price")]
+    PriceAboveMaxPrice,
+    #[error("Invalid Artwork Swap")]
+    InvalidArtworkSwap,
+    #[error("Invalid Artwork Bundle")]
+    InvalidArtworkBundle,
+    #[error("Invalid Artwork Vault")]
+    InvalidArtworkVault,
+    #[error("Artwork Vault Not Active")]
+    ArtworkVaultNotActive,
+    #[error("Artwork Vault Not Bought Out")]
+    ArtworkVaultNotBoughtOut,
+    #[error("Insufficient Fractions")]
+    InsufficientFractions,
+    #[error("Invalid Bonding Curve")]
+    InvalidBondingCurve,
+    #[error("Price Below Min Price")]
+    PriceBelowMinPrice,
+    #[error("Invalid Revenue Share")]
+    InvalidRevenueShare,
+    #[error("Insufficient Stake")]
+    InsufficientStake,
+    #[error("Invalid Artist Token Allocation")]
+    InvalidArtistTokenAllocation,
+    #[error("Invalid Vesting Schedule")]
+    InvalidVestingSchedule,
+    #[error("Nothing Vested")]
+    NothingVested,
+    #[error("Insufficient Market Inventory")]
+    InsufficientMarketInventory,
+    #[error("Invalid Airdrop")]
+    InvalidAirdrop,
+    #[error("Invalid Merkle Proof")]
+    InvalidMerkleProof,
+    #[error("Airdrop Already Claimed")]
+    AirdropAlreadyClaimed,
+    #[error("Artist Inactive")]
+    ArtistInactive,
+    #[error("Invalid DAO Authority")]
+    InvalidDaoAuthority,
+    #[error("Verified Artist Name Locked")]
+    VerifiedArtistNameLocked,
+    #[error("Artist Symbol Taken")]
+    ArtistSymbolTaken,
+    #[error("Artist Symbol Locked")]
+    ArtistSymbolLocked,
+    #[error("Invalid Artist Profile")]
+    InvalidArtistProfile,
+    #[error("Invalid Account Key")]
+    InvalidAccountKey,
+    #[error("Account Needs Migration")]
+    AccountNeedsMigration,
+    #[error("Account Already Migrated")]
+    AccountAlreadyMigrated,
+    #[error("Account Not Writable")]
+    AccountNotWritable,
+    #[error("Missing Artist Wallet")]
+    MissingArtistWallet,
+    #[error("Insufficient Community Tokens")]
+    InsufficientCommunityTokens,
     #[error("Inflation Not Due")]
     InflationNotDue,
     #[error("Incorrect founder address")]
@@ -1,7 +1,22 @@
+use std::str::FromStr;
+
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use mpl_token_metadata::pda::{
+    find_edition_account, find_master_edition_account, find_metadata_account,
+};
+use mpl_token_metadata::state::EDITION_MARKER_BIT_SIZE;
+use solana_program::clock::UnixTimestamp;
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+use spl_associated_token_account::get_associated_token_address;
 
-use crate::state::Currency;
+use crate::pda::*;
+use crate::state::{
+    ArtistLink, ArtworkBundle, ArtworkCreator, ArtworkMetadata, ArtworkSwap, ArtworkSwapTopUp,
+    ArtworkVault, BondingCurve, Currency,
+};
+use crate::*;
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
@@ -14,6 +29,19 @@ pub struct InitialisaAllovrArgs {
     pub founder_6: Pubkey,
     pub founder_7: Pubkey,
     pub founder_8: Pubkey,
+    pub dao_authority: Pubkey, // DAO governance, verifies artists
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct SetDaoAuthorityArgs {
+    pub dao_authority: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct DistributeArtistCommunityTokensArgs {
+    pub amount: u64,
 }
 
 #[repr(C)]
@@ -24,6 +52,101 @@ pub struct RegisterArtistArgs {
     pub token_symbol: String,
     pub uri: Option<String>,
     pub create_metaplex_metadata: Option<bool>,
+    pub bonding_curve: BondingCurve,
+    pub token_supply: u64, // in base units
+    pub token_decimals: u8,
+    pub allocation: ArtistTokenAllocation,
+    pub vesting: ArtistVestingArgs,
+}
+
+/// Artist details UpdateArtist can change, the token set up at registration is fixed
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct UpdateArtistArgs {
+    pub name: String,
+    pub description: String,
+    pub token_symbol: String,
+    pub uri: Option<String>,
+    pub create_metaplex_metadata: Option<bool>,
+    pub profile: Option<ArtistProfileArgs>, // creates or replaces the artist profile
+}
+
+/// Split of the artist token supply, in basis points summing to 10000. Rounding dust goes to
+/// the community treasury.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtistTokenAllocation {
+    pub artist_basis_points: u16,
+    pub community_basis_points: u16,
+    pub bonding_curve_basis_points: u16,
+}
+
+/// Artist allocation vests linearly over duration_seconds from registration, with nothing
+/// claimable before cliff_seconds
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtistVestingArgs {
+    pub cliff_seconds: i64,
+    pub duration_seconds: i64,
+}
+
+/// Artist profile extension, see ArtistProfile
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtistProfileArgs {
+    pub links: Vec<ArtistLink>,
+    pub avatar_uri: Option<String>,
+    pub banner_uri: Option<String>,
+    pub genres: Vec<String>,
+    pub location: Option<String>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct BuyArtistTokensArgs {
+    pub amount: u64,
+    pub max_cost: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct SellArtistTokensArgs {
+    pub amount: u64,
+    pub min_return: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct SetArtistRevenueShareArgs {
+    pub share_basis_points: u16,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtistStakeArgs {
+    pub amount: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct AirdropArtistTokensArgs {
+    pub amounts: Vec<u64>, // one per recipient, in recipient account order
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct CreateArtistAirdropArgs {
+    pub airdrop_id: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ClaimArtistAirdropArgs {
+    pub index: u64,
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
 }
 
 #[repr(C)]
@@ -35,15 +158,79 @@ pub struct InitArtworkSaleArgs {
     pub payment_account: Pubkey,
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct InitArtworkDutchAuctionArgs {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub currency: Currency,
+    pub payment_account: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct AcceptArtworkSaleArgs {
+    pub max_price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct InitArtworkBundleSaleArgs {
+    pub buyer: Option<Pubkey>,
+    pub amount: u64,
+    pub currency: Currency,
+    pub payment_account: Pubkey,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct FractionaliseArtworkArgs {
+    pub fraction_supply: u64,
+    pub reserve_price: u64,
+    pub currency: Currency,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ProposeArtworkSwapArgs {
+    pub top_up: Option<ArtworkSwapTopUp>,
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
 pub struct CreateArtworkArgs {
     pub symbol: String,
     pub description: String,
+    pub name: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub collection_symbol: Option<String>,
+    pub max_editions: u64,
+    pub content_uri: String,
+    pub content_hash: [u8; 32],
+    pub creators: Vec<ArtworkCreator>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct UpdateArtworkMetadataArgs {
+    pub description: String,
+    pub content_uri: String,
+    pub content_hash: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct CreateArtistCollectionArgs {
+    pub symbol: String,
+    pub name: String,
+    pub uri: String,
 }
 
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, BorshSchema)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub enum AllovrInstruction {
     /// Initialise ALLOVR Token
     ///
@@ -86,9 +273,17 @@ pub enum AllovrInstruction {
     /// Summary: Any account can register as artist exactly once.
     /// 1. 10 000 AOVR is transferred to ALLOVR DAO Treasury
     /// 2. Artist token mint account is created with PDA seeds [ALLOVRARTIST, artist account address] and initialised
-    /// 3. 10 000 000 artist tokens are minted to artist account
+    /// 3. token_supply artist tokens (token_decimals decimals) are minted and split by allocation between
+    ///    the artist vesting PDA [ALLOVRARTISTVESTING, artist token mint address], the community treasury
+    ///    PDA [ALLOVRARTISTCOMMUNITY, artist token mint address] and the artist market inventory. The
+    ///    artist's allocation vests linearly (see ClaimVestedArtistTokens), the DAO pays out the
+    ///    community treasury (see DistributeArtistCommunityTokens)
     /// 4. Artist metadata account is created with PDA seeds [ALLOVRARTISTMETA, artist token mint account address]
     /// 5. Meta data args (see RegisterArtistArgs) are saved to metadata data account
+    /// 6. Artist market account is created with PDA seeds [ALLOVRARTISTMARKET, artist token mint address]
+    ///    holding the bonding curve, with an AOVR reserve ATA owned by the market PDA
+    /// 7. The token symbol is reserved with PDA seeds [ALLOVRARTISTSYMBOL, uppercase symbol], a symbol
+    ///    already held by another artist is rejected
     ///
     /// Accounts expected:
     ///
@@ -98,25 +293,2245 @@ pub enum AllovrInstruction {
     /// `[writable]` Artist Token Mint PDA with seeds [ALLOVRARTIST, artist account address]
     /// `[writable]` Artist Token Metadata PDA with seeds [ALLOVRARTISTMETA, artist token mint address]        
     /// `[writable]` Treasury AOVR Token Account (destincation for 10K AOVR)
+    /// `[writable]` Artist Token Metaplex Metadata Account
+    /// `[]` Metaplex Token Metadata Program
     /// `[]` Token Program
     /// `[]` ATA Program
     /// `[]` Rent Sysvar    
     /// `[]` System
+    /// `[writable]` Artist Market PDA with seeds [ALLOVRARTISTMARKET, artist token mint address]
+    /// `[writable]` Artist Market AOVR Reserve ATA
+    /// `[]` AOVR Mint
+    /// `[writable]` Artist Market Artist Token ATA (bonding curve inventory)
+    /// `[writable]` Artist Vesting PDA with seeds [ALLOVRARTISTVESTING, artist token mint address]
+    /// `[writable]` Artist Vesting Artist Token ATA
+    /// `[]` Artist Community Treasury PDA with seeds [ALLOVRARTISTCOMMUNITY, artist token mint address]
+    /// `[writable]` Artist Community Treasury Artist Token ATA
+    /// `[writable]` Artist Symbol PDA with seeds [ALLOVRARTISTSYMBOL, uppercase token symbol]
     RegisterArtist(RegisterArtistArgs),
     /// Update Artist Info
     ///
+    /// A verified artist cannot change their name, the DAO has to revoke and verify again.
+    /// The token symbol is registered to the artist and can only change case.
+    /// When profile is passed the artist profile PDA [ALLOVRARTISTPROFILE, artist token mint address]
+    /// is created, or replaced if it exists.
+    ///
     /// Accounts expected:
     ///
-    /// `[signer]` Artist Wallet
+    /// `[signer,writable]` Artist Wallet, payer
+    /// `[]` Artist Token Mint PDA
     /// `[writable]` Artist Metadata Account to be updated (PDA with seed prefix ALLOVRARTISTMETA and Artist Token Mint address)   
-    UpdateArtist(RegisterArtistArgs),
+    /// `[writable]` Artist Token Metaplex Metadata Account
+    /// `[]` Metaplex Token Metadata Program
+    /// `[]` System
+    /// `[writable]` Artist Profile PDA (optional, required when profile is passed)
+    UpdateArtist(UpdateArtistArgs),
     /// Create Artwork
     ///
-    /// Accounts exepected:
+    /// Summary: A registered artist creates a unique artwork token.
+    /// 1. 1000 AOVR is transferred to ALLOVR DAO Treasury
+    /// 2. Artwork mint account is created with PDA seeds [ALLOVRARTWORK, artwork symbol, artist account address] and initialised
+    /// 3. 1 artwork token is minted to the artist account
+    /// 4. Metaplex metadata (name, symbol, uri, royalties, artist as verified creator) and master edition
+    ///    (with max_editions numbered prints allowed, 0 for a unique work) are created
+    /// 5. If a collection symbol is passed, the artwork joins the artist's collection and is verified
+    /// 6. Artwork metadata account is created with PDA seeds [ALLOVRARTWORKMETA, artwork mint address],
+    ///    recording the content URI (Arweave / IPFS) and SHA-256 hash of the artwork file
     ///
+    /// Collaborations: creators lists every creator with their share (summing to 100) and must
+    /// include the artist. Co-creators sign the transaction and are verified in Metaplex. Primary
+    /// sale proceeds and royalties are split by share. An empty list means the artist alone.
     ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Account, payer
+    /// `[writable]` Artist AOVR Token ATA
+    /// `[writable]` Artist's Artwork Token ATA (based on Artwork Mint PDA below)
+    /// `[writable]` Artwork Mint PDA with seeds [ALLOVRARTWORK, artwork symbol, artist account address]
+    /// `[writable]` Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, artwork mint address]
+    /// `[writable]` Treasury AOVR Token Account (destination for 1K AOVR)
+    /// `[]` Token Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[]` ATA Program
+    /// `[]` Metaplex Token Metadata Program
+    /// `[writable]` Artwork Metaplex Metadata PDA
+    /// `[writable]` Artwork Metaplex Master Edition PDA
+    /// `[]` (optional, with collection symbol) Collection Mint PDA with seeds [ALLOVRARTISTCOLLECTION, collection symbol, artist token mint address]
+    /// `[]` (optional, with collection symbol) Collection Metaplex Metadata PDA
+    /// `[]` (optional, with collection symbol) Collection Metaplex Master Edition PDA
+    /// `[signer]` Co-creator Wallet, one per creator other than the artist, in creators order
     CreateArtwork(CreateArtworkArgs),
+    /// Init Artwork Sale
+    ///
+    /// Summary: The holder puts the artwork in escrow and offers it to the buyer for amount in
+    /// the given currency. The escrow PDA is derived from the new offer count, so every offer
+    /// gets a fresh escrow. Accepted with AcceptArtworkSale.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artwork Holder Wallet, payer
+    /// `[writable]` Artwork Holder Artwork Token Account
+    /// `[writable]` Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, artwork mint address]
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Escrow PDA with seeds [ALLOVRARTWORKESCROW, artwork symbol, offer count, artist account address]
+    ///   (prints use [ALLOVRARTWORKESCROW, edition number, offer count, master artwork mint address])
+    /// `[]` Token Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
     InitArtworkSale(InitArtworkSaleArgs),
+    /// Cancel Artwork Sale
+    ///
+    /// Summary: The holder takes the artwork back from escrow and the offer is cleared. The
+    /// escrow is closed and its rent returned to the holder.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artwork Holder Wallet
+    /// `[writable]` Artwork Holder Artwork Token Account
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[]` Token Program
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
     CancelArtworkSale(),
-    AcceptArtworkSale(),
+    /// Accept Artwork Sale
+    ///
+    /// Summary: The buyer pays the offer price and receives the artwork from escrow.
+    /// For Dutch auctions the price is taken from the clock at execution. The sale
+    /// fails if the price is above the buyer's max price.
+    /// Creators are paid their share first (the whole price on the primary sale, the
+    /// seller fee on later sales) and the seller receives the rest. An artwork gifted,
+    /// swapped or redeemed to a non-creator counts as sold, later sales are secondary.
+    /// If the artist has opted in to revenue sharing, that share of the artist's payout
+    /// goes to the artist revenue vault.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Buyer Wallet
+    /// `[writable]` Buyer Artwork Token Account
+    /// `[writable]` Buyer Payment Account (wallet for SOL, AOVR token account for AOVR)
+    /// `[writable]` Artwork Holder Wallet
+    /// `[writable]` Seller Payment Account
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[]` Token Program
+    /// `[]` System
+    /// `[writable]` Artist Revenue Vault PDA with seeds [ALLOVRARTISTREVENUE, artist token mint address] (may be uninitialised)
+    /// `[writable]` Revenue Vault AOVR ATA (ignored for SOL)
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
+    /// `[writable]` Creator Payment Account, one per artwork creator in creators order
+    AcceptArtworkSale(AcceptArtworkSaleArgs),
+    /// Transfer Artwork
+    ///
+    /// Summary: The holder moves the artwork token to another wallet (e.g. as a gift) and
+    /// the artwork metadata holder is updated in the same instruction. The recipient
+    /// artwork ATA is created if it does not exist yet.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artwork Holder Wallet, payer
+    /// `[writable]` Artwork Holder Artwork ATA
+    /// `[]` Recipient Wallet
+    /// `[writable]` Recipient Artwork ATA
+    /// `[]` Artwork Mint PDA with seeds [ALLOVRARTWORK, artwork symbol, artist account address]
+    /// `[writable]` Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, artwork mint address]
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    TransferArtwork(),
+    /// Sync Artwork Holder
+    ///
+    /// Summary: Sets the artwork metadata holder to the wallet that actually owns the artwork
+    /// token, e.g. after a plain SPL token transfer. Can be called by anyone.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[]` Holder Wallet (owner of the token account below)
+    /// `[]` Holder Artwork Token Account (must hold the artwork token)
+    /// `[]` Artwork Mint PDA with seeds [ALLOVRARTWORK, artwork symbol, artist account address]
+    /// `[writable]` Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, artwork mint address]
+    SyncArtworkHolder(),
+    /// Create Artist Collection
+    ///
+    /// Summary: A registered artist creates a Metaplex collection NFT that artworks can join.
+    /// The collection mint PDA is the collection update authority, so artworks created by the
+    /// artist can be verified as part of the collection by the program.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Account, payer
+    /// `[]` Artist Token Mint PDA with seeds [ALLOVRARTIST, artist account address]
+    /// `[writable]` Artist's Collection Token ATA (based on Collection Mint PDA below)
+    /// `[writable]` Collection Mint PDA with seeds [ALLOVRARTISTCOLLECTION, collection symbol, artist token mint address]
+    /// `[writable]` Collection Metaplex Metadata PDA
+    /// `[writable]` Collection Metaplex Master Edition PDA
+    /// `[]` Metaplex Token Metadata Program
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    CreateArtistCollection(CreateArtistCollectionArgs),
+    /// Mint Artwork Print
+    ///
+    /// Summary: The artist, while holding the master artwork, mints the next numbered print.
+    /// 1. Print mint account is created with PDA seeds [ALLOVRARTWORKPRINT, master artwork mint address, edition number] and 1 token is minted to the artist
+    /// 2. Metaplex edition is printed from the master edition (fails once max editions are reached)
+    /// 3. Print artwork metadata account is created with PDA seeds [ALLOVRARTWORKMETA, print mint address], linking to the master
+    ///
+    /// Prints are sold, transferred and synced with the same instructions as any other artwork.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Account, payer (must hold the master artwork)
+    /// `[]` Master Artwork Mint PDA with seeds [ALLOVRARTWORK, artwork symbol, artist account address]
+    /// `[writable]` Master Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, master artwork mint address]
+    /// `[]` Artist's Master Artwork Token ATA
+    /// `[writable]` Print Mint PDA with seeds [ALLOVRARTWORKPRINT, master artwork mint address, edition number]
+    /// `[writable]` Print Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, print mint address]
+    /// `[writable]` Artist's Print Token ATA (based on Print Mint PDA above)
+    /// `[writable]` Master Metaplex Metadata PDA
+    /// `[writable]` Master Metaplex Master Edition PDA
+    /// `[writable]` Print Metaplex Metadata PDA
+    /// `[writable]` Print Metaplex Edition PDA
+    /// `[writable]` Metaplex Edition Marker PDA
+    /// `[]` Metaplex Token Metadata Program
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    MintArtworkPrint(),
+    /// Update Artwork Metadata
+    ///
+    /// Summary: The artist updates description, content URI and content hash of an artwork,
+    /// only while the artist still holds it and it is not under offer.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Artist Wallet
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA with seeds [ALLOVRARTWORKMETA, artwork mint address]
+    UpdateArtworkMetadata(UpdateArtworkMetadataArgs),
+    /// Init Artwork Dutch Auction
+    ///
+    /// Summary: The holder puts the artwork in escrow for sale to any buyer at a price that
+    /// declines linearly from the start price to the floor price between start and end time.
+    /// Accepted with AcceptArtworkSale.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Artwork Holder Wallet
+    /// `[writable]` Artwork Holder Artwork Token Account
+    /// `[writable]` Artwork Metadata PDA
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[]` Token Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
+    InitArtworkDutchAuction(InitArtworkDutchAuctionArgs),
+    /// Propose Artwork Swap
+    ///
+    /// Summary: The holder of the offered artwork puts it in escrow and proposes swapping it
+    /// for the requested artwork, optionally with a SOL or AOVR top-up paid by either side.
+    /// A top-up from the proposer is taken now and held by the swap (SOL in the swap account,
+    /// AOVR in its AOVR ATA), a top-up from the counterparty is paid on accept.
+    ///
+    /// Only the offered artwork is escrowed. The requested artwork stays with its holder until
+    /// the swap is accepted, so it can still be sold or moved, AcceptArtworkSwap then fails
+    /// because the acceptor must be the holder recorded here and the artwork not under offer.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Proposer Wallet
+    /// `[writable]` Proposer Offered Artwork Token Account
+    /// `[]` Offered Artwork Mint PDA
+    /// `[writable]` Offered Artwork Metadata PDA
+    /// `[]` Requested Artwork Mint PDA
+    /// `[]` Requested Artwork Metadata PDA
+    /// `[writable]` Offered Artwork Escrow PDA
+    /// `[writable]` Artwork Swap PDA with seeds [ALLOVRARTWORKSWAP, offered artwork mint address, requested artwork mint address]
+    /// `[]` Token Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[writable]` Artist Metadata PDA of the offered artwork's artist (counts open offers, may be closed)
+    /// `[writable]` Proposer Payment Account (the proposer wallet for SOL, ignored without proposer top-up)
+    /// `[writable]` Swap AOVR ATA (created for a proposer AOVR top-up, ignored otherwise)
+    /// `[]` AOVR Mint
+    /// `[]` ATA Program
+    ProposeArtworkSwap(ProposeArtworkSwapArgs),
+    /// Accept Artwork Swap
+    ///
+    /// Summary: The holder of the requested artwork sends it to the proposer and receives the
+    /// offered artwork from escrow. The acceptor pays a counterparty top-up, or receives the
+    /// proposer top-up held by the swap. Both artwork holders are updated.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Acceptor Wallet
+    /// `[writable]` Acceptor Requested Artwork Token Account
+    /// `[writable]` Acceptor Offered Artwork Token Account (created if empty)
+    /// `[writable]` Proposer Wallet
+    /// `[writable]` Proposer Requested Artwork Token Account (created if empty)
+    /// `[]` Offered Artwork Mint PDA
+    /// `[writable]` Offered Artwork Metadata PDA
+    /// `[]` Requested Artwork Mint PDA
+    /// `[writable]` Requested Artwork Metadata PDA
+    /// `[writable]` Offered Artwork Escrow PDA
+    /// `[writable]` Artwork Swap PDA
+    /// `[writable]` Acceptor Payment Account (wallet for SOL, AOVR token account for AOVR, pays or receives the top-up, ignored without)
+    /// `[writable]` Proposer Payment Account (ignored without top-up)
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[writable]` Artist Metadata PDA of the offered artwork's artist (counts open offers, may be closed)
+    /// `[writable]` Swap AOVR ATA (ignored without proposer AOVR top-up)
+    AcceptArtworkSwap(),
+    /// Cancel Artwork Swap
+    ///
+    /// Summary: The proposer takes the offered artwork back from escrow and closes the swap,
+    /// which refunds any proposer top-up.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Proposer Wallet
+    /// `[writable]` Proposer Offered Artwork Token Account
+    /// `[writable]` Offered Artwork Metadata PDA
+    /// `[writable]` Offered Artwork Escrow PDA
+    /// `[writable]` Artwork Swap PDA
+    /// `[]` Token Program
+    /// `[writable]` Artist Metadata PDA of the offered artwork's artist (counts open offers, may be closed)
+    /// `[writable]` Proposer Payment Account (ignored without proposer top-up)
+    /// `[writable]` Swap AOVR ATA (ignored without proposer AOVR top-up)
+    CancelArtworkSwap(),
+    /// Init Artwork Bundle Sale
+    ///
+    /// Summary: The holder escrows several artworks and lists them as one bundle for one price,
+    /// to a given buyer or to anyone. The bundle is identified by its first artwork.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Seller Wallet
+    /// `[writable]` Artwork Bundle PDA with seeds [ALLOVRARTWORKBUNDLE, first artwork mint address]
+    /// `[]` Token Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// Then for each artwork in the bundle, in order:
+    /// `[writable]` Seller Artwork Token Account
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
+    InitArtworkBundleSale(InitArtworkBundleSaleArgs),
+    /// Accept Artwork Bundle Sale
+    ///
+    /// Summary: The buyer pays the bundle price and receives every artwork in the bundle. The
+    /// price is split evenly across the artworks to pay each artwork's creators as in
+    /// AcceptArtworkSale, including each artist's revenue share, and the seller receives the
+    /// rest.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Buyer Wallet
+    /// `[writable]` Buyer Payment Account (wallet for SOL, AOVR token account for AOVR)
+    /// `[writable]` Seller Wallet
+    /// `[writable]` Seller Payment Account
+    /// `[writable]` Artwork Bundle PDA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// Then for each artwork in the bundle, in order:
+    /// `[writable]` Buyer Artwork Token Account (created if empty)
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[writable]` Artist Revenue Vault PDA of the artwork's artist (may be uninitialised)
+    /// `[writable]` Revenue Vault AOVR ATA (ignored for SOL)
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
+    /// Then `[writable]` Creator Payment Account for each creator of each artwork, in order
+    AcceptArtworkBundleSale(AcceptArtworkSaleArgs),
+    /// Cancel Artwork Bundle Sale
+    ///
+    /// Summary: The seller takes every artwork back from escrow and closes the bundle.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Seller Wallet
+    /// `[writable]` Artwork Bundle PDA
+    /// `[]` Token Program
+    /// Then for each artwork in the bundle, in order:
+    /// `[writable]` Seller Artwork Token Account
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Escrow PDA
+    /// `[writable]` Artist Metadata PDA of the artwork's artist (counts open offers, may be closed)
+    CancelArtworkBundleSale(),
+    /// Fractionalise Artwork
+    ///
+    /// Summary: The holder locks the artwork in a vault PDA and receives the fraction supply
+    /// of a fungible fraction token. Anyone can buy the artwork out of the vault at the
+    /// reserve price, or redeem it by burning every fraction.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Holder Wallet
+    /// `[writable]` Holder Artwork Token Account
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Vault PDA with seeds [ALLOVRARTWORKVAULT, artwork mint address]
+    /// `[writable]` Vault Artwork ATA
+    /// `[writable]` Fraction Mint PDA with seeds [ALLOVRARTWORKFRACTION, artwork mint address]
+    /// `[writable]` Holder Fraction ATA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    FractionaliseArtwork(FractionaliseArtworkArgs),
+    /// Buyout Artwork
+    ///
+    /// Summary: The buyer pays the vault reserve price and receives the artwork. Creators are
+    /// paid as in AcceptArtworkSale, including the artist's revenue share, and the rest is held
+    /// by the vault for fraction holders.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Buyer Wallet
+    /// `[writable]` Buyer Payment Account (wallet for SOL, AOVR token account for AOVR)
+    /// `[writable]` Buyer Artwork Token Account (created if empty)
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Vault PDA
+    /// `[writable]` Vault Artwork ATA
+    /// `[writable]` Vault AOVR ATA (created if empty, ignored for SOL)
+    /// `[]` AOVR Mint
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[writable]` Artist Revenue Vault PDA (may be uninitialised)
+    /// `[writable]` Revenue Vault AOVR ATA (ignored for SOL)
+    /// `[writable]` Creator Payment Account, one per artwork creator in creators order
+    BuyoutArtwork(AcceptArtworkSaleArgs),
+    /// Redeem Artwork
+    ///
+    /// Summary: Whoever holds every fraction burns them and receives the artwork. The vault is
+    /// closed and its rent goes to the redeemer.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Redeemer Wallet
+    /// `[writable]` Redeemer Fraction ATA
+    /// `[writable]` Fraction Mint PDA
+    /// `[writable]` Redeemer Artwork Token Account (created if empty)
+    /// `[]` Artwork Mint PDA
+    /// `[writable]` Artwork Metadata PDA
+    /// `[writable]` Artwork Vault PDA
+    /// `[writable]` Vault Artwork ATA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    RedeemArtwork(),
+    /// Claim Fraction Proceeds
+    ///
+    /// Summary: After a buyout, a fraction holder burns their fractions for a pro rata share of
+    /// the buyout proceeds. The last claim closes the vault.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Fraction Holder Wallet
+    /// `[writable]` Fraction Holder Fraction ATA
+    /// `[writable]` Fraction Mint PDA
+    /// `[writable]` Artwork Vault PDA
+    /// `[writable]` Fraction Holder Payment Account (wallet for SOL, AOVR token account for AOVR)
+    /// `[writable]` Vault AOVR ATA (ignored for SOL)
+    /// `[]` Token Program
+    ClaimFractionProceeds(),
+    /// Buy Artist Tokens
+    ///
+    /// Summary: Artist tokens are sent from the market inventory to the buyer at the price set by
+    /// the artist's bonding curve for the current curve supply. The AOVR cost is paid into the
+    /// market reserve. Fails if the cost is above max_cost or the artist has deregistered.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Buyer Wallet, payer
+    /// `[writable]` Buyer AOVR Token ATA
+    /// `[writable]` Buyer Artist Token ATA (created if empty)
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Market PDA
+    /// `[writable]` Artist Market AOVR Reserve ATA
+    /// `[writable]` Artist Market Artist Token ATA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    BuyArtistTokens(BuyArtistTokensArgs),
+    /// Sell Artist Tokens
+    ///
+    /// Summary: Artist tokens are returned to the market inventory and the seller is paid from the
+    /// market reserve at the bonding curve price. Only tokens sold by the curve can be sold back to it. Fails if the
+    /// return is below min_return.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Seller Wallet
+    /// `[writable]` Seller AOVR Token ATA
+    /// `[writable]` Seller Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Market PDA
+    /// `[writable]` Artist Market AOVR Reserve ATA
+    /// `[writable]` Artist Market Artist Token ATA
+    /// `[]` Token Program
+    SellArtistTokens(SellArtistTokensArgs),
+    /// Set Artist Revenue Share
+    ///
+    /// Summary: The artist opts in to sharing share_basis_points of their artwork sale proceeds
+    /// (primary sale price and secondary royalties) with artist token holders who stake in the
+    /// revenue vault. The vault and its ATAs are created on first use. 0 opts out.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Wallet, payer
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Revenue Vault PDA with seeds [ALLOVRARTISTREVENUE, artist token mint address]
+    /// `[writable]` Revenue Vault Artist Token ATA (holds staked tokens)
+    /// `[writable]` Revenue Vault AOVR ATA
+    /// `[]` AOVR Mint
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    SetArtistRevenueShare(SetArtistRevenueShareArgs),
+    /// Stake Artist Tokens
+    ///
+    /// Summary: Artist tokens are moved into the revenue vault and earn a pro rata share of
+    /// revenue paid in from then on.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Holder Wallet, payer
+    /// `[writable]` Holder Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Revenue Vault PDA
+    /// `[writable]` Revenue Vault Artist Token ATA
+    /// `[writable]` Artist Stake PDA with seeds [ALLOVRARTISTSTAKE, artist token mint address, holder address]
+    /// `[]` Token Program
+    /// `[]` System
+    StakeArtistTokens(ArtistStakeArgs),
+    /// Unstake Artist Tokens
+    ///
+    /// Summary: Staked artist tokens are returned to the holder. Revenue earned so far stays
+    /// claimable.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Holder Wallet
+    /// `[writable]` Holder Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Revenue Vault PDA
+    /// `[writable]` Revenue Vault Artist Token ATA
+    /// `[writable]` Artist Stake PDA
+    /// `[]` Token Program
+    UnstakeArtistTokens(ArtistStakeArgs),
+    /// Claim Artist Revenue
+    ///
+    /// Summary: The holder is paid the SOL and AOVR revenue earned by their stake. A stake with
+    /// nothing staked is closed and its rent returned.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Holder Wallet (receives SOL)
+    /// `[writable]` Holder AOVR Token ATA
+    /// `[writable]` Artist Revenue Vault PDA
+    /// `[writable]` Revenue Vault AOVR ATA
+    /// `[writable]` Artist Stake PDA
+    /// `[]` Token Program
+    ClaimArtistRevenue(),
+    /// Claim Vested Artist Tokens
+    ///
+    /// Summary: The artist receives whatever part of their token allocation has vested and not
+    /// yet been claimed.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` Artist Wallet
+    /// `[writable]` Artist's Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Vesting PDA
+    /// `[writable]` Artist Vesting Artist Token ATA
+    /// `[]` Token Program
+    ClaimVestedArtistTokens(),
+    /// Airdrop Artist Tokens
+    ///
+    /// Summary: The artist sends their artist tokens to up to 10 recipients in one call. Recipient
+    /// ATAs are created as needed, paid for by the artist.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Wallet, payer
+    /// `[writable]` Artist's Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    /// `[]` Recipient Wallet and `[writable]` Recipient Artist Token ATA, one pair per amount
+    AirdropArtistTokens(AirdropArtistTokensArgs),
+    /// Create Artist Airdrop
+    ///
+    /// Summary: For large audiences the artist posts a merkle root of (index, claimant, amount)
+    /// leaves and locks total_amount of their artist tokens in the airdrop PDA. Fans claim
+    /// with ClaimArtistAirdrop.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Wallet, payer
+    /// `[writable]` Artist's Artist Token ATA
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Airdrop PDA with seeds [ALLOVRARTISTAIRDROP, artist token mint address, airdrop id]
+    /// `[writable]` Artist Airdrop Artist Token ATA
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    CreateArtistAirdrop(CreateArtistAirdropArgs),
+    /// Claim Artist Airdrop
+    ///
+    /// Summary: A fan proves their leaf is in the airdrop merkle tree and receives the amount.
+    /// A claim receipt PDA stops the same leaf being claimed twice.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Claimant Wallet, payer
+    /// `[writable]` Claimant Artist Token ATA (created if empty)
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Airdrop PDA
+    /// `[writable]` Artist Airdrop Artist Token ATA
+    /// `[writable]` Airdrop Claim PDA with seeds [ALLOVRARTISTAIRDROPCLAIM, artist airdrop address, leaf index]
+    /// `[]` Token Program
+    /// `[]` ATA Program
+    /// `[]` Rent Sysvar
+    /// `[]` System
+    ClaimArtistAirdrop(ClaimArtistAirdropArgs),
+    /// Deregister Artist
+    ///
+    /// Summary: The artist leaves the platform.
+    /// 1. None of the artist's artworks may be under a sale, swap or bundle offer
+    /// 2. Name and uri are removed from the artist token Metaplex metadata
+    /// 3. The artist metadata account is closed and its rent refunded to the artist
+    /// 4. The artist market is marked inactive, fans can still sell tokens back to the curve
+    /// 5. The artist symbol registry entry is closed, releasing the symbol
+    /// 6. The artist profile account is closed, if the artist created one
+    ///
+    /// The artist metadata counts the offers open on the artist's artworks, the sale, swap and
+    /// bundle instructions take the artist metadata PDA to keep that count.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Artist Wallet (receives rent)
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Metadata PDA
+    /// `[writable]` Artist Token Metaplex Metadata Account
+    /// `[]` Metaplex Token Metadata Program
+    /// `[writable]` Artist Market PDA
+    /// `[writable]` Artist Symbol PDA with seeds [ALLOVRARTISTSYMBOL, uppercase token symbol]
+    /// `[writable]` Artist Profile PDA with seeds [ALLOVRARTISTPROFILE, artist token mint address]
+    DeregisterArtist(),
+    /// Verify Artist
+    ///
+    /// Summary: The DAO governance authority marks the artist as verified. The DAO authority is
+    /// added to the artist token Metaplex metadata as a verified creator.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` DAO Authority, as recorded in the State
+    /// `[]` State (ALLOVR state account with known address)
+    /// `[]` Artist Wallet
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Metadata PDA
+    /// `[writable]` Artist Token Metaplex Metadata Account
+    /// `[]` Metaplex Token Metadata Program
+    VerifyArtist(),
+    /// Revoke Artist Verification
+    ///
+    /// Summary: The DAO governance authority removes the artist's verification and its verified
+    /// creator entry from the artist token Metaplex metadata.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` DAO Authority, as recorded in the State
+    /// `[]` State (ALLOVR state account with known address)
+    /// `[]` Artist Wallet
+    /// `[]` Artist Token Mint PDA
+    /// `[writable]` Artist Metadata PDA
+    /// `[writable]` Artist Token Metaplex Metadata Account
+    /// `[]` Metaplex Token Metadata Program
+    RevokeArtistVerification(),
+    /// Migrate Account
+    ///
+    /// Summary: Upgrades a program account to the current layout, [account key, version, data].
+    /// Accounts written before account headers are recognised by their legacy size, headed
+    /// accounts by an older version. The account is reallocated to the current size and the
+    /// payer tops up the rent. Anyone may migrate any account, the upgrade is fully determined
+    /// by the existing data and, for artist metadata, the artist wallet its PDAs derive from.
+    /// Migrated state has no DAO authority until SetDaoAuthority sets one.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer,writable]` Payer
+    /// `[writable]` Program account to migrate (ALLOVR state, artist metadata or artwork metadata)
+    /// `[]` System
+    /// `[]` (optional, required for artist metadata) Artist Wallet
+    MigrateAccount(),
+    /// Set DAO Authority
+    ///
+    /// Summary: Hands the DAO authority that verifies artists over to a new key, signed by the
+    /// current DAO authority. State migrated without an authority is given its first one by
+    /// the program keypair, as in IntialiseAllovr.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` DAO Authority (program_id while unset)
+    /// `[writable]` State (ALLOVR state account with known address)
+    SetDaoAuthority(SetDaoAuthorityArgs),
+    /// Distribute Artist Community Tokens
+    ///
+    /// Summary: The DAO authority pays amount artist tokens out of the community treasury an
+    /// artist's registration allocated, to a recipient's existing artist token ATA. The
+    /// community treasury PDA signs the transfer.
+    ///
+    /// Accounts expected:
+    ///
+    /// `[signer]` DAO Authority, as recorded in the State
+    /// `[]` State (ALLOVR state account with known address)
+    /// `[]` Artist Wallet
+    /// `[]` Artist Token Mint PDA
+    /// `[]` Artist Community Treasury PDA with seeds [ALLOVRARTISTCOMMUNITY, artist token mint address]
+    /// `[writable]` Artist Community Treasury Artist Token ATA
+    /// `[]` Recipient Wallet
+    /// `[writable]` Recipient Artist Token ATA
+    /// `[]` Token Program
+    DistributeArtistCommunityTokens(DistributeArtistCommunityTokensArgs),
+}
+
+/// Creates an IntialiseAllovr instruction. The program keypair, state and mint keypairs sign
+/// alongside the payer.
+pub fn initialise_allovr(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    args: InitialisaAllovrArgs,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*program_id, true),
+        AccountMeta::new(allovr_state_id(), true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(allovr_mint_id(), true),
+        AccountMeta::new_readonly(find_allovr_mint_authority(program_id).0, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::IntialiseAllovr(args),
+        accounts,
+    )
+}
+
+/// Creates a MintAllovr instruction, founder_tokens in the order of the founders in state
+pub fn mint_allovr(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    treasury_token: &Pubkey,
+    founder_tokens: &[Pubkey; 8],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*program_id, true),
+        AccountMeta::new(allovr_state_id(), false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(allovr_mint_id(), false),
+        AccountMeta::new_readonly(find_allovr_mint_authority(program_id).0, false),
+        AccountMeta::new(*treasury_token, false),
+    ];
+    accounts.extend(
+        founder_tokens
+            .iter()
+            .map(|founder_token| AccountMeta::new(*founder_token, false)),
+    );
+    accounts.extend([
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+
+    Instruction::new_with_borsh(*program_id, &AllovrInstruction::MintAllovr, accounts)
+}
+
+/// Creates a RegisterArtist instruction
+pub fn register_artist(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: RegisterArtistArgs,
+) -> Instruction {
+    let aovr_mint = allovr_mint_id();
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let artist_market = find_artist_market(program_id, &artist_token_mint).0;
+    let artist_vesting = find_artist_vesting(program_id, &artist_token_mint).0;
+    let artist_community = find_artist_community(program_id, &artist_token_mint).0;
+    let (artist_symbol, _) = find_artist_symbol(program_id, &args.token_symbol);
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(artist_token_mint, false),
+        AccountMeta::new(
+            find_artist_metadata(program_id, &artist_token_mint).0,
+            false,
+        ),
+        AccountMeta::new(allovr_aovr_treasury_id(), false),
+        AccountMeta::new(find_metadata_account(&artist_token_mint).0, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(artist_market, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_market, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(aovr_mint, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_market, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(artist_vesting, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_vesting, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_community, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_community, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(artist_symbol, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::RegisterArtist(args),
+        accounts,
+    )
+}
+
+/// Creates an UpdateArtist instruction, the artist profile PDA is passed when args has a profile
+pub fn update_artist(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: UpdateArtistArgs,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(
+            find_artist_metadata(program_id, &artist_token_mint).0,
+            false,
+        ),
+        AccountMeta::new(find_metadata_account(&artist_token_mint).0, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if args.profile.is_some() {
+        accounts.push(AccountMeta::new(
+            find_artist_profile(program_id, &artist_token_mint).0,
+            false,
+        ));
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::UpdateArtist(args),
+        accounts,
+    )
+}
+
+/// Creates a CreateArtwork instruction. Every creator in args other than the artist must sign.
+pub fn create_artwork(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: CreateArtworkArgs,
+) -> Instruction {
+    let (artwork_mint, _) = find_artwork_mint(program_id, &args.symbol, artist_wallet);
+
+    let mut accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &allovr_mint_id()),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new(artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, &artwork_mint).0, false),
+        AccountMeta::new(allovr_aovr_treasury_id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new(find_metadata_account(&artwork_mint).0, false),
+        AccountMeta::new(find_master_edition_account(&artwork_mint).0, false),
+    ];
+    if let Some(collection_symbol) = &args.collection_symbol {
+        let collection_mint = find_artist_collection_mint(
+            program_id,
+            collection_symbol,
+            &find_artist_mint(program_id, artist_wallet).0,
+        )
+        .0;
+        accounts.extend([
+            AccountMeta::new_readonly(collection_mint, false),
+            AccountMeta::new_readonly(find_metadata_account(&collection_mint).0, false),
+            AccountMeta::new_readonly(find_master_edition_account(&collection_mint).0, false),
+        ]);
+    }
+    accounts.extend(
+        args.creators
+            .iter()
+            .filter(|creator| creator.address != *artist_wallet)
+            .map(|creator| AccountMeta::new_readonly(creator.address, true)),
+    );
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CreateArtwork(args),
+        accounts,
+    )
+}
+
+/// Creates an InitArtworkSale instruction for the artwork as currently stored on chain
+pub fn init_artwork_sale(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+    args: InitArtworkSaleArgs,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::InitArtworkSale(args),
+        init_artwork_sale_accounts(program_id, holder_wallet, artwork),
+    )
+}
+
+/// Creates a CancelArtworkSale instruction for the artwork as currently stored on chain
+pub fn cancel_artwork_sale(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+) -> Instruction {
+    let artwork_mint = find_artwork_metadata_mint(program_id, artwork).0;
+
+    let accounts = vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new(find_artwork_metadata(program_id, &artwork_mint).0, false),
+        AccountMeta::new(
+            find_escrow(program_id, artwork, artwork.offer_count).0,
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        artist_metadata_account(program_id, artwork),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CancelArtworkSale(),
+        accounts,
+    )
+}
+
+/// Creates an AcceptArtworkSale instruction for the artwork as currently stored on chain, which
+/// must be under a sale offer or Dutch auction
+pub fn accept_artwork_sale(
+    program_id: &Pubkey,
+    buyer_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+    args: AcceptArtworkSaleArgs,
+) -> Instruction {
+    let artwork_mint = find_artwork_metadata_mint(program_id, artwork).0;
+    let currency = artwork.offer_currency.clone().unwrap_or(Currency::SOL);
+
+    let mut accounts = vec![
+        AccountMeta::new(*buyer_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(buyer_wallet, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new(payment_address(buyer_wallet, &currency), false),
+        AccountMeta::new(artwork.holder, false),
+        AccountMeta::new(
+            artwork
+                .payment_account
+                .unwrap_or_else(|| payment_address(&artwork.holder, &currency)),
+            false,
+        ),
+        AccountMeta::new(find_artwork_metadata(program_id, &artwork_mint).0, false),
+        AccountMeta::new(
+            find_escrow(program_id, artwork, artwork.offer_count).0,
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(artist_revenue_vault_accounts(program_id, artwork));
+    accounts.push(artist_metadata_account(program_id, artwork));
+    accounts.extend(creator_payment_accounts(artwork, &currency));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::AcceptArtworkSale(args),
+        accounts,
+    )
+}
+
+/// Creates a TransferArtwork instruction
+pub fn transfer_artwork(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    recipient_wallet: &Pubkey,
+    artwork_mint: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*recipient_wallet, false),
+        AccountMeta::new(
+            get_associated_token_address(recipient_wallet, artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &AllovrInstruction::TransferArtwork(), accounts)
+}
+
+/// Creates a SyncArtworkHolder instruction for the holder of the artwork token in their ATA
+pub fn sync_artwork_holder(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork_mint: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*holder_wallet, false),
+        AccountMeta::new_readonly(
+            get_associated_token_address(holder_wallet, artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::SyncArtworkHolder(),
+        accounts,
+    )
+}
+
+/// Creates a CreateArtistCollection instruction
+pub fn create_artist_collection(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: CreateArtistCollectionArgs,
+) -> Instruction {
+    let collection_mint = find_artist_collection_mint(
+        program_id,
+        &args.symbol,
+        &find_artist_mint(program_id, artist_wallet).0,
+    )
+    .0;
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new_readonly(find_artist_mint(program_id, artist_wallet).0, false),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &collection_mint),
+            false,
+        ),
+        AccountMeta::new(collection_mint, false),
+        AccountMeta::new(find_metadata_account(&collection_mint).0, false),
+        AccountMeta::new(find_master_edition_account(&collection_mint).0, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CreateArtistCollection(args),
+        accounts,
+    )
+}
+
+/// Creates a MintArtworkPrint instruction for the next edition of the master artwork as
+/// currently stored on chain
+pub fn mint_artwork_print(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    master: &ArtworkMetadata,
+) -> Instruction {
+    let master_mint = find_artwork_metadata_mint(program_id, master).0;
+    let edition = master.editions_printed + 1;
+    let (print_mint, _) = find_artwork_print_mint(program_id, &master_mint, edition);
+    let (edition_marker, _) = find_edition_account(
+        &master_mint,
+        (edition / EDITION_MARKER_BIT_SIZE).to_string(),
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new_readonly(master_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, &master_mint).0, false),
+        AccountMeta::new_readonly(
+            get_associated_token_address(artist_wallet, &master_mint),
+            false,
+        ),
+        AccountMeta::new(print_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, &print_mint).0, false),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &print_mint),
+            false,
+        ),
+        AccountMeta::new(find_metadata_account(&master_mint).0, false),
+        AccountMeta::new(find_master_edition_account(&master_mint).0, false),
+        AccountMeta::new(find_metadata_account(&print_mint).0, false),
+        AccountMeta::new(find_master_edition_account(&print_mint).0, false),
+        AccountMeta::new(edition_marker, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::MintArtworkPrint(),
+        accounts,
+    )
+}
+
+/// Creates an UpdateArtworkMetadata instruction
+pub fn update_artwork_metadata(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    artwork_mint: &Pubkey,
+    args: UpdateArtworkMetadataArgs,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*artist_wallet, true),
+        AccountMeta::new_readonly(*artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::UpdateArtworkMetadata(args),
+        accounts,
+    )
+}
+
+/// Creates an InitArtworkDutchAuction instruction for the artwork as currently stored on chain
+pub fn init_artwork_dutch_auction(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+    args: InitArtworkDutchAuctionArgs,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::InitArtworkDutchAuction(args),
+        init_artwork_sale_accounts(program_id, holder_wallet, artwork),
+    )
+}
+
+/// Creates a ProposeArtworkSwap instruction for the offered artwork as currently stored on chain
+pub fn propose_artwork_swap(
+    program_id: &Pubkey,
+    proposer_wallet: &Pubkey,
+    offered: &ArtworkMetadata,
+    requested_mint: &Pubkey,
+    args: ProposeArtworkSwapArgs,
+) -> Instruction {
+    let offered_mint = find_artwork_metadata_mint(program_id, offered).0;
+    let swap = find_artwork_swap(program_id, &offered_mint, requested_mint).0;
+    let proposer_payment = match &args.top_up {
+        Some(top_up) => top_up.payment_account,
+        None => *proposer_wallet,
+    };
+
+    let accounts = vec![
+        AccountMeta::new(*proposer_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(proposer_wallet, &offered_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(offered_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, &offered_mint).0, false),
+        AccountMeta::new_readonly(*requested_mint, false),
+        AccountMeta::new_readonly(find_artwork_metadata(program_id, requested_mint).0, false),
+        AccountMeta::new(
+            find_escrow(program_id, offered, offered.offer_count + 1).0,
+            false,
+        ),
+        AccountMeta::new(swap, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        artist_metadata_account(program_id, offered),
+        AccountMeta::new(proposer_payment, false),
+        AccountMeta::new(
+            get_associated_token_address(&swap, &allovr_mint_id()),
+            false,
+        ),
+        AccountMeta::new_readonly(allovr_mint_id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::ProposeArtworkSwap(args),
+        accounts,
+    )
+}
+
+/// Creates an AcceptArtworkSwap instruction from the swap and offered artwork as currently
+/// stored on chain
+pub fn accept_artwork_swap(
+    program_id: &Pubkey,
+    acceptor_wallet: &Pubkey,
+    swap: &ArtworkSwap,
+    offered: &ArtworkMetadata,
+) -> Instruction {
+    let swap_address = find_artwork_swap(program_id, &swap.offered_mint, &swap.requested_mint).0;
+    let (acceptor_payment, proposer_payment) = match &swap.top_up {
+        Some(top_up) => (
+            payment_address(acceptor_wallet, &top_up.currency),
+            top_up.payment_account,
+        ),
+        None => (*acceptor_wallet, swap.proposer),
+    };
+
+    let accounts = vec![
+        AccountMeta::new(*acceptor_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(acceptor_wallet, &swap.requested_mint),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(acceptor_wallet, &swap.offered_mint),
+            false,
+        ),
+        AccountMeta::new(swap.proposer, false),
+        AccountMeta::new(
+            get_associated_token_address(&swap.proposer, &swap.requested_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(swap.offered_mint, false),
+        AccountMeta::new(
+            find_artwork_metadata(program_id, &swap.offered_mint).0,
+            false,
+        ),
+        AccountMeta::new_readonly(swap.requested_mint, false),
+        AccountMeta::new(
+            find_artwork_metadata(program_id, &swap.requested_mint).0,
+            false,
+        ),
+        AccountMeta::new(
+            find_escrow(program_id, offered, offered.offer_count).0,
+            false,
+        ),
+        AccountMeta::new(swap_address, false),
+        AccountMeta::new(acceptor_payment, false),
+        AccountMeta::new(proposer_payment, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        artist_metadata_account(program_id, offered),
+        AccountMeta::new(
+            get_associated_token_address(&swap_address, &allovr_mint_id()),
+            false,
+        ),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::AcceptArtworkSwap(),
+        accounts,
+    )
+}
+
+/// Creates a CancelArtworkSwap instruction from the swap and offered artwork as currently
+/// stored on chain
+pub fn cancel_artwork_swap(
+    program_id: &Pubkey,
+    proposer_wallet: &Pubkey,
+    swap: &ArtworkSwap,
+    offered: &ArtworkMetadata,
+) -> Instruction {
+    let offered_mint = find_artwork_metadata_mint(program_id, offered).0;
+    let swap_address = find_artwork_swap(program_id, &offered_mint, &swap.requested_mint).0;
+    let proposer_payment = match &swap.top_up {
+        Some(top_up) => top_up.payment_account,
+        None => *proposer_wallet,
+    };
+
+    let accounts = vec![
+        AccountMeta::new(*proposer_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(proposer_wallet, &offered_mint),
+            false,
+        ),
+        AccountMeta::new(find_artwork_metadata(program_id, &offered_mint).0, false),
+        AccountMeta::new(
+            find_escrow(program_id, offered, offered.offer_count).0,
+            false,
+        ),
+        AccountMeta::new(swap_address, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        artist_metadata_account(program_id, offered),
+        AccountMeta::new(proposer_payment, false),
+        AccountMeta::new(
+            get_associated_token_address(&swap_address, &allovr_mint_id()),
+            false,
+        ),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CancelArtworkSwap(),
+        accounts,
+    )
+}
+
+/// Creates an InitArtworkBundleSale instruction for the artworks as currently stored on chain,
+/// the first artwork identifies the bundle
+pub fn init_artwork_bundle_sale(
+    program_id: &Pubkey,
+    seller_wallet: &Pubkey,
+    artworks: &[ArtworkMetadata],
+    args: InitArtworkBundleSaleArgs,
+) -> Instruction {
+    let artwork_mints: Vec<Pubkey> = artworks
+        .iter()
+        .map(|artwork| find_artwork_metadata_mint(program_id, artwork).0)
+        .collect();
+
+    let mut accounts = vec![
+        AccountMeta::new(*seller_wallet, true),
+        AccountMeta::new(artwork_bundle_address(program_id, &artwork_mints), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for (artwork, artwork_mint) in artworks.iter().zip(artwork_mints.iter()) {
+        accounts.extend([
+            AccountMeta::new(
+                get_associated_token_address(seller_wallet, artwork_mint),
+                false,
+            ),
+            AccountMeta::new_readonly(*artwork_mint, false),
+            AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+            AccountMeta::new(
+                find_escrow(program_id, artwork, artwork.offer_count + 1).0,
+                false,
+            ),
+            artist_metadata_account(program_id, artwork),
+        ]);
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::InitArtworkBundleSale(args),
+        accounts,
+    )
+}
+
+/// Creates an AcceptArtworkBundleSale instruction from the bundle and its artworks, in bundle
+/// order, as currently stored on chain
+pub fn accept_artwork_bundle_sale(
+    program_id: &Pubkey,
+    buyer_wallet: &Pubkey,
+    bundle: &ArtworkBundle,
+    artworks: &[ArtworkMetadata],
+    args: AcceptArtworkSaleArgs,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*buyer_wallet, true),
+        AccountMeta::new(payment_address(buyer_wallet, &bundle.currency), false),
+        AccountMeta::new(bundle.seller, false),
+        AccountMeta::new(bundle.payment_account, false),
+        AccountMeta::new(artwork_bundle_address(program_id, &bundle.artworks), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for (artwork, artwork_mint) in artworks.iter().zip(bundle.artworks.iter()) {
+        accounts.extend([
+            AccountMeta::new(
+                get_associated_token_address(buyer_wallet, artwork_mint),
+                false,
+            ),
+            AccountMeta::new_readonly(*artwork_mint, false),
+            AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+            AccountMeta::new(
+                find_escrow(program_id, artwork, artwork.offer_count).0,
+                false,
+            ),
+        ]);
+        accounts.extend(artist_revenue_vault_accounts(program_id, artwork));
+        accounts.push(artist_metadata_account(program_id, artwork));
+    }
+    for artwork in artworks.iter() {
+        accounts.extend(creator_payment_accounts(artwork, &bundle.currency));
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::AcceptArtworkBundleSale(args),
+        accounts,
+    )
+}
+
+/// Creates a CancelArtworkBundleSale instruction for the bundled artworks, in bundle order, as
+/// currently stored on chain
+pub fn cancel_artwork_bundle_sale(
+    program_id: &Pubkey,
+    seller_wallet: &Pubkey,
+    artworks: &[ArtworkMetadata],
+) -> Instruction {
+    let artwork_mints: Vec<Pubkey> = artworks
+        .iter()
+        .map(|artwork| find_artwork_metadata_mint(program_id, artwork).0)
+        .collect();
+
+    let mut accounts = vec![
+        AccountMeta::new(*seller_wallet, true),
+        AccountMeta::new(artwork_bundle_address(program_id, &artwork_mints), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (artwork, artwork_mint) in artworks.iter().zip(artwork_mints.iter()) {
+        accounts.extend([
+            AccountMeta::new(
+                get_associated_token_address(seller_wallet, artwork_mint),
+                false,
+            ),
+            AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+            AccountMeta::new(
+                find_escrow(program_id, artwork, artwork.offer_count).0,
+                false,
+            ),
+            artist_metadata_account(program_id, artwork),
+        ]);
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CancelArtworkBundleSale(),
+        accounts,
+    )
+}
+
+/// Creates a FractionaliseArtwork instruction
+pub fn fractionalise_artwork(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork_mint: &Pubkey,
+    args: FractionaliseArtworkArgs,
+) -> Instruction {
+    let vault = find_artwork_vault(program_id, artwork_mint).0;
+    let fraction_mint = find_artwork_fraction_mint(program_id, artwork_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(get_associated_token_address(&vault, artwork_mint), false),
+        AccountMeta::new(fraction_mint, false),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &fraction_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::FractionaliseArtwork(args),
+        accounts,
+    )
+}
+
+/// Creates a BuyoutArtwork instruction from the artwork and its vault as currently stored on chain
+pub fn buyout_artwork(
+    program_id: &Pubkey,
+    buyer_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+    vault: &ArtworkVault,
+    args: AcceptArtworkSaleArgs,
+) -> Instruction {
+    let artwork_mint = vault.artwork_mint;
+    let aovr_mint = allovr_mint_id();
+    let vault_address = find_artwork_vault(program_id, &artwork_mint).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(*buyer_wallet, true),
+        AccountMeta::new(payment_address(buyer_wallet, &vault.currency), false),
+        AccountMeta::new(
+            get_associated_token_address(buyer_wallet, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, &artwork_mint).0, false),
+        AccountMeta::new(vault_address, false),
+        AccountMeta::new(
+            get_associated_token_address(&vault_address, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(&vault_address, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(aovr_mint, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(artist_revenue_vault_accounts(program_id, artwork));
+    accounts.extend(creator_payment_accounts(artwork, &vault.currency));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::BuyoutArtwork(args),
+        accounts,
+    )
+}
+
+/// Creates a RedeemArtwork instruction
+pub fn redeem_artwork(
+    program_id: &Pubkey,
+    redeemer_wallet: &Pubkey,
+    artwork_mint: &Pubkey,
+) -> Instruction {
+    let vault = find_artwork_vault(program_id, artwork_mint).0;
+    let fraction_mint = find_artwork_fraction_mint(program_id, artwork_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new(*redeemer_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(redeemer_wallet, &fraction_mint),
+            false,
+        ),
+        AccountMeta::new(fraction_mint, false),
+        AccountMeta::new(
+            get_associated_token_address(redeemer_wallet, artwork_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*artwork_mint, false),
+        AccountMeta::new(find_artwork_metadata(program_id, artwork_mint).0, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new(get_associated_token_address(&vault, artwork_mint), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &AllovrInstruction::RedeemArtwork(), accounts)
+}
+
+/// Creates a ClaimFractionProceeds instruction from the vault as currently stored on chain
+pub fn claim_fraction_proceeds(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    vault: &ArtworkVault,
+) -> Instruction {
+    let vault_address = find_artwork_vault(program_id, &vault.artwork_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &vault.fraction_mint),
+            false,
+        ),
+        AccountMeta::new(vault.fraction_mint, false),
+        AccountMeta::new(vault_address, false),
+        AccountMeta::new(payment_address(holder_wallet, &vault.currency), false),
+        AccountMeta::new(
+            get_associated_token_address(&vault_address, &allovr_mint_id()),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::ClaimFractionProceeds(),
+        accounts,
+    )
+}
+
+/// Creates a BuyArtistTokens instruction for the artist's token
+pub fn buy_artist_tokens(
+    program_id: &Pubkey,
+    buyer_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: BuyArtistTokensArgs,
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new(*buyer_wallet, true)];
+    accounts.extend(artist_market_accounts(
+        program_id,
+        buyer_wallet,
+        artist_wallet,
+    ));
+    accounts.extend([
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::BuyArtistTokens(args),
+        accounts,
+    )
+}
+
+/// Creates a SellArtistTokens instruction for the artist's token
+pub fn sell_artist_tokens(
+    program_id: &Pubkey,
+    seller_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: SellArtistTokensArgs,
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(*seller_wallet, true)];
+    accounts.extend(artist_market_accounts(
+        program_id,
+        seller_wallet,
+        artist_wallet,
+    ));
+    accounts.push(AccountMeta::new_readonly(spl_token::id(), false));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::SellArtistTokens(args),
+        accounts,
+    )
+}
+
+/// Creates a SetArtistRevenueShare instruction
+pub fn set_artist_revenue_share(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: SetArtistRevenueShareArgs,
+) -> Instruction {
+    let aovr_mint = allovr_mint_id();
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let revenue_vault = find_artist_revenue_vault(program_id, &artist_token_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(revenue_vault, false),
+        AccountMeta::new(
+            get_associated_token_address(&revenue_vault, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(&revenue_vault, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(aovr_mint, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::SetArtistRevenueShare(args),
+        accounts,
+    )
+}
+
+/// Creates a StakeArtistTokens instruction for the artist's token
+pub fn stake_artist_tokens(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: ArtistStakeArgs,
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new(*holder_wallet, true)];
+    accounts.extend(artist_stake_accounts(
+        program_id,
+        holder_wallet,
+        artist_wallet,
+    ));
+    accounts.extend([
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::StakeArtistTokens(args),
+        accounts,
+    )
+}
+
+/// Creates an UnstakeArtistTokens instruction for the artist's token
+pub fn unstake_artist_tokens(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: ArtistStakeArgs,
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(*holder_wallet, true)];
+    accounts.extend(artist_stake_accounts(
+        program_id,
+        holder_wallet,
+        artist_wallet,
+    ));
+    accounts.push(AccountMeta::new_readonly(spl_token::id(), false));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::UnstakeArtistTokens(args),
+        accounts,
+    )
+}
+
+/// Creates a ClaimArtistRevenue instruction for the holder's stake in the artist's revenue vault
+pub fn claim_artist_revenue(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Instruction {
+    let aovr_mint = allovr_mint_id();
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let revenue_vault = find_artist_revenue_vault(program_id, &artist_token_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new(revenue_vault, false),
+        AccountMeta::new(
+            get_associated_token_address(&revenue_vault, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new(
+            find_artist_stake(program_id, &artist_token_mint, holder_wallet).0,
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::ClaimArtistRevenue(),
+        accounts,
+    )
+}
+
+/// Creates a ClaimVestedArtistTokens instruction
+pub fn claim_vested_artist_tokens(program_id: &Pubkey, artist_wallet: &Pubkey) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let artist_vesting = find_artist_vesting(program_id, &artist_token_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*artist_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(artist_vesting, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_vesting, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::ClaimVestedArtistTokens(),
+        accounts,
+    )
+}
+
+/// Creates an AirdropArtistTokens instruction sending each recipient wallet its amount
+pub fn airdrop_artist_tokens(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    recipients: &[(Pubkey, u64)],
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+
+    let mut accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for (recipient, _) in recipients.iter() {
+        accounts.extend([
+            AccountMeta::new_readonly(*recipient, false),
+            AccountMeta::new(
+                get_associated_token_address(recipient, &artist_token_mint),
+                false,
+            ),
+        ]);
+    }
+
+    let args = AirdropArtistTokensArgs {
+        amounts: recipients.iter().map(|(_, amount)| *amount).collect(),
+    };
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::AirdropArtistTokens(args),
+        accounts,
+    )
+}
+
+/// Creates a CreateArtistAirdrop instruction
+pub fn create_artist_airdrop(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    args: CreateArtistAirdropArgs,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let airdrop = find_artist_airdrop(program_id, &artist_token_mint, args.airdrop_id).0;
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(artist_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(airdrop, false),
+        AccountMeta::new(
+            get_associated_token_address(&airdrop, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::CreateArtistAirdrop(args),
+        accounts,
+    )
+}
+
+/// Creates a ClaimArtistAirdrop instruction for a leaf of the artist's airdrop_id airdrop
+pub fn claim_artist_airdrop(
+    program_id: &Pubkey,
+    claimant_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+    airdrop_id: u64,
+    args: ClaimArtistAirdropArgs,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let airdrop = find_artist_airdrop(program_id, &artist_token_mint, airdrop_id).0;
+    let (claim, _) = find_artist_airdrop_claim(program_id, &airdrop, args.index);
+
+    let accounts = vec![
+        AccountMeta::new(*claimant_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(claimant_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(airdrop, false),
+        AccountMeta::new(
+            get_associated_token_address(&airdrop, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(claim, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::ClaimArtistAirdrop(args),
+        accounts,
+    )
+}
+
+/// Creates a DeregisterArtist instruction. token_symbol is the artist's registered symbol.
+pub fn deregister_artist(
+    program_id: &Pubkey,
+    artist_wallet: &Pubkey,
+    token_symbol: &str,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let (artist_symbol, _) = find_artist_symbol(program_id, token_symbol);
+
+    let accounts = vec![
+        AccountMeta::new(*artist_wallet, true),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(
+            find_artist_metadata(program_id, &artist_token_mint).0,
+            false,
+        ),
+        AccountMeta::new(find_metadata_account(&artist_token_mint).0, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new(find_artist_market(program_id, &artist_token_mint).0, false),
+        AccountMeta::new(artist_symbol, false),
+        AccountMeta::new(find_artist_profile(program_id, &artist_token_mint).0, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::DeregisterArtist(),
+        accounts,
+    )
+}
+
+/// Creates a VerifyArtist instruction
+pub fn verify_artist(
+    program_id: &Pubkey,
+    dao_authority: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::VerifyArtist(),
+        artist_verification_accounts(program_id, dao_authority, artist_wallet),
+    )
+}
+
+/// Creates a RevokeArtistVerification instruction
+pub fn revoke_artist_verification(
+    program_id: &Pubkey,
+    dao_authority: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Instruction {
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::RevokeArtistVerification(),
+        artist_verification_accounts(program_id, dao_authority, artist_wallet),
+    )
+}
+
+/// Creates a SetDaoAuthority instruction, dao_authority is the current authority
+pub fn set_dao_authority(
+    program_id: &Pubkey,
+    dao_authority: &Pubkey,
+    args: SetDaoAuthorityArgs,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*dao_authority, true),
+        AccountMeta::new(allovr_state_id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::SetDaoAuthority(args),
+        accounts,
+    )
+}
+
+/// Creates a DistributeArtistCommunityTokens instruction paying the recipient wallet's artist
+/// token ATA
+pub fn distribute_artist_community_tokens(
+    program_id: &Pubkey,
+    dao_authority: &Pubkey,
+    artist_wallet: &Pubkey,
+    recipient_wallet: &Pubkey,
+    args: DistributeArtistCommunityTokensArgs,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let artist_community = find_artist_community(program_id, &artist_token_mint).0;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*dao_authority, true),
+        AccountMeta::new_readonly(allovr_state_id(), false),
+        AccountMeta::new_readonly(*artist_wallet, false),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new_readonly(artist_community, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_community, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(*recipient_wallet, false),
+        AccountMeta::new(
+            get_associated_token_address(recipient_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &AllovrInstruction::DistributeArtistCommunityTokens(args),
+        accounts,
+    )
+}
+
+/// Creates a MigrateAccount instruction
+pub fn migrate_account(program_id: &Pubkey, payer: &Pubkey, account: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &AllovrInstruction::MigrateAccount(), accounts)
+}
+
+/// Creates a MigrateAccount instruction for the metadata of the artist registered by
+/// artist_wallet
+pub fn migrate_artist_metadata(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Instruction {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+
+    let mut ix = migrate_account(
+        program_id,
+        payer,
+        &find_artist_metadata(program_id, &artist_token_mint).0,
+    );
+    ix.accounts
+        .push(AccountMeta::new_readonly(*artist_wallet, false));
+    ix
+}
+
+fn init_artwork_sale_accounts(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artwork: &ArtworkMetadata,
+) -> Vec<AccountMeta> {
+    let artwork_mint = find_artwork_metadata_mint(program_id, artwork).0;
+
+    vec![
+        AccountMeta::new(*holder_wallet, true),
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &artwork_mint),
+            false,
+        ),
+        AccountMeta::new(find_artwork_metadata(program_id, &artwork_mint).0, false),
+        AccountMeta::new_readonly(artwork_mint, false),
+        AccountMeta::new(
+            find_escrow(program_id, artwork, artwork.offer_count + 1).0,
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        artist_metadata_account(program_id, artwork),
+    ]
+}
+
+/// Artist metadata of the artwork's artist, which counts the offers open on their artworks
+fn artist_metadata_account(program_id: &Pubkey, artwork: &ArtworkMetadata) -> AccountMeta {
+    let artist_token_mint = find_artist_mint(program_id, &artwork.artist).0;
+
+    AccountMeta::new(
+        find_artist_metadata(program_id, &artist_token_mint).0,
+        false,
+    )
+}
+
+/// Artist token mint through market artist token ATA, as in Buy and SellArtistTokens
+fn artist_market_accounts(
+    program_id: &Pubkey,
+    wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Vec<AccountMeta> {
+    let aovr_mint = allovr_mint_id();
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let artist_market = find_artist_market(program_id, &artist_token_mint).0;
+
+    vec![
+        AccountMeta::new(get_associated_token_address(wallet, &aovr_mint), false),
+        AccountMeta::new(
+            get_associated_token_address(wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(artist_market, false),
+        AccountMeta::new(
+            get_associated_token_address(&artist_market, &aovr_mint),
+            false,
+        ),
+        AccountMeta::new(
+            get_associated_token_address(&artist_market, &artist_token_mint),
+            false,
+        ),
+    ]
+}
+
+/// Holder artist token ATA through stake PDA, as in Stake and UnstakeArtistTokens
+fn artist_stake_accounts(
+    program_id: &Pubkey,
+    holder_wallet: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Vec<AccountMeta> {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+    let revenue_vault = find_artist_revenue_vault(program_id, &artist_token_mint).0;
+
+    vec![
+        AccountMeta::new(
+            get_associated_token_address(holder_wallet, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(revenue_vault, false),
+        AccountMeta::new(
+            get_associated_token_address(&revenue_vault, &artist_token_mint),
+            false,
+        ),
+        AccountMeta::new(
+            find_artist_stake(program_id, &artist_token_mint, holder_wallet).0,
+            false,
+        ),
+    ]
+}
+
+fn artist_verification_accounts(
+    program_id: &Pubkey,
+    dao_authority: &Pubkey,
+    artist_wallet: &Pubkey,
+) -> Vec<AccountMeta> {
+    let artist_token_mint = find_artist_mint(program_id, artist_wallet).0;
+
+    vec![
+        AccountMeta::new_readonly(*dao_authority, true),
+        AccountMeta::new_readonly(allovr_state_id(), false),
+        AccountMeta::new_readonly(*artist_wallet, false),
+        AccountMeta::new_readonly(artist_token_mint, false),
+        AccountMeta::new(
+            find_artist_metadata(program_id, &artist_token_mint).0,
+            false,
+        ),
+        AccountMeta::new(find_metadata_account(&artist_token_mint).0, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+    ]
+}
+
+/// The artist revenue vault of the artwork's artist and its AOVR ATA, which sales pay the
+/// artist's revenue share into
+fn artist_revenue_vault_accounts(
+    program_id: &Pubkey,
+    artwork: &ArtworkMetadata,
+) -> [AccountMeta; 2] {
+    let artist_token_mint = find_artist_mint(program_id, &artwork.artist).0;
+    let revenue_vault = find_artist_revenue_vault(program_id, &artist_token_mint).0;
+
+    [
+        AccountMeta::new(revenue_vault, false),
+        AccountMeta::new(
+            get_associated_token_address(&revenue_vault, &allovr_mint_id()),
+            false,
+        ),
+    ]
+}
+
+/// One payment account per artwork creator, in creators order
+fn creator_payment_accounts(artwork: &ArtworkMetadata, currency: &Currency) -> Vec<AccountMeta> {
+    artwork
+        .creators
+        .iter()
+        .map(|creator| AccountMeta::new(payment_address(&creator.address, currency), false))
+        .collect()
+}
+
+/// The wallet itself for SOL, its AOVR ATA for AOVR
+fn payment_address(wallet: &Pubkey, currency: &Currency) -> Pubkey {
+    match currency {
+        Currency::SOL => *wallet,
+        Currency::AOVR => get_associated_token_address(wallet, &allovr_mint_id()),
+    }
+}
+
+fn allovr_state_id() -> Pubkey {
+    Pubkey::from_str(ALLOVR_STATE_ID).unwrap()
+}
+
+fn allovr_mint_id() -> Pubkey {
+    Pubkey::from_str(ALLOVR_MINT_ID).unwrap()
+}
+
+fn allovr_aovr_treasury_id() -> Pubkey {
+    Pubkey::from_str(ALLOVR_AOVR_TREASURY_ID).unwrap()
+}
+
+/// The bundle is identified by its first artwork
+fn artwork_bundle_address(program_id: &Pubkey, artwork_mints: &[Pubkey]) -> Pubkey {
+    let first_mint = artwork_mints.first().copied().unwrap_or_default();
+    find_artwork_bundle(program_id, &first_mint).0
 }
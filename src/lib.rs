@@ -5,6 +5,7 @@ use solana_program::pubkey::Pubkey;
 pub mod entrypoint;
 pub mod error;
 pub mod instruction;
+pub mod pda;
 pub mod processor;
 pub mod processors;
 pub mod state;
@@ -17,6 +18,21 @@ pub const ARTIST_METADATA_SEED_PREFIX: &'static str = "ALLOVRARTISTMETA";
 pub const ARTWORK_SEED_PREFIX: &'static str = "ALLOVRARTWORK";
 pub const ARTWORK_METADATA_SEED_PREFIX: &'static str = "ALLOVRARTWORKMETA";
 pub const ARTWORK_ESCROW_SEED_PREFIX: &'static str = "ALLOVRARTWORKESCROW";
+pub const ARTWORK_PRINT_SEED_PREFIX: &str = "ALLOVRARTWORKPRINT";
+pub const ARTIST_COLLECTION_SEED_PREFIX: &str = "ALLOVRARTISTCOLLECTION";
+pub const ARTWORK_SWAP_SEED_PREFIX: &str = "ALLOVRARTWORKSWAP";
+pub const ARTWORK_BUNDLE_SEED_PREFIX: &str = "ALLOVRARTWORKBUNDLE";
+pub const ARTWORK_VAULT_SEED_PREFIX: &str = "ALLOVRARTWORKVAULT";
+pub const ARTWORK_FRACTION_SEED_PREFIX: &str = "ALLOVRARTWORKFRACTION";
+pub const ARTIST_MARKET_SEED_PREFIX: &str = "ALLOVRARTISTMARKET";
+pub const ARTIST_REVENUE_SEED_PREFIX: &str = "ALLOVRARTISTREVENUE";
+pub const ARTIST_STAKE_SEED_PREFIX: &str = "ALLOVRARTISTSTAKE";
+pub const ARTIST_VESTING_SEED_PREFIX: &str = "ALLOVRARTISTVESTING";
+pub const ARTIST_COMMUNITY_SEED_PREFIX: &str = "ALLOVRARTISTCOMMUNITY";
+pub const ARTIST_AIRDROP_SEED_PREFIX: &str = "ALLOVRARTISTAIRDROP";
+pub const ARTIST_AIRDROP_CLAIM_SEED_PREFIX: &str = "ALLOVRARTISTAIRDROPCLAIM";
+pub const ARTIST_SYMBOL_SEED_PREFIX: &str = "ALLOVRARTISTSYMBOL";
+pub const ARTIST_PROFILE_SEED_PREFIX: &str = "ALLOVRARTISTPROFILE";
 
 // Known Addresses
 // pub const ALLOVR_PROGRAM_ID: &'static str = "ovRW7Yrq6Nqcz3GXnL4wexGZJeoJjRwo5EHdpWwLEDe";
@@ -31,11 +47,15 @@ pub const ALLOVR_STATE_ID: &'static str = "GHBMbRKqCFZ4QGpCnFemaBP4XvRqDdmUSWQw8
 pub const ALLOVR_AOVR_TREASURY_ID: &'static str = "B6zMabUJL4br4cwRtnf9AXUPsJn4uxu5GqxTSFdrFbSz";
 
 pub const ALL_DECIMAL_PLACES: u8 = 9;
+pub const ACCOUNT_HEADER_SIZE: usize = size_of::<u8>() + // Account key (see state::AccountKey)
+    size_of::<u8>(); // Layout version
 pub const MINT_SIZE: usize = 82;
-pub const STATE_SIZE: usize = size_of::<bool>() + // minted
+pub const STATE_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<bool>() + // minted
     size_of::<UnixTimestamp>() + // Next Inflation Due UnixTimestamp
     size_of::<u32>() + // Inflation Run Count
-    (8 * size_of::<Pubkey>()); // Founder Pubkey * 8
+    (8 * size_of::<Pubkey>()) + // Founder Pubkey * 8
+    size_of::<Pubkey>(); // DAO Authority Pubkey
 pub const TOKEN_ACCOUNT_SIZE: usize = 165;
 pub const INFLATION_INTERVAL_IN_SECONDS: i64 = 604800; // seconds in a week (60 * 60 * 24 * 7);
 
@@ -44,21 +64,186 @@ pub const ARTIST_METADATA_NAME_SIZE: usize = 32;
 pub const ARTIST_METADATA_DESCRIPTION_SIZE: usize = 1000;
 pub const ARTIST_METADATA_SYMBOL_SIZE: usize = 4;
 pub const ARTIST_METADATA_URI_SIZE: usize = 200;
-pub const ARTIST_METADATA_SIZE: usize = ARTIST_METADATA_NAME_SIZE
-    + ARTIST_METADATA_DESCRIPTION_SIZE
-    + ARTIST_METADATA_SYMBOL_SIZE
-    + ARTIST_METADATA_URI_SIZE;
+pub const ARTIST_METADATA_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<u32>() + ARTIST_METADATA_NAME_SIZE + // Name
+    size_of::<u32>() + ARTIST_METADATA_DESCRIPTION_SIZE + // Description
+    size_of::<u32>() + ARTIST_METADATA_SYMBOL_SIZE + // Symbol
+    size_of::<bool>() + size_of::<u32>() + ARTIST_METADATA_URI_SIZE + // Option (URI)
+    size_of::<bool>() + // Verified
+    size_of::<u8>() + // Bump
+    size_of::<u8>() + // Artist token mint bump
+    size_of::<u32>(); // Open offers
+
+// Artist Profile
+pub const ARTIST_PROFILE_MAX_LINKS: usize = 8;
+pub const ARTIST_PROFILE_MAX_GENRES: usize = 5;
+pub const ARTIST_PROFILE_URI_SIZE: usize = 200;
+pub const ARTIST_PROFILE_GENRE_SIZE: usize = 32;
+pub const ARTIST_PROFILE_LOCATION_SIZE: usize = 64;
+pub const ARTIST_PROFILE_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<u32>() + ARTIST_PROFILE_MAX_LINKS * (size_of::<u8>() + size_of::<u32>() + ARTIST_PROFILE_URI_SIZE) + // Links
+    size_of::<u8>() + size_of::<u32>() + ARTIST_PROFILE_URI_SIZE + // Option<Avatar URI>
+    size_of::<u8>() + size_of::<u32>() + ARTIST_PROFILE_URI_SIZE + // Option<Banner URI>
+    size_of::<u32>() + ARTIST_PROFILE_MAX_GENRES * (size_of::<u32>() + ARTIST_PROFILE_GENRE_SIZE) + // Genre tags
+    size_of::<u8>() + size_of::<u32>() + ARTIST_PROFILE_LOCATION_SIZE + // Option<Location>
+    size_of::<u8>(); // Bump
+
+// Artist Symbol Registry
+pub const ARTIST_SYMBOL_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u8>(); // Bump
+
+// Artist Token
+pub const ARTIST_TOKEN_MAX_DECIMALS: u8 = 9;
+pub const ARTIST_MIN_VESTING_SECONDS: i64 = 7776000; // 90 days
+pub const ARTIST_VESTING_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u64>() + // Total vested amount
+    size_of::<u64>() + // Claimed amount
+    size_of::<UnixTimestamp>() + // Start time
+    size_of::<UnixTimestamp>() + // Cliff time
+    size_of::<UnixTimestamp>() + // End time
+    size_of::<u8>(); // Bump
+
+// Artist Airdrop
+pub const ARTIST_AIRDROP_MAX_RECIPIENTS: usize = 10; // keeps batches within transaction account limits
+pub const ARTIST_AIRDROP_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u64>() + // Airdrop id
+    32 + // Merkle root
+    size_of::<u64>() + // Total amount
+    size_of::<u64>() + // Claimed amount
+    size_of::<u8>(); // Bump
+pub const ARTIST_AIRDROP_CLAIM_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Claimant Pubkey
+    size_of::<u64>(); // Amount
+
+// Artist Market
+pub const ARTIST_BONDING_CURVE_SLOPE_SCALE: u64 = 1000000; // slope is per million artist tokens sold
+pub const ARTIST_MARKET_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u64>() + size_of::<u64>() + // Bonding curve (base price, slope)
+    size_of::<u64>() + // Curve supply
+    size_of::<bool>() + // Active
+    size_of::<u8>() + // Bump
+    size_of::<u8>(); // Artist token mint bump
+
+// Artist Revenue Share
+pub const ARTIST_MAX_REVENUE_SHARE_BASIS_POINTS: u16 = 10000;
+pub const ARTIST_REWARD_PRECISION: u128 = 1000000000000;
+pub const ARTIST_REVENUE_VAULT_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u16>() + // Share basis points
+    size_of::<u64>() + // Total staked
+    size_of::<u128>() + // SOL reward per token
+    size_of::<u128>() + // AOVR reward per token
+    size_of::<u8>(); // Bump
+pub const ARTIST_REVENUE_STAKE_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Holder Pubkey
+    size_of::<Pubkey>() + // Artist token mint
+    size_of::<u64>() + // Staked amount
+    size_of::<u128>() + // SOL reward per token paid
+    size_of::<u128>() + // AOVR reward per token paid
+    size_of::<u64>() + // SOL owed
+    size_of::<u64>() + // AOVR owed
+    size_of::<u8>(); // Bump
+
+// Artist Collection
+pub const ARTIST_COLLECTION_SYMBOL_SIZE: usize = 10;
+pub const ARTIST_COLLECTION_NAME_SIZE: usize = 32;
+pub const ARTIST_COLLECTION_URI_SIZE: usize = 200;
 
 // Artwork Metadata
 pub const ARTWORK_METADATA_SYMBOL_SIZE: usize = 4;
 pub const ARTWORK_METADATA_DESCRIPTION_SIZE: usize = 256;
-pub const ARTWORK_METADATA_SIZE: usize = size_of::<Pubkey>() + // Artist Pubkey
+pub const ARTWORK_METADATA_CONTENT_URI_SIZE: usize = 200;
+pub const ARTWORK_METADATA_CONTENT_HASH_SIZE: usize = 32;
+pub const ARTWORK_METAPLEX_NAME_SIZE: usize = 32;
+pub const ARTWORK_METAPLEX_URI_SIZE: usize = 200;
+pub const ARTWORK_MAX_SELLER_FEE_BASIS_POINTS: u16 = 10000;
+pub const ARTWORK_MAX_EDITIONS: u64 = 10000;
+pub const ARTWORK_MAX_CREATORS: usize = 5; // Metaplex creator limit
+pub const ARTWORK_METADATA_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Artist Pubkey
+    size_of::<u32>() + ARTWORK_METADATA_SYMBOL_SIZE + // Symbol (ABCD)
+    size_of::<u32>() + ARTWORK_METADATA_DESCRIPTION_SIZE + // Description
+    size_of::<Pubkey>() + // Holder Pubkey
+    size_of::<u32>() + // Offer count
+    size_of::<bool>() + size_of::<Pubkey>() + // Option (Offered to Pubkey)
+    size_of::<bool>() + size_of::<u64>() + // Option (Offer Price)
+    size_of::<bool>() + 1 + //size_of::<Currency>(); // Option (Offer Currency);
+    size_of::<bool>() + size_of::<Pubkey>() + // Option (Payment account Pubkey)
+    size_of::<u64>() + // Max editions
+    size_of::<u64>() + // Editions printed
+    size_of::<bool>() + size_of::<Pubkey>() + size_of::<u64>() + // Option (Print parent mint, edition number)
+    size_of::<u32>() + ARTWORK_METADATA_CONTENT_URI_SIZE + // Content URI (Arweave / IPFS)
+    ARTWORK_METADATA_CONTENT_HASH_SIZE + // Content SHA-256 hash
+    size_of::<u32>() + ARTWORK_MAX_CREATORS * (size_of::<Pubkey>() + size_of::<u8>()) + // Creators (address, share)
+    size_of::<u16>() + // Seller fee basis points
+    size_of::<bool>() + // Primary sale happened
+    size_of::<bool>() + 2 * size_of::<u64>() + 2 * size_of::<i64>() + // Option (Dutch auction prices and times)
+    size_of::<bool>() + size_of::<Pubkey>() + // Option (Swap PDA)
+    size_of::<bool>() + size_of::<Pubkey>() + // Option (Bundle PDA)
+    size_of::<u8>() + // Bump
+    size_of::<u8>() + // Artwork mint bump
+    size_of::<bool>() + size_of::<u8>() + // Option (Escrow bump)
+    size_of::<bool>() + size_of::<u8>(); // Option (Fraction mint bump)
+
+// Artwork Swap
+pub const ARTWORK_SWAP_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Proposer Pubkey
+    size_of::<Pubkey>() + // Offered artwork mint
+    size_of::<Pubkey>() + // Requested artwork mint
+    size_of::<Pubkey>() + // Counterparty Pubkey
+    size_of::<bool>() + size_of::<u64>() + 1 + size_of::<Pubkey>() + 1 + // Option (Top-up amount, currency, payment account, payer)
+    size_of::<u8>(); // Bump
+
+// Artwork Bundle
+pub const ARTWORK_BUNDLE_MIN_ARTWORKS: usize = 2;
+pub const ARTWORK_BUNDLE_MAX_ARTWORKS: usize = 10; // keeps accept within transaction account limits
+pub const ARTWORK_BUNDLE_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Seller Pubkey
+    size_of::<u32>() + ARTWORK_BUNDLE_MAX_ARTWORKS * size_of::<Pubkey>() + // Artwork mints
+    size_of::<bool>() + size_of::<Pubkey>() + // Option (Offered to Pubkey)
+    size_of::<u64>() + // Price
+    1 + // Currency
+    size_of::<Pubkey>() + // Payment account Pubkey
+    size_of::<u8>(); // Bump
+
+// Artwork Vault
+pub const ARTWORK_VAULT_SIZE: usize = ACCOUNT_HEADER_SIZE + // Account key and version
+    size_of::<Pubkey>() + // Curator Pubkey
+    size_of::<Pubkey>() + // Artwork mint
+    size_of::<Pubkey>() + // Fraction mint
+    size_of::<u64>() + // Fraction supply
+    size_of::<u64>() + // Reserve price
+    1 + // Currency
+    1 + // State
+    size_of::<u64>() + // Proceeds
+    size_of::<u8>(); // Bump
+
+// Unversioned layouts written before account headers, MigrateAccount recognises them by size
+pub const LEGACY_STATE_SIZE: usize = size_of::<bool>() + // minted
+    size_of::<UnixTimestamp>() + // Next Inflation Due UnixTimestamp
+    size_of::<u32>() + // Inflation Run Count
+    (8 * size_of::<Pubkey>()); // Founder Pubkey * 8
+pub const LEGACY_ARTIST_METADATA_SIZE: usize = ARTIST_METADATA_NAME_SIZE
+    + ARTIST_METADATA_DESCRIPTION_SIZE
+    + ARTIST_METADATA_SYMBOL_SIZE
+    + ARTIST_METADATA_URI_SIZE;
+pub const LEGACY_ARTWORK_METADATA_SIZE: usize = size_of::<Pubkey>() + // Artist Pubkey
     ARTWORK_METADATA_SYMBOL_SIZE + // Symbol (ABCD)
     ARTWORK_METADATA_DESCRIPTION_SIZE + // Description
     size_of::<Pubkey>() + // Holder Pubkey
     size_of::<bool>() + size_of::<Pubkey>() + // Option (Offered to Pubkey)
     size_of::<bool>() + size_of::<u64>() + // Option (Offer Price)
-    size_of::<bool>() + 1 + //size_of::<Currency>(); // Option (Offer Currency);
+    size_of::<bool>() + 1 + // Option (Offer Currency)
     size_of::<Pubkey>(); // Payment account Pubkey
 
 solana_program::declare_id!("4ujXmUcCa8upcfy9u8CJsxoSfGRuTMw7eZvTxkPEH4Ae");
@@ -0,0 +1,316 @@
+//! Seeds and addresses of every program derived address. Processors assert and sign with the
+//! seeds built here and clients derive addresses with the find functions, so the two cannot
+//! drift apart.
+
+use solana_program::pubkey::Pubkey;
+
+use crate::error::AllovrError;
+use crate::state::ArtworkMetadata;
+use crate::utils::normalise_artist_symbol;
+use crate::*;
+
+/// Owned seeds of a PDA, without the bump
+pub struct PdaSeeds(Vec<Vec<u8>>);
+
+impl PdaSeeds {
+    fn new(prefix: &str, seeds: &[&[u8]]) -> Self {
+        let mut all = vec![prefix.as_bytes().to_vec()];
+        all.extend(seeds.iter().map(|seed| seed.to_vec()));
+        PdaSeeds(all)
+    }
+
+    /// Seeds as slices, for find_program_address and assert_pda
+    pub fn as_slices(&self) -> Vec<&[u8]> {
+        self.0.iter().map(|seed| seed.as_slice()).collect()
+    }
+
+    /// Seeds followed by the bump, for invoke_signed
+    pub fn with_bump(&self, bump: u8) -> PdaSeeds {
+        let mut all = self.0.clone();
+        all.push(vec![bump]);
+        PdaSeeds(all)
+    }
+
+    pub fn find(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.as_slices(), program_id)
+    }
+
+    /// Address for a bump stored when the PDA was created. A single hash, where find may try
+    /// many bumps before it lands on one off the curve.
+    pub fn create(&self, bump: u8, program_id: &Pubkey) -> Result<Pubkey, AllovrError> {
+        Pubkey::create_program_address(&self.with_bump(bump).as_slices(), program_id)
+            .map_err(|_| AllovrError::InvalidPda)
+    }
+}
+
+// Allovr
+
+pub fn allovr_mint_authority_seeds() -> PdaSeeds {
+    PdaSeeds::new(ALLOVR_MINT_SEED_PREFIX, &[])
+}
+
+pub fn find_allovr_mint_authority(program_id: &Pubkey) -> (Pubkey, u8) {
+    allovr_mint_authority_seeds().find(program_id)
+}
+
+// Artist
+
+pub fn artist_mint_seeds(artist_wallet: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_SEED_PREFIX, &[artist_wallet.as_ref()])
+}
+
+pub fn find_artist_mint(program_id: &Pubkey, artist_wallet: &Pubkey) -> (Pubkey, u8) {
+    artist_mint_seeds(artist_wallet).find(program_id)
+}
+
+pub fn artist_metadata_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_METADATA_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_metadata(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_metadata_seeds(artist_token_mint).find(program_id)
+}
+
+pub fn artist_profile_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_PROFILE_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_profile(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_profile_seeds(artist_token_mint).find(program_id)
+}
+
+/// Seeded by the normalised symbol, so symbols differing only in case share a registry entry
+pub fn artist_symbol_seeds(symbol: &str) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTIST_SYMBOL_SEED_PREFIX,
+        &[normalise_artist_symbol(symbol).as_bytes()],
+    )
+}
+
+pub fn find_artist_symbol(program_id: &Pubkey, symbol: &str) -> (Pubkey, u8) {
+    artist_symbol_seeds(symbol).find(program_id)
+}
+
+pub fn artist_market_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_MARKET_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_market(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_market_seeds(artist_token_mint).find(program_id)
+}
+
+pub fn artist_vesting_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_VESTING_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_vesting(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_vesting_seeds(artist_token_mint).find(program_id)
+}
+
+pub fn artist_community_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_COMMUNITY_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_community(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_community_seeds(artist_token_mint).find(program_id)
+}
+
+pub fn artist_revenue_vault_seeds(artist_token_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTIST_REVENUE_SEED_PREFIX, &[artist_token_mint.as_ref()])
+}
+
+pub fn find_artist_revenue_vault(program_id: &Pubkey, artist_token_mint: &Pubkey) -> (Pubkey, u8) {
+    artist_revenue_vault_seeds(artist_token_mint).find(program_id)
+}
+
+pub fn artist_stake_seeds(artist_token_mint: &Pubkey, holder: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTIST_STAKE_SEED_PREFIX,
+        &[artist_token_mint.as_ref(), holder.as_ref()],
+    )
+}
+
+pub fn find_artist_stake(
+    program_id: &Pubkey,
+    artist_token_mint: &Pubkey,
+    holder: &Pubkey,
+) -> (Pubkey, u8) {
+    artist_stake_seeds(artist_token_mint, holder).find(program_id)
+}
+
+pub fn artist_airdrop_seeds(artist_token_mint: &Pubkey, airdrop_id: u64) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTIST_AIRDROP_SEED_PREFIX,
+        &[
+            artist_token_mint.as_ref(),
+            airdrop_id.to_string().as_bytes(),
+        ],
+    )
+}
+
+pub fn find_artist_airdrop(
+    program_id: &Pubkey,
+    artist_token_mint: &Pubkey,
+    airdrop_id: u64,
+) -> (Pubkey, u8) {
+    artist_airdrop_seeds(artist_token_mint, airdrop_id).find(program_id)
+}
+
+pub fn artist_airdrop_claim_seeds(airdrop: &Pubkey, index: u64) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTIST_AIRDROP_CLAIM_SEED_PREFIX,
+        &[airdrop.as_ref(), index.to_string().as_bytes()],
+    )
+}
+
+pub fn find_artist_airdrop_claim(
+    program_id: &Pubkey,
+    airdrop: &Pubkey,
+    index: u64,
+) -> (Pubkey, u8) {
+    artist_airdrop_claim_seeds(airdrop, index).find(program_id)
+}
+
+pub fn artist_collection_mint_seeds(
+    collection_symbol: &str,
+    artist_token_mint: &Pubkey,
+) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTIST_COLLECTION_SEED_PREFIX,
+        &[collection_symbol.as_bytes(), artist_token_mint.as_ref()],
+    )
+}
+
+pub fn find_artist_collection_mint(
+    program_id: &Pubkey,
+    collection_symbol: &str,
+    artist_token_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    artist_collection_mint_seeds(collection_symbol, artist_token_mint).find(program_id)
+}
+
+// Artwork
+
+/// Seeds of an original artwork mint
+pub fn artwork_mint_seeds(symbol: &str, artist_wallet: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTWORK_SEED_PREFIX,
+        &[symbol.as_bytes(), artist_wallet.as_ref()],
+    )
+}
+
+pub fn find_artwork_mint(
+    program_id: &Pubkey,
+    symbol: &str,
+    artist_wallet: &Pubkey,
+) -> (Pubkey, u8) {
+    artwork_mint_seeds(symbol, artist_wallet).find(program_id)
+}
+
+pub fn artwork_print_mint_seeds(master_mint: &Pubkey, edition: u64) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTWORK_PRINT_SEED_PREFIX,
+        &[master_mint.as_ref(), edition.to_string().as_bytes()],
+    )
+}
+
+pub fn find_artwork_print_mint(
+    program_id: &Pubkey,
+    master_mint: &Pubkey,
+    edition: u64,
+) -> (Pubkey, u8) {
+    artwork_print_mint_seeds(master_mint, edition).find(program_id)
+}
+
+/// Seeds of the mint an artwork metadata account describes. Originals are seeded by symbol
+/// and artist, prints by their master artwork mint and edition number.
+pub fn artwork_metadata_mint_seeds(metadata: &ArtworkMetadata) -> PdaSeeds {
+    match &metadata.print {
+        None => artwork_mint_seeds(&metadata.symbol, &metadata.artist),
+        Some(print) => artwork_print_mint_seeds(&print.parent, print.edition),
+    }
+}
+
+pub fn find_artwork_metadata_mint(program_id: &Pubkey, metadata: &ArtworkMetadata) -> (Pubkey, u8) {
+    artwork_metadata_mint_seeds(metadata).find(program_id)
+}
+
+pub fn artwork_metadata_seeds(artwork_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTWORK_METADATA_SEED_PREFIX, &[artwork_mint.as_ref()])
+}
+
+pub fn find_artwork_metadata(program_id: &Pubkey, artwork_mint: &Pubkey) -> (Pubkey, u8) {
+    artwork_metadata_seeds(artwork_mint).find(program_id)
+}
+
+/// Escrow of the artwork's offer_count-th offer. Prints of the same artwork share symbol and
+/// artist, so they are told apart by edition number and master mint.
+pub fn escrow_seeds(metadata: &ArtworkMetadata, offer_count: u32) -> PdaSeeds {
+    let offer_count = offer_count.to_string();
+    match &metadata.print {
+        None => PdaSeeds::new(
+            ARTWORK_ESCROW_SEED_PREFIX,
+            &[
+                metadata.symbol.as_bytes(),
+                offer_count.as_bytes(),
+                metadata.artist.as_ref(),
+            ],
+        ),
+        Some(print) => PdaSeeds::new(
+            ARTWORK_ESCROW_SEED_PREFIX,
+            &[
+                print.edition.to_string().as_bytes(),
+                offer_count.as_bytes(),
+                print.parent.as_ref(),
+            ],
+        ),
+    }
+}
+
+pub fn find_escrow(
+    program_id: &Pubkey,
+    metadata: &ArtworkMetadata,
+    offer_count: u32,
+) -> (Pubkey, u8) {
+    escrow_seeds(metadata, offer_count).find(program_id)
+}
+
+pub fn artwork_swap_seeds(offered_mint: &Pubkey, requested_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(
+        ARTWORK_SWAP_SEED_PREFIX,
+        &[offered_mint.as_ref(), requested_mint.as_ref()],
+    )
+}
+
+pub fn find_artwork_swap(
+    program_id: &Pubkey,
+    offered_mint: &Pubkey,
+    requested_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    artwork_swap_seeds(offered_mint, requested_mint).find(program_id)
+}
+
+/// A bundle is identified by its first artwork mint
+pub fn artwork_bundle_seeds(first_artwork_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTWORK_BUNDLE_SEED_PREFIX, &[first_artwork_mint.as_ref()])
+}
+
+pub fn find_artwork_bundle(program_id: &Pubkey, first_artwork_mint: &Pubkey) -> (Pubkey, u8) {
+    artwork_bundle_seeds(first_artwork_mint).find(program_id)
+}
+
+pub fn artwork_vault_seeds(artwork_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTWORK_VAULT_SEED_PREFIX, &[artwork_mint.as_ref()])
+}
+
+pub fn find_artwork_vault(program_id: &Pubkey, artwork_mint: &Pubkey) -> (Pubkey, u8) {
+    artwork_vault_seeds(artwork_mint).find(program_id)
+}
+
+pub fn artwork_fraction_mint_seeds(artwork_mint: &Pubkey) -> PdaSeeds {
+    PdaSeeds::new(ARTWORK_FRACTION_SEED_PREFIX, &[artwork_mint.as_ref()])
+}
+
+pub fn find_artwork_fraction_mint(program_id: &Pubkey, artwork_mint: &Pubkey) -> (Pubkey, u8) {
+    artwork_fraction_mint_seeds(artwork_mint).find(program_id)
+}
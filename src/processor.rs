@@ -43,9 +43,137 @@ impl Processor {
                 msg!("Cancel Artwork Sale Instruction");
                 cancel_artwork_sale::execute(accounts, program_id)
             }
-            AllovrInstruction::AcceptArtworkSale() => {
+            AllovrInstruction::AcceptArtworkSale(args) => {
                 msg!("Accept Artwork Sale Instruction");
-                accept_artwork_sale::execute(accounts, program_id)
+                accept_artwork_sale::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::TransferArtwork() => {
+                msg!("Transfer Artwork Instruction");
+                transfer_artwork::execute(accounts, program_id)
+            }
+            AllovrInstruction::SyncArtworkHolder() => {
+                msg!("Sync Artwork Holder Instruction");
+                sync_artwork_holder::execute(accounts, program_id)
+            }
+            AllovrInstruction::CreateArtistCollection(args) => {
+                msg!("Create Artist Collection Instruction");
+                create_artist_collection::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::MintArtworkPrint() => {
+                msg!("Mint Artwork Print Instruction");
+                mint_artwork_print::execute(accounts, program_id)
+            }
+            AllovrInstruction::UpdateArtworkMetadata(args) => {
+                msg!("Update Artwork Metadata Instruction");
+                update_artwork_metadata::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::InitArtworkDutchAuction(args) => {
+                msg!("Init Artwork Dutch Auction Instruction");
+                init_artwork_sale::execute_dutch_auction(accounts, program_id, args)
+            }
+            AllovrInstruction::ProposeArtworkSwap(args) => {
+                msg!("Propose Artwork Swap Instruction");
+                propose_artwork_swap::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::AcceptArtworkSwap() => {
+                msg!("Accept Artwork Swap Instruction");
+                accept_artwork_swap::execute(accounts, program_id)
+            }
+            AllovrInstruction::CancelArtworkSwap() => {
+                msg!("Cancel Artwork Swap Instruction");
+                cancel_artwork_swap::execute(accounts, program_id)
+            }
+            AllovrInstruction::InitArtworkBundleSale(args) => {
+                msg!("Init Artwork Bundle Sale Instruction");
+                init_artwork_bundle_sale::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::AcceptArtworkBundleSale(args) => {
+                msg!("Accept Artwork Bundle Sale Instruction");
+                accept_artwork_bundle_sale::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::CancelArtworkBundleSale() => {
+                msg!("Cancel Artwork Bundle Sale Instruction");
+                cancel_artwork_bundle_sale::execute(accounts, program_id)
+            }
+            AllovrInstruction::FractionaliseArtwork(args) => {
+                msg!("Fractionalise Artwork Instruction");
+                fractionalise_artwork::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::BuyoutArtwork(args) => {
+                msg!("Buyout Artwork Instruction");
+                buyout_artwork::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::RedeemArtwork() => {
+                msg!("Redeem Artwork Instruction");
+                redeem_artwork::execute(accounts, program_id)
+            }
+            AllovrInstruction::ClaimFractionProceeds() => {
+                msg!("Claim Fraction Proceeds Instruction");
+                claim_fraction_proceeds::execute(accounts, program_id)
+            }
+            AllovrInstruction::BuyArtistTokens(args) => {
+                msg!("Buy Artist Tokens Instruction");
+                buy_artist_tokens::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::SellArtistTokens(args) => {
+                msg!("Sell Artist Tokens Instruction");
+                sell_artist_tokens::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::SetArtistRevenueShare(args) => {
+                msg!("Set Artist Revenue Share Instruction");
+                set_artist_revenue_share::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::StakeArtistTokens(args) => {
+                msg!("Stake Artist Tokens Instruction");
+                stake_artist_tokens::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::UnstakeArtistTokens(args) => {
+                msg!("Unstake Artist Tokens Instruction");
+                unstake_artist_tokens::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::ClaimArtistRevenue() => {
+                msg!("Claim Artist Revenue Instruction");
+                claim_artist_revenue::execute(accounts, program_id)
+            }
+            AllovrInstruction::ClaimVestedArtistTokens() => {
+                msg!("Claim Vested Artist Tokens Instruction");
+                claim_vested_artist_tokens::execute(accounts, program_id)
+            }
+            AllovrInstruction::AirdropArtistTokens(args) => {
+                msg!("Airdrop Artist Tokens Instruction");
+                airdrop_artist_tokens::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::CreateArtistAirdrop(args) => {
+                msg!("Create Artist Airdrop Instruction");
+                create_artist_airdrop::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::ClaimArtistAirdrop(args) => {
+                msg!("Claim Artist Airdrop Instruction");
+                claim_artist_airdrop::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::DeregisterArtist() => {
+                msg!("Deregister Artist Instruction");
+                deregister_artist::execute(accounts, program_id)
+            }
+            AllovrInstruction::VerifyArtist() => {
+                msg!("Verify Artist Instruction");
+                verify_artist::execute(accounts, program_id, true)
+            }
+            AllovrInstruction::RevokeArtistVerification() => {
+                msg!("Revoke Artist Verification Instruction");
+                verify_artist::execute(accounts, program_id, false)
+            }
+            AllovrInstruction::MigrateAccount() => {
+                msg!("Migrate Account Instruction");
+                migrate_account::execute(accounts, program_id)
+            }
+            AllovrInstruction::SetDaoAuthority(args) => {
+                msg!("Set DAO Authority Instruction");
+                set_dao_authority::execute(accounts, program_id, args)
+            }
+            AllovrInstruction::DistributeArtistCommunityTokens(args) => {
+                msg!("Distribute Artist Community Tokens Instruction");
+                distribute_artist_community_tokens::execute(accounts, program_id, args)
             }
         }
     }
@@ -83,16 +211,72 @@ impl PrintProgramError for AllovrError {
             AllovrError::ArtworkDescriptionMaxLengthExceeded => {
                 msg!("Error: Artwork Description Max Length Exceeded")
             }
+            AllovrError::InvalidArtworkName => msg!("Error: Invalid Artwork Name"),
+            AllovrError::InvalidArtworkUri => msg!("Error: Invalid Artwork Uri"),
+            AllovrError::InvalidSellerFeeBasisPoints => {
+                msg!("Error: Invalid Seller Fee Basis Points")
+            }
+            AllovrError::InvalidMaxEditions => msg!("Error: Invalid Max Editions"),
+            AllovrError::MaxEditionsReached => msg!("Error: Max Editions Reached"),
+            AllovrError::ArtworkIsPrint => msg!("Error: Artwork Is Print"),
+            AllovrError::InvalidArtworkContentUri => msg!("Error: Invalid Artwork Content Uri"),
+            AllovrError::InvalidArtworkContentHash => msg!("Error: Invalid Artwork Content Hash"),
+            AllovrError::InvalidArtworkCreators => msg!("Error: Invalid Artwork Creators"),
+            AllovrError::IncorrectCreatorPaymentAccount => {
+                msg!("Error: Incorrect Creator Payment Account")
+            }
             AllovrError::InvalidArtworkTokenAccount => msg!("Error: Invalid Artwork Token Account"),
             AllovrError::InvalidSystemProgramId => msg!("Error: Invalid System Program Id"),
             AllovrError::InvalidMetaplexMetadataProgramId => {
                 msg!("Error: Invalid Metaplex Metadata Program Id")
             }
+            AllovrError::InvalidCollectionSymbol => msg!("Error: Invalid Collection Symbol"),
+            AllovrError::InvalidCollectionName => msg!("Error: Invalid Collection Name"),
+            AllovrError::InvalidCollectionUri => msg!("Error: Invalid Collection Uri"),
+            AllovrError::MissingCollectionAccounts => msg!("Error: Missing Collection Accounts"),
             AllovrError::ArtworkUnderOffer => msg!("Error: Artwork Under Offer"),
             AllovrError::IncorrectArtworkBuyer => msg!("Error: Incorrect Artwork Buyer"),
             AllovrError::IncorrectArtworkHolder => msg!("Error: Incorrect Artwork Holder"),
             AllovrError::IncorrectPaymentAccount => msg!("Error: Incorrect Payment Account"),
             AllovrError::InvalidOffer => msg!("Error: Invalid Offer"),
+            AllovrError::InvalidDutchAuction => msg!("Error: Invalid Dutch Auction"),
+            AllovrError::DutchAuctionNotStarted => msg!("Error: Dutch Auction Not Started"),
+            AllovrError::PriceAboveMaxPrice => msg!("Error: Price Above Max Price"),
+            AllovrError::InvalidArtworkSwap => msg!("Error: Invalid Artwork Swap"),
+            AllovrError::InvalidArtworkBundle => msg!("Error: Invalid Artwork Bundle"),
+            AllovrError::InvalidArtworkVault => msg!("Error: Invalid Artwork Vault"),
+            AllovrError::ArtworkVaultNotActive => msg!("Error: Artwork Vault Not Active"),
+            AllovrError::ArtworkVaultNotBoughtOut => msg!("Error: Artwork Vault Not Bought Out"),
+            AllovrError::InsufficientFractions => msg!("Error: Insufficient Fractions"),
+            AllovrError::InvalidBondingCurve => msg!("Error: Invalid Bonding Curve"),
+            AllovrError::PriceBelowMinPrice => msg!("Error: Price Below Min Price"),
+            AllovrError::InvalidRevenueShare => msg!("Error: Invalid Revenue Share"),
+            AllovrError::InsufficientStake => msg!("Error: Insufficient Stake"),
+            AllovrError::InvalidArtistTokenAllocation => {
+                msg!("Error: Invalid Artist Token Allocation")
+            }
+            AllovrError::InvalidVestingSchedule => msg!("Error: Invalid Vesting Schedule"),
+            AllovrError::NothingVested => msg!("Error: Nothing Vested"),
+            AllovrError::InsufficientMarketInventory => {
+                msg!("Error: Insufficient Market Inventory")
+            }
+            AllovrError::InvalidAirdrop => msg!("Error: Invalid Airdrop"),
+            AllovrError::InvalidMerkleProof => msg!("Error: Invalid Merkle Proof"),
+            AllovrError::AirdropAlreadyClaimed => msg!("Error: Airdrop Already Claimed"),
+            AllovrError::ArtistInactive => msg!("Error: Artist Inactive"),
+            AllovrError::InvalidDaoAuthority => msg!("Error: Invalid DAO Authority"),
+            AllovrError::VerifiedArtistNameLocked => msg!("Error: Verified Artist Name Locked"),
+            AllovrError::ArtistSymbolTaken => msg!("Error: Artist Symbol Taken"),
+            AllovrError::ArtistSymbolLocked => msg!("Error: Artist Symbol Locked"),
+            AllovrError::InvalidArtistProfile => msg!("Error: Invalid Artist Profile"),
+            AllovrError::InvalidAccountKey => msg!("Error: Invalid Account Key"),
+            AllovrError::AccountNeedsMigration => msg!("Error: Account Needs Migration"),
+            AllovrError::AccountAlreadyMigrated => msg!("Error: Account Already Migrated"),
+            AllovrError::AccountNotWritable => msg!("Error: Account Not Writable"),
+            AllovrError::MissingArtistWallet => msg!("Error: Missing Artist Wallet"),
+            AllovrError::InsufficientCommunityTokens => {
+                msg!("Error: Insufficient Community Tokens")
+            }
             AllovrError::InflationNotDue => msg!("Error: Inflation Not Due"),
             AllovrError::IncorrectFounderAddress => msg!("Error: Incorrect founder address"),
             AllovrError::ManualFail => msg!("MANUAL FAIL"),
@@ -0,0 +1,272 @@
+use crate::instruction::AcceptArtworkSaleArgs;
+use crate::state::{ArtistMetadata, ArtworkBundle, ArtworkMetadata, Currency, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    buyer_wallet: &'a AccountInfo<'b>,
+    buyer_payment: &'a AccountInfo<'b>,
+    seller_wallet: &'a AccountInfo<'b>,
+    payment_account: &'a AccountInfo<'b>,
+    bundle: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    // artwork accounts in groups of 7, then creator payment accounts
+    remaining: &'a [AccountInfo<'b>],
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: AcceptArtworkSaleArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let bundle = ArtworkBundle::load(a.bundle, program_id)?;
+
+    if bundle.artworks.is_empty() {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    assert_pda_with_bump(
+        a.bundle,
+        program_id,
+        &pda::artwork_bundle_seeds(&bundle.artworks[0]).as_slices(),
+        bundle.bump,
+    )?;
+
+    if let Some(buyer) = bundle.offered_to {
+        if *a.buyer_wallet.key != buyer {
+            return Err(AllovrError::IncorrectArtworkBuyer.into());
+        }
+    }
+
+    if *a.seller_wallet.key != bundle.seller {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if *a.payment_account.key != bundle.payment_account {
+        return Err(AllovrError::IncorrectPaymentAccount.into());
+    }
+
+    if bundle.price > args.max_price {
+        return Err(AllovrError::PriceAboveMaxPrice.into());
+    }
+
+    match bundle.currency {
+        Currency::SOL => {
+            msg!("Checking buyer and seller payment accounts are SOL accounts...");
+            assert_owned_by(a.buyer_payment, a.system.key)?;
+            assert_owned_by(a.payment_account, a.system.key)?;
+        }
+        Currency::AOVR => {
+            msg!("Checking buyer and seller payment accounts are AOVR accounts...");
+            assert_ata_mint_and_owner(
+                a.buyer_payment,
+                Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                *a.buyer_wallet.key,
+            )?;
+            assert_ata_mint_and_owner(
+                a.payment_account,
+                Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                *a.seller_wallet.key,
+            )?;
+        }
+    }
+
+    let artwork_account_count = bundle.artworks.len() * 7;
+    if a.remaining.len() < artwork_account_count {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    let (artwork_accounts, creator_payment_accounts) = a.remaining.split_at(artwork_account_count);
+    let creator_payment_iter = &mut creator_payment_accounts.iter();
+
+    // each artwork's creators are paid from an even split of the bundle price, the first
+    // artwork takes any remainder
+    let artwork_count = bundle.artworks.len() as u64;
+    let mut seller_amount = bundle.price;
+
+    for (i, (artwork, bundle_mint)) in artwork_accounts
+        .chunks(7)
+        .zip(bundle.artworks.iter())
+        .enumerate()
+    {
+        let buyer_artwork_ata = &artwork[0];
+        let artwork_mint = &artwork[1];
+        let artwork_meta = &artwork[2];
+        let escrow = &artwork[3];
+        let artist_revenue_vault = &artwork[4];
+        let artist_revenue_vault_aovr = &artwork[5];
+        let artist_meta = &artwork[6];
+
+        let mut metadata = ArtworkMetadata::load(artwork_meta, program_id)?;
+
+        if metadata.bundle != Some(*a.bundle.key) || *artwork_mint.key != *bundle_mint {
+            return Err(AllovrError::InvalidArtworkBundle.into());
+        }
+
+        let artwork_mint_pda = assert_pda_with_bump(
+            artwork_mint,
+            program_id,
+            &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+            metadata.mint_bump,
+        )?;
+
+        assert_pda_with_bump(
+            artwork_meta,
+            program_id,
+            &pda::artwork_metadata_seeds(artwork_mint.key).as_slices(),
+            metadata.bump,
+        )?;
+
+        let mut artwork_price = bundle.price / artwork_count;
+        if i == 0 {
+            artwork_price += bundle.price % artwork_count;
+        }
+
+        let creator_payouts = calculate_creator_payouts(
+            artwork_price,
+            &metadata.creators,
+            metadata.seller_fee_basis_points,
+            !metadata.primary_sale_happened,
+        )?;
+
+        let mut revenue_share = load_artist_revenue_share(
+            program_id,
+            &metadata.artist,
+            &bundle.currency,
+            artist_revenue_vault,
+            artist_revenue_vault_aovr,
+        )?;
+
+        for (creator, amount) in metadata.creators.iter().zip(creator_payouts) {
+            let creator_payment = next_account_info(creator_payment_iter)
+                .map_err(|_| AllovrError::IncorrectCreatorPaymentAccount)?;
+            assert_creator_payment_account(creator_payment, &bundle.currency, creator.address)?;
+
+            let creator_amount = pay_artist_revenue_share(
+                revenue_share.as_mut(),
+                &creator.address,
+                a.token_program,
+                a.system,
+                a.buyer_wallet,
+                a.buyer_payment,
+                amount,
+            )?;
+
+            transfer_payment(
+                &bundle.currency,
+                a.token_program,
+                a.system,
+                a.buyer_wallet,
+                a.buyer_payment,
+                creator_payment,
+                creator_amount,
+            )?;
+            seller_amount -= amount;
+        }
+
+        if buyer_artwork_ata.data_is_empty() {
+            create_ata(
+                a.buyer_wallet,
+                a.buyer_wallet,
+                buyer_artwork_ata,
+                artwork_mint,
+                a.rent_sysvar,
+                AllovrError::InvalidArtworkTokenAccount,
+            )?;
+        } else {
+            assert_ata_mint_and_owner(buyer_artwork_ata, artwork_mint_pda, *a.buyer_wallet.key)?;
+        }
+
+        let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+        let escrow_bump = metadata
+            .escrow_bump
+            .ok_or(AllovrError::InvalidArtworkBundle)?;
+
+        assert_pda_with_bump(escrow, program_id, &escrow_seeds.as_slices(), escrow_bump)?;
+
+        msg!("Sending artwork {} to buyer...", artwork_mint_pda);
+        release_escrow(
+            a.token_program,
+            escrow,
+            buyer_artwork_ata,
+            a.seller_wallet,
+            &escrow_seeds.with_bump(escrow_bump).as_slices(),
+        )?;
+
+        metadata.bundle = None;
+        metadata.escrow_bump = None;
+        metadata.set_holder(*a.buyer_wallet.key);
+        metadata.save(artwork_meta)?;
+        ArtistMetadata::count_open_offer(artist_meta, program_id, &metadata.artist, false)?;
+    }
+
+    if creator_payment_iter.next().is_some() {
+        return Err(AllovrError::IncorrectCreatorPaymentAccount.into());
+    }
+
+    msg!("Paying seller...");
+    transfer_payment(
+        &bundle.currency,
+        a.token_program,
+        a.system,
+        a.buyer_wallet,
+        a.buyer_payment,
+        a.payment_account,
+        seller_amount,
+    )?;
+
+    msg!("Closing artwork bundle account...");
+    close_program_account(a.bundle, a.seller_wallet)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        buyer_wallet: next_account_info(account_iter)?,
+        buyer_payment: next_account_info(account_iter)?,
+        seller_wallet: next_account_info(account_iter)?,
+        payment_account: next_account_info(account_iter)?,
+        bundle: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        remaining: account_iter.as_slice(),
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.buyer_wallet, &[Signer, Writable]),
+            (a.seller_wallet, &[Writable]),
+            (a.bundle, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
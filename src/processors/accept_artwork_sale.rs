@@ -1,14 +1,16 @@
-use crate::state::ArtworkMetadata;
-use crate::state::Currency;
+use crate::instruction::AcceptArtworkSaleArgs;
+use crate::state::{ArtistMetadata, ArtworkMetadata};
+use crate::state::{Currency, ProgramAccount};
 use crate::ALLOVR_MINT_ID;
-use crate::ARTWORK_ESCROW_SEED_PREFIX;
-use crate::{error::AllovrError, utils::*, ARTWORK_SEED_PREFIX};
-use borsh::BorshSerialize;
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
 use solana_program::msg;
 use solana_program::program::invoke_signed;
 use std::str::FromStr;
 
-use solana_program::borsh::try_from_slice_unchecked;
 use solana_program::program::invoke;
 
 use solana_program::{
@@ -16,6 +18,7 @@ use solana_program::{
     entrypoint::ProgramResult,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
 };
 
 struct Accounts<'a, 'b: 'a> {
@@ -28,15 +31,34 @@ struct Accounts<'a, 'b: 'a> {
     escrow: &'a AccountInfo<'b>,
     token_program: &'a AccountInfo<'b>,
     system_program: &'a AccountInfo<'b>,
+    artist_revenue_vault: &'a AccountInfo<'b>,
+    artist_revenue_vault_aovr: &'a AccountInfo<'b>,
+    artist_meta: &'a AccountInfo<'b>,
+    creator_payment_accounts: Vec<&'a AccountInfo<'b>>,
 }
 
-pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: AcceptArtworkSaleArgs,
+) -> ProgramResult {
     let a = parse_accounts(program_id, accounts)?;
-    let mut metadata: ArtworkMetadata =
-        try_from_slice_unchecked(&a.artwork_meta.data.borrow_mut())?;
+    // the artwork mint is not passed, the metadata PDA is checked against the mint it records
+    let (mut metadata, artwork_mint_pda) =
+        ArtworkMetadata::load_with_mint(a.artwork_meta, program_id)?;
+
+    if metadata.offer_currency == None
+        || metadata.offer_price == None
+        || metadata.payment_account == None
+    {
+        return Err(AllovrError::InvalidOffer.into());
+    }
 
-    if *a.buyer_wallet.key != metadata.offered_to.unwrap() {
-        return Err(AllovrError::IncorrectArtworkBuyer.into());
+    // Dutch auctions are open to any buyer
+    if let Some(buyer) = metadata.offered_to {
+        if *a.buyer_wallet.key != buyer {
+            return Err(AllovrError::IncorrectArtworkBuyer.into());
+        }
     }
 
     if *a.artwork_holder.key != metadata.holder {
@@ -47,50 +69,38 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(AllovrError::IncorrectPaymentAccount.into());
     }
 
-    if metadata.offer_currency == None
-        || metadata.offer_price == None
-        || metadata.payment_account == None
-    {
-        return Err(AllovrError::InvalidOffer.into());
-    }
-
     // Check the buyer artwork ATA belong to the buyer wallet and the correct artwork token
-    let (artwork_mint_pda, _artwork_mint_bump) = Pubkey::find_program_address(
-        &[
-            ARTWORK_SEED_PREFIX.as_bytes(),
-            metadata.symbol.as_bytes(),
-            metadata.artist.as_ref(),
-        ],
-        &program_id,
-    );
-
     msg!("Checking buyer artwork ATA has correct artwork mint and is owned by buyer account...");
     assert_ata_mint_and_owner(&a.buyer_artwork_ata, artwork_mint_pda, *a.buyer_wallet.key)?;
 
     // Take payment from buyer
-    match metadata.offer_currency.unwrap() {
+    let price = match &metadata.dutch_auction {
+        Some(auction) => {
+            let now = Clock::get()?.unix_timestamp;
+            if now < auction.start_time {
+                return Err(AllovrError::DutchAuctionNotStarted.into());
+            }
+            auction.price_at(now)
+        }
+        None => metadata.offer_price.unwrap(),
+    };
+
+    if price > args.max_price {
+        return Err(AllovrError::PriceAboveMaxPrice.into());
+    }
+
+    let currency = metadata.offer_currency.clone().unwrap();
+    match currency {
         Currency::SOL => {
             msg!("Checking buyer payment account is SOL account...");
-            assert_owned_by(&a.buyer_payment, a.system_program.key)?;
+            assert_owned_by(a.buyer_payment, a.system_program.key)?;
             msg!("Checking seller payment account is SOL account...");
-            assert_owned_by(&a.payment_account, a.system_program.key)?;
-            invoke(
-                &solana_program::system_instruction::transfer(
-                    &a.buyer_payment.key,
-                    a.payment_account.key,
-                    metadata.offer_price.unwrap(),
-                ),
-                &[
-                    a.buyer_payment.clone(),
-                    a.payment_account.clone(),
-                    a.system_program.clone(),
-                ],
-            )?;
+            assert_owned_by(a.payment_account, a.system_program.key)?;
         }
         Currency::AOVR => {
             msg!("Checking buyer payment account is AOVR account owned by buyer wallet account...");
             assert_ata_mint_and_owner(
-                &a.buyer_payment,
+                a.buyer_payment,
                 Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
                 *a.buyer_wallet.key,
             )?;
@@ -98,42 +108,84 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
                 "Checking seller payment account is AOVR account owned by seller wallet account..."
             );
             assert_ata_mint_and_owner(
-                &a.payment_account,
+                a.payment_account,
                 Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
                 *a.artwork_holder.key,
             )?;
-
-            transfer_token(
-                &a.token_program,
-                &a.buyer_payment,
-                &a.payment_account,
-                &a.buyer_wallet,
-                metadata.offer_price.unwrap(),
-            )?;
         }
     }
 
-    let offer_count_string = metadata.offer_count.to_string();
+    // Creators get the whole price on the primary sale and their royalty on secondary
+    // sales, split by share. The seller gets the remainder.
+    let creator_payouts = calculate_creator_payouts(
+        price,
+        &metadata.creators,
+        metadata.seller_fee_basis_points,
+        !metadata.primary_sale_happened,
+    )?;
 
-    msg!("Checking escrow PDA is correct...");
-    let (escrow_pda, escrow_bump) = assert_pda(
-        &a.escrow,
+    if a.creator_payment_accounts.len() != metadata.creators.len() {
+        return Err(AllovrError::IncorrectCreatorPaymentAccount.into());
+    }
+
+    // If the artist opted in to revenue sharing and tokens are staked, part of the artist's
+    // payout goes to the revenue vault for artist token holders
+    let mut revenue_share = load_artist_revenue_share(
         program_id,
-        &[
-            ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-            metadata.symbol.as_bytes(),
-            offer_count_string.as_bytes(),
-            metadata.artist.as_ref(),
-        ],
+        &metadata.artist,
+        &currency,
+        a.artist_revenue_vault,
+        a.artist_revenue_vault_aovr,
     )?;
 
-    let escrow_signer_seeds = &[
-        ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-        metadata.symbol.as_bytes(),
-        offer_count_string.as_bytes(),
-        metadata.artist.as_ref(),
-        &[escrow_bump],
-    ];
+    let mut seller_amount = price;
+    for ((creator, amount), creator_payment) in metadata
+        .creators
+        .iter()
+        .zip(creator_payouts)
+        .zip(a.creator_payment_accounts.iter())
+    {
+        msg!("Checking creator payment account...");
+        match currency {
+            Currency::SOL => {
+                if *creator_payment.key != creator.address {
+                    return Err(AllovrError::IncorrectCreatorPaymentAccount.into());
+                }
+            }
+            Currency::AOVR => {
+                assert_ata_mint_and_owner(
+                    creator_payment,
+                    Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                    creator.address,
+                )?;
+            }
+        }
+
+        let creator_amount = pay_artist_revenue_share(
+            revenue_share.as_mut(),
+            &creator.address,
+            a.token_program,
+            a.system_program,
+            a.buyer_wallet,
+            a.buyer_payment,
+            amount,
+        )?;
+
+        pay(&a, &currency, creator_payment, creator_amount)?;
+        seller_amount -= amount;
+    }
+
+    pay(&a, &currency, a.payment_account, seller_amount)?;
+
+    let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+    let escrow_bump = metadata.escrow_bump.ok_or(AllovrError::InvalidOffer)?;
+
+    msg!("Checking escrow PDA is correct...");
+    let escrow_pda =
+        assert_pda_with_bump(a.escrow, program_id, &escrow_seeds.as_slices(), escrow_bump)?;
+
+    let escrow_signer = escrow_seeds.with_bump(escrow_bump);
+    let escrow_signer_seeds = &escrow_signer.as_slices()[..];
 
     // send artwork to buyer ATA
     msg!("Sending artwork to buyer...");
@@ -181,14 +233,50 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
     metadata.offer_price = None;
     metadata.offer_currency = None;
     metadata.payment_account = None;
-    metadata.holder = *a.buyer_wallet.key;
+    metadata.dutch_auction = None;
+    metadata.escrow_bump = None;
+    metadata.set_holder(*a.buyer_wallet.key);
 
     msg!("Setting artwork metadata...");
-    metadata.serialize(&mut &mut a.artwork_meta.data.borrow_mut()[..])?;
+    metadata.save(a.artwork_meta)?;
+    ArtistMetadata::count_open_offer(a.artist_meta, program_id, &metadata.artist, false)?;
 
     Ok(())
 }
 
+fn pay<'a, 'b: 'a>(
+    a: &Accounts<'a, 'b>,
+    currency: &Currency,
+    recipient: &'a AccountInfo<'b>,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match currency {
+        Currency::SOL => invoke(
+            &solana_program::system_instruction::transfer(
+                a.buyer_payment.key,
+                recipient.key,
+                amount,
+            ),
+            &[
+                a.buyer_payment.clone(),
+                recipient.clone(),
+                a.system_program.clone(),
+            ],
+        ),
+        Currency::AOVR => transfer_token(
+            a.token_program,
+            a.buyer_payment,
+            recipient,
+            a.buyer_wallet,
+            amount,
+        ),
+    }
+}
+
 fn parse_accounts<'a, 'b: 'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'b>],
@@ -204,14 +292,23 @@ fn parse_accounts<'a, 'b: 'a>(
         escrow: next_account_info(account_iter)?,
         token_program: next_account_info(account_iter)?,
         system_program: next_account_info(account_iter)?,
+        artist_revenue_vault: next_account_info(account_iter)?,
+        artist_revenue_vault_aovr: next_account_info(account_iter)?,
+        artist_meta: next_account_info(account_iter)?,
+        creator_payment_accounts: account_iter.collect(),
     };
 
-    assert_program_id(&program_id)?;
-    assert_system(&a.system_program)?;
-    assert_signer(&a.buyer_wallet)?;
-    assert_token_program_matches_package(&a.token_program)?;
-
-    // TODO: More checks!
+    validate_accounts(
+        program_id,
+        &[
+            (a.buyer_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.escrow, &[Owner(&spl_token::id()), Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.system_program, &[SystemProgram]),
+            (a.artist_meta, &[Writable]),
+        ],
+    )?;
 
     Ok(a)
 }
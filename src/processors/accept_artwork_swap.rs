@@ -0,0 +1,326 @@
+use crate::state::{
+    ArtistMetadata, ArtworkMetadata, ArtworkSwap, ArtworkSwapTopUp, ArtworkSwapTopUpPayer,
+    Currency, ProgramAccount,
+};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use solana_program::program::invoke;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    acceptor_wallet: &'a AccountInfo<'b>,
+    acceptor_requested_ata: &'a AccountInfo<'b>,
+    acceptor_offered_ata: &'a AccountInfo<'b>,
+    proposer_wallet: &'a AccountInfo<'b>,
+    proposer_requested_ata: &'a AccountInfo<'b>,
+    offered_mint: &'a AccountInfo<'b>,
+    offered_meta: &'a AccountInfo<'b>,
+    requested_mint: &'a AccountInfo<'b>,
+    requested_meta: &'a AccountInfo<'b>,
+    escrow: &'a AccountInfo<'b>,
+    swap: &'a AccountInfo<'b>,
+    acceptor_payment: &'a AccountInfo<'b>,
+    proposer_payment: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    offered_artist_meta: &'a AccountInfo<'b>,
+    swap_aovr_ata: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut offered = ArtworkMetadata::load(a.offered_meta, program_id)?;
+    let mut requested = ArtworkMetadata::load(a.requested_meta, program_id)?;
+    let swap = ArtworkSwap::load(a.swap, program_id)?;
+
+    msg!("Checking artwork swap...");
+    assert_pda_with_bump(
+        a.swap,
+        program_id,
+        &pda::artwork_swap_seeds(a.offered_mint.key, a.requested_mint.key).as_slices(),
+        swap.bump,
+    )?;
+
+    if offered.swap != Some(*a.swap.key)
+        || swap.offered_mint != *a.offered_mint.key
+        || swap.requested_mint != *a.requested_mint.key
+    {
+        return Err(AllovrError::InvalidArtworkSwap.into());
+    }
+
+    if *a.proposer_wallet.key != swap.proposer || *a.proposer_wallet.key != offered.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    // the requested artwork may have changed hands since the swap was proposed
+    if *a.acceptor_wallet.key != swap.counterparty || *a.acceptor_wallet.key != requested.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if requested.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    msg!("Checking artwork mint and metadata PDAs...");
+    let offered_mint_pda = assert_pda_with_bump(
+        a.offered_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&offered).as_slices(),
+        offered.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.offered_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.offered_mint.key).as_slices(),
+        offered.bump,
+    )?;
+
+    let requested_mint_pda = assert_pda_with_bump(
+        a.requested_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&requested).as_slices(),
+        requested.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.requested_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.requested_mint.key).as_slices(),
+        requested.bump,
+    )?;
+
+    if let Some(top_up) = &swap.top_up {
+        if *a.proposer_payment.key != top_up.payment_account {
+            return Err(AllovrError::IncorrectPaymentAccount.into());
+        }
+
+        match top_up.payer {
+            ArtworkSwapTopUpPayer::Counterparty => {
+                msg!("Paying swap top-up...");
+                pay_top_up(&a, top_up)?;
+            }
+            ArtworkSwapTopUpPayer::Proposer => {
+                msg!("Releasing proposer top-up to acceptor...");
+                release_top_up(&a, &swap, top_up)?;
+            }
+        }
+    }
+
+    msg!("Sending requested artwork to proposer...");
+    assert_ata_mint_and_owner(
+        a.acceptor_requested_ata,
+        requested_mint_pda,
+        *a.acceptor_wallet.key,
+    )?;
+
+    if a.proposer_requested_ata.data_is_empty() {
+        create_ata(
+            a.acceptor_wallet,
+            a.proposer_wallet,
+            a.proposer_requested_ata,
+            a.requested_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.proposer_requested_ata,
+            requested_mint_pda,
+            *a.proposer_wallet.key,
+        )?;
+    }
+
+    transfer_token(
+        a.token_program,
+        a.acceptor_requested_ata,
+        a.proposer_requested_ata,
+        a.acceptor_wallet,
+        1,
+    )?;
+
+    msg!("Sending offered artwork to acceptor...");
+    if a.acceptor_offered_ata.data_is_empty() {
+        create_ata(
+            a.acceptor_wallet,
+            a.acceptor_wallet,
+            a.acceptor_offered_ata,
+            a.offered_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.acceptor_offered_ata,
+            offered_mint_pda,
+            *a.acceptor_wallet.key,
+        )?;
+    }
+
+    let escrow_seeds = pda::escrow_seeds(&offered, offered.offer_count);
+    let escrow_bump = offered.escrow_bump.ok_or(AllovrError::InvalidArtworkSwap)?;
+
+    assert_pda_with_bump(a.escrow, program_id, &escrow_seeds.as_slices(), escrow_bump)?;
+
+    release_escrow(
+        a.token_program,
+        a.escrow,
+        a.acceptor_offered_ata,
+        a.proposer_wallet,
+        &escrow_seeds.with_bump(escrow_bump).as_slices(),
+    )?;
+
+    msg!("Closing artwork swap account...");
+    close_program_account(a.swap, a.proposer_wallet)?;
+
+    offered.swap = None;
+    offered.escrow_bump = None;
+    offered.set_holder(*a.acceptor_wallet.key);
+    requested.set_holder(*a.proposer_wallet.key);
+
+    msg!("Setting artwork metadata...");
+    offered.save(a.offered_meta)?;
+    requested.save(a.requested_meta)?;
+    ArtistMetadata::count_open_offer(a.offered_artist_meta, program_id, &offered.artist, false)?;
+
+    Ok(())
+}
+
+fn pay_top_up<'a, 'b: 'a>(a: &Accounts<'a, 'b>, top_up: &ArtworkSwapTopUp) -> ProgramResult {
+    match top_up.currency {
+        Currency::SOL => {
+            assert_owned_by(a.acceptor_payment, a.system.key)?;
+            assert_owned_by(a.proposer_payment, a.system.key)?;
+            invoke(
+                &solana_program::system_instruction::transfer(
+                    a.acceptor_payment.key,
+                    a.proposer_payment.key,
+                    top_up.amount,
+                ),
+                &[
+                    a.acceptor_payment.clone(),
+                    a.proposer_payment.clone(),
+                    a.system.clone(),
+                ],
+            )
+        }
+        Currency::AOVR => {
+            assert_ata_mint_and_owner(
+                a.acceptor_payment,
+                Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                *a.acceptor_wallet.key,
+            )?;
+            assert_ata_mint_and_owner(
+                a.proposer_payment,
+                Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                *a.proposer_wallet.key,
+            )?;
+            transfer_token(
+                a.token_program,
+                a.acceptor_payment,
+                a.proposer_payment,
+                a.acceptor_wallet,
+                top_up.amount,
+            )
+        }
+    }
+}
+
+/// Pays out the top-up ProposeArtworkSwap took from the proposer
+fn release_top_up<'a, 'b: 'a>(
+    a: &Accounts<'a, 'b>,
+    swap: &ArtworkSwap,
+    top_up: &ArtworkSwapTopUp,
+) -> ProgramResult {
+    match top_up.currency {
+        Currency::SOL => {
+            assert_owned_by(a.acceptor_payment, a.system.key)?;
+            **a.swap.lamports.borrow_mut() = a
+                .swap
+                .lamports()
+                .checked_sub(top_up.amount)
+                .ok_or(AllovrError::InvalidArtworkSwap)?;
+            **a.acceptor_payment.lamports.borrow_mut() = a
+                .acceptor_payment
+                .lamports()
+                .checked_add(top_up.amount)
+                .ok_or(AllovrError::InvalidArtworkSwap)?;
+            Ok(())
+        }
+        Currency::AOVR => {
+            let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+            assert_ata_mint_and_owner(a.acceptor_payment, aovr_mint, *a.acceptor_wallet.key)?;
+            assert_ata_mint_and_owner(a.swap_aovr_ata, aovr_mint, *a.swap.key)?;
+            release_token_account(
+                a.token_program,
+                a.swap_aovr_ata,
+                a.acceptor_payment,
+                a.proposer_wallet,
+                a.swap,
+                &pda::artwork_swap_seeds(&swap.offered_mint, &swap.requested_mint)
+                    .with_bump(swap.bump)
+                    .as_slices(),
+            )
+        }
+    }
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        acceptor_wallet: next_account_info(account_iter)?,
+        acceptor_requested_ata: next_account_info(account_iter)?,
+        acceptor_offered_ata: next_account_info(account_iter)?,
+        proposer_wallet: next_account_info(account_iter)?,
+        proposer_requested_ata: next_account_info(account_iter)?,
+        offered_mint: next_account_info(account_iter)?,
+        offered_meta: next_account_info(account_iter)?,
+        requested_mint: next_account_info(account_iter)?,
+        requested_meta: next_account_info(account_iter)?,
+        escrow: next_account_info(account_iter)?,
+        swap: next_account_info(account_iter)?,
+        acceptor_payment: next_account_info(account_iter)?,
+        proposer_payment: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        offered_artist_meta: next_account_info(account_iter)?,
+        swap_aovr_ata: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.acceptor_wallet, &[Signer, Writable]),
+            (a.proposer_wallet, &[Writable]),
+            (a.offered_meta, &[ProgramOwned, Writable]),
+            (a.requested_meta, &[ProgramOwned, Writable]),
+            (a.swap, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+            (a.offered_artist_meta, &[Writable]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,139 @@
+use crate::instruction::AirdropArtistTokensArgs;
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_AIRDROP_MAX_RECIPIENTS,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct RecipientAccounts<'a, 'b: 'a> {
+    wallet: &'a AccountInfo<'b>,
+    artist_token_ata: &'a AccountInfo<'b>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_artist_token: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    recipients: Vec<RecipientAccounts<'a, 'b>>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: AirdropArtistTokensArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    if a.recipients.is_empty()
+        || a.recipients.len() > ARTIST_AIRDROP_MAX_RECIPIENTS
+        || a.recipients.len() != args.amounts.len()
+    {
+        return Err(AllovrError::InvalidAirdrop.into());
+    }
+
+    msg!("Checking artist token mint PDA...");
+    let (artist_token_mint_pda, _artist_token_mint_bump) = assert_pda(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(a.artist_wallet.key).as_slices(),
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.artist_artist_token,
+        artist_token_mint_pda,
+        *a.artist_wallet.key,
+    )?;
+
+    for (recipient, amount) in a.recipients.iter().zip(args.amounts) {
+        if recipient.artist_token_ata.data_is_empty() {
+            create_ata(
+                a.artist_wallet,
+                recipient.wallet,
+                recipient.artist_token_ata,
+                a.artist_token_mint,
+                a.rent_sysvar,
+                AllovrError::InvalidArtistTokenAccount,
+            )?;
+        } else {
+            assert_ata_mint_and_owner(
+                recipient.artist_token_ata,
+                artist_token_mint_pda,
+                *recipient.wallet.key,
+            )?;
+        }
+
+        msg!("Airdropping {} to {}...", amount, recipient.wallet.key);
+        transfer_token(
+            a.token_program,
+            a.artist_artist_token,
+            recipient.artist_token_ata,
+            a.artist_wallet,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let artist_wallet = next_account_info(account_iter)?;
+    let artist_artist_token = next_account_info(account_iter)?;
+    let artist_token_mint = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let associated_token_account_program = next_account_info(account_iter)?;
+    let rent_sysvar = next_account_info(account_iter)?;
+    let system = next_account_info(account_iter)?;
+
+    let remaining = account_iter.as_slice();
+    if remaining.len() % 2 != 0 {
+        return Err(AllovrError::InvalidAirdrop.into());
+    }
+
+    let recipients = remaining
+        .chunks(2)
+        .map(|x| RecipientAccounts {
+            wallet: &x[0],
+            artist_token_ata: &x[1],
+        })
+        .collect();
+
+    let a = Accounts {
+        artist_wallet,
+        artist_artist_token,
+        artist_token_mint,
+        token_program,
+        associated_token_account_program,
+        rent_sysvar,
+        system,
+        recipients,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,160 @@
+use crate::instruction::BuyArtistTokensArgs;
+use crate::state::{ArtistMarket, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    buyer_wallet: &'a AccountInfo<'b>,
+    buyer_aovr_ata: &'a AccountInfo<'b>,
+    buyer_artist_ata: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_market: &'a AccountInfo<'b>,
+    artist_market_aovr_reserve: &'a AccountInfo<'b>,
+    artist_market_artist_token: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: BuyArtistTokensArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut market = ArtistMarket::load(a.artist_market, program_id)?;
+
+    if market.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidBondingCurve.into());
+    }
+
+    if !market.active {
+        return Err(AllovrError::ArtistInactive.into());
+    }
+
+    msg!("Checking artist mint and market PDAs...");
+    let artist_token_mint_pda = assert_pda_with_bump(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(&market.artist).as_slices(),
+        market.mint_bump,
+    )?;
+
+    let artist_market_pda = assert_pda_with_bump(
+        a.artist_market,
+        program_id,
+        &pda::artist_market_seeds(a.artist_token_mint.key).as_slices(),
+        market.bump,
+    )?;
+
+    let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+    assert_ata_mint_and_owner(a.buyer_aovr_ata, aovr_mint, *a.buyer_wallet.key)?;
+    assert_ata_mint_and_owner(a.artist_market_aovr_reserve, aovr_mint, artist_market_pda)?;
+    assert_ata_mint_and_owner(
+        a.artist_market_artist_token,
+        artist_token_mint_pda,
+        artist_market_pda,
+    )?;
+
+    if get_token_account(a.artist_market_artist_token)?.amount < args.amount {
+        return Err(AllovrError::InsufficientMarketInventory.into());
+    }
+
+    let cost = calculate_bonding_curve_cost(&market.curve, market.supply, args.amount, true)?;
+    if cost > args.max_cost {
+        return Err(AllovrError::PriceAboveMaxPrice.into());
+    }
+
+    msg!("Paying {} AOVR into the market reserve...", cost);
+    transfer_token(
+        a.token_program,
+        a.buyer_aovr_ata,
+        a.artist_market_aovr_reserve,
+        a.buyer_wallet,
+        cost,
+    )?;
+
+    if a.buyer_artist_ata.data_is_empty() {
+        create_ata(
+            a.buyer_wallet,
+            a.buyer_wallet,
+            a.buyer_artist_ata,
+            a.artist_token_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtistTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.buyer_artist_ata,
+            artist_token_mint_pda,
+            *a.buyer_wallet.key,
+        )?;
+    }
+
+    msg!("Sending {} artist tokens to buyer...", args.amount);
+    transfer_token_signed(
+        a.token_program,
+        a.artist_market_artist_token,
+        a.buyer_artist_ata,
+        a.artist_market,
+        args.amount,
+        &pda::artist_market_seeds(a.artist_token_mint.key)
+            .with_bump(market.bump)
+            .as_slices(),
+    )?;
+
+    market.supply = market
+        .supply
+        .checked_add(args.amount)
+        .ok_or(AllovrError::InvalidBondingCurve)?;
+    market.save(a.artist_market)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        buyer_wallet: next_account_info(account_iter)?,
+        buyer_aovr_ata: next_account_info(account_iter)?,
+        buyer_artist_ata: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_market: next_account_info(account_iter)?,
+        artist_market_aovr_reserve: next_account_info(account_iter)?,
+        artist_market_artist_token: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.buyer_wallet, &[Signer, Writable]),
+            (a.artist_market, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
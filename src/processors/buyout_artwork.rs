@@ -0,0 +1,246 @@
+use crate::instruction::AcceptArtworkSaleArgs;
+use crate::state::{ArtworkMetadata, ArtworkVault, ArtworkVaultState, Currency, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    buyer_wallet: &'a AccountInfo<'b>,
+    buyer_payment: &'a AccountInfo<'b>,
+    buyer_artwork_ata: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    vault: &'a AccountInfo<'b>,
+    vault_artwork_ata: &'a AccountInfo<'b>,
+    vault_aovr_ata: &'a AccountInfo<'b>,
+    aovr_mint: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    artist_revenue_vault: &'a AccountInfo<'b>,
+    artist_revenue_vault_aovr: &'a AccountInfo<'b>,
+    creator_payment_accounts: Vec<&'a AccountInfo<'b>>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: AcceptArtworkSaleArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+    let mut vault = ArtworkVault::load(a.vault, program_id)?;
+
+    if vault.state != ArtworkVaultState::Active {
+        return Err(AllovrError::ArtworkVaultNotActive.into());
+    }
+
+    if vault.artwork_mint != *a.artwork_mint.key || metadata.holder != *a.vault.key {
+        return Err(AllovrError::InvalidArtworkVault.into());
+    }
+
+    if vault.reserve_price > args.max_price {
+        return Err(AllovrError::PriceAboveMaxPrice.into());
+    }
+
+    msg!("Checking artwork and vault PDAs...");
+    let artwork_mint_pda = assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    let vault_pda = assert_pda_with_bump(
+        a.vault,
+        program_id,
+        &pda::artwork_vault_seeds(a.artwork_mint.key).as_slices(),
+        vault.bump,
+    )?;
+
+    assert_ata_mint_and_owner(a.vault_artwork_ata, artwork_mint_pda, vault_pda)?;
+
+    // the vault account holds SOL proceeds itself, AOVR proceeds are held in its AOVR ATA
+    let proceeds_account = match vault.currency {
+        Currency::SOL => {
+            assert_owned_by(a.buyer_payment, a.system.key)?;
+            a.vault
+        }
+        Currency::AOVR => {
+            let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+            assert_ata_mint_and_owner(a.buyer_payment, aovr_mint, *a.buyer_wallet.key)?;
+
+            if a.vault_aovr_ata.data_is_empty() {
+                if *a.aovr_mint.key != aovr_mint {
+                    return Err(AllovrError::InvalidArtworkVault.into());
+                }
+                create_ata(
+                    a.buyer_wallet,
+                    a.vault,
+                    a.vault_aovr_ata,
+                    a.aovr_mint,
+                    a.rent_sysvar,
+                    AllovrError::InvalidAssociatedTokenAccount,
+                )?;
+            } else {
+                assert_ata_mint_and_owner(a.vault_aovr_ata, aovr_mint, vault_pda)?;
+            }
+            a.vault_aovr_ata
+        }
+    };
+
+    let creator_payouts = calculate_creator_payouts(
+        vault.reserve_price,
+        &metadata.creators,
+        metadata.seller_fee_basis_points,
+        !metadata.primary_sale_happened,
+    )?;
+
+    if a.creator_payment_accounts.len() != metadata.creators.len() {
+        return Err(AllovrError::IncorrectCreatorPaymentAccount.into());
+    }
+
+    let mut revenue_share = load_artist_revenue_share(
+        program_id,
+        &metadata.artist,
+        &vault.currency,
+        a.artist_revenue_vault,
+        a.artist_revenue_vault_aovr,
+    )?;
+
+    let mut proceeds = vault.reserve_price;
+    for ((creator, amount), creator_payment) in metadata
+        .creators
+        .iter()
+        .zip(creator_payouts)
+        .zip(a.creator_payment_accounts.iter())
+    {
+        assert_creator_payment_account(creator_payment, &vault.currency, creator.address)?;
+
+        let creator_amount = pay_artist_revenue_share(
+            revenue_share.as_mut(),
+            &creator.address,
+            a.token_program,
+            a.system,
+            a.buyer_wallet,
+            a.buyer_payment,
+            amount,
+        )?;
+
+        transfer_payment(
+            &vault.currency,
+            a.token_program,
+            a.system,
+            a.buyer_wallet,
+            a.buyer_payment,
+            creator_payment,
+            creator_amount,
+        )?;
+        proceeds -= amount;
+    }
+
+    msg!("Paying buyout proceeds to vault...");
+    transfer_payment(
+        &vault.currency,
+        a.token_program,
+        a.system,
+        a.buyer_wallet,
+        a.buyer_payment,
+        proceeds_account,
+        proceeds,
+    )?;
+
+    if a.buyer_artwork_ata.data_is_empty() {
+        create_ata(
+            a.buyer_wallet,
+            a.buyer_wallet,
+            a.buyer_artwork_ata,
+            a.artwork_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(a.buyer_artwork_ata, artwork_mint_pda, *a.buyer_wallet.key)?;
+    }
+
+    msg!("Sending artwork to buyer...");
+    transfer_token_signed(
+        a.token_program,
+        a.vault_artwork_ata,
+        a.buyer_artwork_ata,
+        a.vault,
+        1,
+        &pda::artwork_vault_seeds(a.artwork_mint.key)
+            .with_bump(vault.bump)
+            .as_slices(),
+    )?;
+
+    vault.state = ArtworkVaultState::BoughtOut;
+    vault.proceeds = proceeds;
+    vault.save(a.vault)?;
+
+    metadata.set_holder(*a.buyer_wallet.key);
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        buyer_wallet: next_account_info(account_iter)?,
+        buyer_payment: next_account_info(account_iter)?,
+        buyer_artwork_ata: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+        vault: next_account_info(account_iter)?,
+        vault_artwork_ata: next_account_info(account_iter)?,
+        vault_aovr_ata: next_account_info(account_iter)?,
+        aovr_mint: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        artist_revenue_vault: next_account_info(account_iter)?,
+        artist_revenue_vault_aovr: next_account_info(account_iter)?,
+        creator_payment_accounts: account_iter.collect(),
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.buyer_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.vault, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,156 @@
+use crate::state::{ArtistMetadata, ArtworkBundle, ArtworkMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct ArtworkAccounts<'a, 'b: 'a> {
+    seller_artwork_ata: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    escrow: &'a AccountInfo<'b>,
+    artist_meta: &'a AccountInfo<'b>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    seller_wallet: &'a AccountInfo<'b>,
+    bundle: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    artworks: Vec<ArtworkAccounts<'a, 'b>>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let bundle = ArtworkBundle::load(a.bundle, program_id)?;
+
+    // the bundle PDA is seeded by its first artwork mint
+    let first_mint = bundle
+        .artworks
+        .first()
+        .ok_or(AllovrError::InvalidArtworkBundle)?;
+    assert_pda_with_bump(
+        a.bundle,
+        program_id,
+        &pda::artwork_bundle_seeds(first_mint).as_slices(),
+        bundle.bump,
+    )?;
+
+    if *a.seller_wallet.key != bundle.seller {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if a.artworks.len() != bundle.artworks.len() {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    for (artwork, bundle_mint) in a.artworks.iter().zip(bundle.artworks.iter()) {
+        let (mut metadata, artwork_mint_pda) =
+            ArtworkMetadata::load_with_mint(artwork.artwork_meta, program_id)?;
+
+        if metadata.bundle != Some(*a.bundle.key) {
+            return Err(AllovrError::InvalidArtworkBundle.into());
+        }
+
+        if artwork_mint_pda != *bundle_mint {
+            return Err(AllovrError::InvalidArtworkBundle.into());
+        }
+
+        assert_ata_mint_and_owner(
+            artwork.seller_artwork_ata,
+            artwork_mint_pda,
+            *a.seller_wallet.key,
+        )?;
+
+        let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+        let escrow_bump = metadata
+            .escrow_bump
+            .ok_or(AllovrError::InvalidArtworkBundle)?;
+
+        assert_pda_with_bump(
+            artwork.escrow,
+            program_id,
+            &escrow_seeds.as_slices(),
+            escrow_bump,
+        )?;
+
+        msg!("Returning artwork {} from escrow...", artwork_mint_pda);
+        release_escrow(
+            a.token_program,
+            artwork.escrow,
+            artwork.seller_artwork_ata,
+            a.seller_wallet,
+            &escrow_seeds.with_bump(escrow_bump).as_slices(),
+        )?;
+
+        metadata.bundle = None;
+        metadata.escrow_bump = None;
+        metadata.save(artwork.artwork_meta)?;
+        ArtistMetadata::count_open_offer(artwork.artist_meta, program_id, &metadata.artist, false)?;
+    }
+
+    msg!("Closing artwork bundle account...");
+    close_program_account(a.bundle, a.seller_wallet)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let seller_wallet = next_account_info(account_iter)?;
+    let bundle = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+
+    let remaining = account_iter.as_slice();
+    if remaining.len() % 4 != 0 {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    let artworks = remaining
+        .chunks(4)
+        .map(|x| ArtworkAccounts {
+            seller_artwork_ata: &x[0],
+            artwork_meta: &x[1],
+            escrow: &x[2],
+            artist_meta: &x[3],
+        })
+        .collect();
+
+    let a = Accounts {
+        seller_wallet,
+        bundle,
+        token_program,
+        artworks,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.seller_wallet, &[Signer, Writable]),
+            (a.bundle, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    for artwork in a.artworks.iter() {
+        validate_accounts(
+            program_id,
+            &[
+                (artwork.artwork_meta, &[ProgramOwned, Writable]),
+                (artwork.artist_meta, &[Writable]),
+            ],
+        )?;
+    }
+
+    Ok(a)
+}
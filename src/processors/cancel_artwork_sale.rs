@@ -1,12 +1,10 @@
-use crate::state::ArtworkMetadata;
-use crate::ARTWORK_ESCROW_SEED_PREFIX;
-use crate::{error::AllovrError, utils::*, ARTWORK_SEED_PREFIX};
-use borsh::BorshSerialize;
+use crate::error::AllovrError;
+use crate::pda;
+use crate::state::{ArtistMetadata, ArtworkMetadata, ProgramAccount};
+use crate::utils::{AccountConstraint::*, *};
 use solana_program::msg;
 use solana_program::program::invoke_signed;
 
-use solana_program::borsh::try_from_slice_unchecked;
-
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -20,12 +18,13 @@ struct Accounts<'a, 'b: 'a> {
     artwork_meta: &'a AccountInfo<'b>,
     escrow: &'a AccountInfo<'b>,
     token_program: &'a AccountInfo<'b>,
+    artist_meta: &'a AccountInfo<'b>,
 }
 
 pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
     let a = parse_accounts(program_id, accounts)?;
-    let mut metadata: ArtworkMetadata =
-        try_from_slice_unchecked(&a.artwork_meta.data.borrow_mut())?;
+    let (mut metadata, artwork_mint_pda) =
+        ArtworkMetadata::load_with_mint(a.artwork_meta, program_id)?;
 
     msg!(
         "Checking escrow PDA symbol {:?} artist {:?}",
@@ -33,15 +32,6 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         metadata.artist
     );
 
-    let (artwork_mint_pda, _artwork_mint_bump) = Pubkey::find_program_address(
-        &[
-            ARTWORK_SEED_PREFIX.as_bytes(),
-            metadata.symbol.as_bytes(),
-            metadata.artist.as_ref(),
-        ],
-        &program_id,
-    );
-
     assert_ata_mint_and_owner(
         a.artwork_holder_ata,
         artwork_mint_pda,
@@ -52,33 +42,33 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
         return Err(AllovrError::MissingRequiredSignature.into());
     }
 
+    // a swap shares the sale escrow, it is withdrawn with CancelArtworkSwap
+    if metadata.swap.is_some() {
+        return Err(AllovrError::InvalidArtworkSwap.into());
+    }
+
+    // bundled artworks are only returned together by CancelArtworkBundleSale
+    if metadata.bundle.is_some() {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
     metadata.offered_to = None;
     metadata.offer_price = None;
     metadata.offer_currency = None;
     metadata.payment_account = None;
+    metadata.dutch_auction = None;
 
-    metadata.serialize(&mut &mut a.artwork_meta.data.borrow_mut()[..])?;
+    let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+    let escrow_bump = metadata.escrow_bump.ok_or(AllovrError::InvalidOffer)?;
+    metadata.escrow_bump = None;
 
-    let offer_count_string = metadata.offer_count.to_string();
+    metadata.save(a.artwork_meta)?;
+    ArtistMetadata::count_open_offer(a.artist_meta, program_id, &metadata.artist, false)?;
 
-    let (escrow_pda, escrow_bump) = assert_pda(
-        &a.escrow,
-        program_id,
-        &[
-            ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-            metadata.symbol.as_bytes(),
-            offer_count_string.as_bytes(),
-            metadata.artist.as_ref(),
-        ],
-    )?;
+    let escrow_pda =
+        assert_pda_with_bump(a.escrow, program_id, &escrow_seeds.as_slices(), escrow_bump)?;
 
-    let escrow_signer_seeds = &[
-        ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-        metadata.symbol.as_bytes(),
-        offer_count_string.as_bytes(),
-        metadata.artist.as_ref(),
-        &[escrow_bump],
-    ];
+    let escrow_signer = escrow_seeds.with_bump(escrow_bump);
+    let escrow_signer_seeds = &escrow_signer.as_slices()[..];
 
     // return artwork from escrow account
     let transfer_artwork_ix = spl_token::instruction::transfer(
@@ -124,7 +114,7 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
 }
 
 fn parse_accounts<'a, 'b: 'a>(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &'a [AccountInfo<'b>],
 ) -> Result<Accounts<'a, 'b>, ProgramError> {
     let account_iter = &mut accounts.iter();
@@ -134,8 +124,19 @@ fn parse_accounts<'a, 'b: 'a>(
         artwork_meta: next_account_info(account_iter)?,
         escrow: next_account_info(account_iter)?,
         token_program: next_account_info(account_iter)?,
+        artist_meta: next_account_info(account_iter)?,
     };
 
-    assert_signer(&a.artwork_holder_wallet)?;
+    validate_accounts(
+        program_id,
+        &[
+            (a.artwork_holder_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.escrow, &[Owner(&spl_token::id()), Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.artist_meta, &[Writable]),
+        ],
+    )?;
+
     Ok(a)
 }
@@ -0,0 +1,130 @@
+use crate::state::{
+    ArtistMetadata, ArtworkMetadata, ArtworkSwap, ArtworkSwapTopUpPayer, Currency, ProgramAccount,
+};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    proposer_wallet: &'a AccountInfo<'b>,
+    proposer_offered_ata: &'a AccountInfo<'b>,
+    offered_meta: &'a AccountInfo<'b>,
+    escrow: &'a AccountInfo<'b>,
+    swap: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    offered_artist_meta: &'a AccountInfo<'b>,
+    proposer_payment: &'a AccountInfo<'b>,
+    swap_aovr_ata: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    let (mut offered, offered_mint_pda) =
+        ArtworkMetadata::load_with_mint(a.offered_meta, program_id)?;
+    let swap = ArtworkSwap::load(a.swap, program_id)?;
+
+    if offered.swap != Some(*a.swap.key) {
+        return Err(AllovrError::InvalidArtworkSwap.into());
+    }
+
+    if *a.proposer_wallet.key != swap.proposer || *a.proposer_wallet.key != offered.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    assert_ata_mint_and_owner(
+        a.proposer_offered_ata,
+        offered_mint_pda,
+        *a.proposer_wallet.key,
+    )?;
+
+    let escrow_seeds = pda::escrow_seeds(&offered, offered.offer_count);
+    let escrow_bump = offered.escrow_bump.ok_or(AllovrError::InvalidArtworkSwap)?;
+
+    assert_pda_with_bump(a.escrow, program_id, &escrow_seeds.as_slices(), escrow_bump)?;
+
+    msg!("Returning offered artwork from escrow...");
+    release_escrow(
+        a.token_program,
+        a.escrow,
+        a.proposer_offered_ata,
+        a.proposer_wallet,
+        &escrow_seeds.with_bump(escrow_bump).as_slices(),
+    )?;
+
+    // a SOL top-up is refunded to the proposer wallet with the swap account rent
+    if let Some(top_up) = &swap.top_up {
+        if top_up.payer == ArtworkSwapTopUpPayer::Proposer && top_up.currency == Currency::AOVR {
+            if *a.proposer_payment.key != top_up.payment_account {
+                return Err(AllovrError::IncorrectPaymentAccount.into());
+            }
+
+            msg!("Refunding proposer top-up...");
+            let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+            assert_ata_mint_and_owner(a.swap_aovr_ata, aovr_mint, *a.swap.key)?;
+            release_token_account(
+                a.token_program,
+                a.swap_aovr_ata,
+                a.proposer_payment,
+                a.proposer_wallet,
+                a.swap,
+                &pda::artwork_swap_seeds(&swap.offered_mint, &swap.requested_mint)
+                    .with_bump(swap.bump)
+                    .as_slices(),
+            )?;
+        }
+    }
+
+    msg!("Closing artwork swap account...");
+    close_program_account(a.swap, a.proposer_wallet)?;
+
+    offered.swap = None;
+    offered.escrow_bump = None;
+    offered.save(a.offered_meta)?;
+    ArtistMetadata::count_open_offer(a.offered_artist_meta, program_id, &offered.artist, false)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        proposer_wallet: next_account_info(account_iter)?,
+        proposer_offered_ata: next_account_info(account_iter)?,
+        offered_meta: next_account_info(account_iter)?,
+        escrow: next_account_info(account_iter)?,
+        swap: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        offered_artist_meta: next_account_info(account_iter)?,
+        proposer_payment: next_account_info(account_iter)?,
+        swap_aovr_ata: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.proposer_wallet, &[Signer, Writable]),
+            (a.offered_meta, &[ProgramOwned, Writable]),
+            (a.swap, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.offered_artist_meta, &[Writable]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,157 @@
+use crate::instruction::ClaimArtistAirdropArgs;
+use crate::state::{ArtistAirdrop, ArtistAirdropClaim, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_AIRDROP_CLAIM_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    claimant_wallet: &'a AccountInfo<'b>,
+    claimant_artist_token: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    airdrop: &'a AccountInfo<'b>,
+    airdrop_artist_token: &'a AccountInfo<'b>,
+    claim: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: ClaimArtistAirdropArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut airdrop = ArtistAirdrop::load(a.airdrop, program_id)?;
+
+    if airdrop.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidAirdrop.into());
+    }
+
+    let airdrop_seeds = pda::artist_airdrop_seeds(a.artist_token_mint.key, airdrop.id);
+    let airdrop_pda = assert_pda_with_bump(
+        a.airdrop,
+        program_id,
+        &airdrop_seeds.as_slices(),
+        airdrop.bump,
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.airdrop_artist_token,
+        *a.artist_token_mint.key,
+        airdrop_pda,
+    )?;
+
+    let leaf = artist_airdrop_leaf(args.index, a.claimant_wallet.key, args.amount);
+    if !verify_merkle_proof(&args.proof, airdrop.merkle_root, leaf) {
+        return Err(AllovrError::InvalidMerkleProof.into());
+    }
+
+    // the receipt is created here, so its bump has to be searched for
+    msg!("Checking airdrop claim PDA...");
+    let claim_seeds = pda::artist_airdrop_claim_seeds(a.airdrop.key, args.index);
+    let (_claim_pda, claim_bump) = assert_pda(a.claim, program_id, &claim_seeds.as_slices())?;
+
+    if !a.claim.data_is_empty() {
+        return Err(AllovrError::AirdropAlreadyClaimed.into());
+    }
+
+    create_raw(
+        *program_id,
+        a.claim,
+        &Rent::get()?,
+        a.system,
+        a.claimant_wallet,
+        ARTIST_AIRDROP_CLAIM_SIZE,
+        &claim_seeds.with_bump(claim_bump).as_slices(),
+    )?;
+
+    let claim = ArtistAirdropClaim {
+        claimant: *a.claimant_wallet.key,
+        amount: args.amount,
+    };
+
+    claim.save(a.claim)?;
+
+    if a.claimant_artist_token.data_is_empty() {
+        create_ata(
+            a.claimant_wallet,
+            a.claimant_wallet,
+            a.claimant_artist_token,
+            a.artist_token_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtistTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.claimant_artist_token,
+            *a.artist_token_mint.key,
+            *a.claimant_wallet.key,
+        )?;
+    }
+
+    msg!("Claiming {} airdropped artist tokens...", args.amount);
+    transfer_token_signed(
+        a.token_program,
+        a.airdrop_artist_token,
+        a.claimant_artist_token,
+        a.airdrop,
+        args.amount,
+        &airdrop_seeds.with_bump(airdrop.bump).as_slices(),
+    )?;
+
+    airdrop.claimed = airdrop
+        .claimed
+        .checked_add(args.amount)
+        .filter(|claimed| *claimed <= airdrop.total)
+        .ok_or(AllovrError::InvalidAirdrop)?;
+    airdrop.save(a.airdrop)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        claimant_wallet: next_account_info(account_iter)?,
+        claimant_artist_token: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        airdrop: next_account_info(account_iter)?,
+        airdrop_artist_token: next_account_info(account_iter)?,
+        claim: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.claimant_wallet, &[Signer, Writable]),
+            (a.airdrop, &[ProgramOwned, Writable]),
+            (a.claim, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
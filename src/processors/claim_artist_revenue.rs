@@ -0,0 +1,122 @@
+use crate::state::{ArtistRevenueStake, ArtistRevenueVault, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_aovr_ata: &'a AccountInfo<'b>,
+    revenue_vault: &'a AccountInfo<'b>,
+    revenue_vault_aovr_ata: &'a AccountInfo<'b>,
+    stake: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let vault = ArtistRevenueVault::load(a.revenue_vault, program_id)?;
+    let mut stake = ArtistRevenueStake::load(a.stake, program_id)?;
+
+    if stake.holder != *a.holder_wallet.key || stake.artist_mint != vault.artist_mint {
+        return Err(AllovrError::InvalidRevenueShare.into());
+    }
+
+    msg!("Checking revenue vault and stake PDAs...");
+    let revenue_vault_pda = assert_pda_with_bump(
+        a.revenue_vault,
+        program_id,
+        &pda::artist_revenue_vault_seeds(&vault.artist_mint).as_slices(),
+        vault.bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.stake,
+        program_id,
+        &pda::artist_stake_seeds(&vault.artist_mint, a.holder_wallet.key).as_slices(),
+        stake.bump,
+    )?;
+
+    accrue_artist_revenue(&mut stake, &vault)?;
+
+    if stake.sol_owed > 0 {
+        msg!("Paying {} SOL revenue...", stake.sol_owed);
+        **a.revenue_vault.lamports.borrow_mut() = a
+            .revenue_vault
+            .lamports()
+            .checked_sub(stake.sol_owed)
+            .ok_or(AllovrError::InsufficientStake)?;
+        **a.holder_wallet.lamports.borrow_mut() = a
+            .holder_wallet
+            .lamports()
+            .checked_add(stake.sol_owed)
+            .ok_or(AllovrError::InsufficientStake)?;
+        stake.sol_owed = 0;
+    }
+
+    if stake.aovr_owed > 0 {
+        let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+        assert_ata_mint_and_owner(a.revenue_vault_aovr_ata, aovr_mint, revenue_vault_pda)?;
+        assert_ata_mint_and_owner(a.holder_aovr_ata, aovr_mint, *a.holder_wallet.key)?;
+
+        msg!("Paying {} AOVR revenue...", stake.aovr_owed);
+        transfer_token_signed(
+            a.token_program,
+            a.revenue_vault_aovr_ata,
+            a.holder_aovr_ata,
+            a.revenue_vault,
+            stake.aovr_owed,
+            &pda::artist_revenue_vault_seeds(&vault.artist_mint)
+                .with_bump(vault.bump)
+                .as_slices(),
+        )?;
+        stake.aovr_owed = 0;
+    }
+
+    if stake.amount == 0 {
+        msg!("Nothing staked, closing artist stake account...");
+        close_program_account(a.stake, a.holder_wallet)?;
+    } else {
+        stake.save(a.stake)?;
+    }
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_aovr_ata: next_account_info(account_iter)?,
+        revenue_vault: next_account_info(account_iter)?,
+        revenue_vault_aovr_ata: next_account_info(account_iter)?,
+        stake: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.holder_wallet, &[Signer]),
+            (a.revenue_vault, &[ProgramOwned]),
+            (a.stake, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,136 @@
+use crate::state::{ArtworkVault, ArtworkVaultState, Currency, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_fraction_ata: &'a AccountInfo<'b>,
+    fraction_mint: &'a AccountInfo<'b>,
+    vault: &'a AccountInfo<'b>,
+    holder_payment: &'a AccountInfo<'b>,
+    vault_aovr_ata: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut vault = ArtworkVault::load(a.vault, program_id)?;
+
+    if vault.state != ArtworkVaultState::BoughtOut {
+        return Err(AllovrError::ArtworkVaultNotBoughtOut.into());
+    }
+
+    if vault.fraction_mint != *a.fraction_mint.key {
+        return Err(AllovrError::InvalidArtworkVault.into());
+    }
+
+    let vault_pda = assert_pda_with_bump(
+        a.vault,
+        program_id,
+        &pda::artwork_vault_seeds(&vault.artwork_mint).as_slices(),
+        vault.bump,
+    )?;
+
+    let fractions = get_token_account(a.holder_fraction_ata)?;
+    if fractions.mint != vault.fraction_mint || fractions.owner != *a.holder_wallet.key {
+        return Err(AllovrError::IncorrectTokenOwnerOrMint.into());
+    }
+
+    if fractions.amount == 0 {
+        return Err(AllovrError::InsufficientFractions.into());
+    }
+
+    // pro rata against the fractions still outstanding, so rounding dust goes to the last claim
+    let outstanding = get_token_supply(a.fraction_mint);
+    let payout = calculate_fraction_payout(vault.proceeds, fractions.amount, outstanding)?;
+
+    msg!("Burning {} fractions for {}...", fractions.amount, payout);
+    burn_tokens(
+        a.token_program,
+        a.holder_fraction_ata,
+        a.fraction_mint,
+        a.holder_wallet,
+        fractions.amount,
+    )?;
+
+    match vault.currency {
+        Currency::SOL => {
+            **a.vault.lamports.borrow_mut() = a
+                .vault
+                .lamports()
+                .checked_sub(payout)
+                .ok_or(AllovrError::InvalidArtworkVault)?;
+            **a.holder_payment.lamports.borrow_mut() = a
+                .holder_payment
+                .lamports()
+                .checked_add(payout)
+                .ok_or(AllovrError::InvalidArtworkVault)?;
+        }
+        Currency::AOVR => {
+            let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+            assert_ata_mint_and_owner(a.vault_aovr_ata, aovr_mint, vault_pda)?;
+            assert_ata_mint_and_owner(a.holder_payment, aovr_mint, *a.holder_wallet.key)?;
+            transfer_token_signed(
+                a.token_program,
+                a.vault_aovr_ata,
+                a.holder_payment,
+                a.vault,
+                payout,
+                &pda::artwork_vault_seeds(&vault.artwork_mint)
+                    .with_bump(vault.bump)
+                    .as_slices(),
+            )?;
+        }
+    }
+
+    vault.proceeds -= payout;
+
+    if fractions.amount == outstanding {
+        msg!("Last fractions claimed, closing artwork vault...");
+        close_program_account(a.vault, a.holder_wallet)?;
+    } else {
+        vault.save(a.vault)?;
+    }
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_fraction_ata: next_account_info(account_iter)?,
+        fraction_mint: next_account_info(account_iter)?,
+        vault: next_account_info(account_iter)?,
+        holder_payment: next_account_info(account_iter)?,
+        vault_aovr_ata: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.holder_wallet, &[Signer]),
+            (a.vault, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
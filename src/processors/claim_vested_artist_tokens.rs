@@ -0,0 +1,103 @@
+use crate::state::{ArtistVesting, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_artist_token: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_vesting: &'a AccountInfo<'b>,
+    artist_vesting_artist_token: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut vesting = ArtistVesting::load(a.artist_vesting, program_id)?;
+
+    if vesting.artist != *a.artist_wallet.key || vesting.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidVestingSchedule.into());
+    }
+
+    // the vesting account was created for this artist and mint, its stored bump proves both
+    msg!("Checking vesting PDA...");
+    let artist_vesting_pda = assert_pda_with_bump(
+        a.artist_vesting,
+        program_id,
+        &pda::artist_vesting_seeds(a.artist_token_mint.key).as_slices(),
+        vesting.bump,
+    )?;
+    let artist_token_mint_pda = vesting.artist_mint;
+
+    assert_ata_mint_and_owner(
+        a.artist_artist_token,
+        artist_token_mint_pda,
+        *a.artist_wallet.key,
+    )?;
+    assert_ata_mint_and_owner(
+        a.artist_vesting_artist_token,
+        artist_token_mint_pda,
+        artist_vesting_pda,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let claimable = vesting.vested_at(now) - vesting.claimed;
+    if claimable == 0 {
+        return Err(AllovrError::NothingVested.into());
+    }
+
+    msg!("Releasing {} vested artist tokens...", claimable);
+    transfer_token_signed(
+        a.token_program,
+        a.artist_vesting_artist_token,
+        a.artist_artist_token,
+        a.artist_vesting,
+        claimable,
+        &pda::artist_vesting_seeds(a.artist_token_mint.key)
+            .with_bump(vesting.bump)
+            .as_slices(),
+    )?;
+
+    vesting.claimed += claimable;
+    vesting.save(a.artist_vesting)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artist_artist_token: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_vesting: next_account_info(account_iter)?,
+        artist_vesting_artist_token: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer]),
+            (a.artist_vesting, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,135 @@
+use crate::instruction::CreateArtistAirdropArgs;
+use crate::state::{ArtistAirdrop, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_AIRDROP_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_artist_token: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    airdrop: &'a AccountInfo<'b>,
+    airdrop_artist_token: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: CreateArtistAirdropArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    if args.total_amount == 0 {
+        return Err(AllovrError::InvalidAirdrop.into());
+    }
+
+    msg!("Checking artist token mint and airdrop PDAs...");
+    let (artist_token_mint_pda, _artist_token_mint_bump) = assert_pda(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(a.artist_wallet.key).as_slices(),
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.artist_artist_token,
+        artist_token_mint_pda,
+        *a.artist_wallet.key,
+    )?;
+
+    let airdrop_seeds = pda::artist_airdrop_seeds(a.artist_token_mint.key, args.airdrop_id);
+    let (_airdrop_pda, airdrop_bump) =
+        assert_pda(a.airdrop, program_id, &airdrop_seeds.as_slices())?;
+
+    msg!("Creating artist airdrop account...");
+    create_raw(
+        *program_id,
+        a.airdrop,
+        &Rent::get()?,
+        a.system,
+        a.artist_wallet,
+        ARTIST_AIRDROP_SIZE,
+        &airdrop_seeds.with_bump(airdrop_bump).as_slices(),
+    )?;
+
+    create_ata(
+        a.artist_wallet,
+        a.airdrop,
+        a.airdrop_artist_token,
+        a.artist_token_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidArtistTokenAccount,
+    )?;
+
+    msg!(
+        "Locking {} artist tokens for the airdrop...",
+        args.total_amount
+    );
+    transfer_token(
+        a.token_program,
+        a.artist_artist_token,
+        a.airdrop_artist_token,
+        a.artist_wallet,
+        args.total_amount,
+    )?;
+
+    let airdrop = ArtistAirdrop {
+        artist: *a.artist_wallet.key,
+        artist_mint: *a.artist_token_mint.key,
+        id: args.airdrop_id,
+        merkle_root: args.merkle_root,
+        total: args.total_amount,
+        claimed: 0,
+        bump: airdrop_bump,
+    };
+
+    airdrop.save(a.airdrop)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artist_artist_token: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        airdrop: next_account_info(account_iter)?,
+        airdrop_artist_token: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.airdrop, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
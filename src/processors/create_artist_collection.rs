@@ -0,0 +1,194 @@
+use crate::instruction::CreateArtistCollectionArgs;
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_COLLECTION_NAME_SIZE, ARTIST_COLLECTION_SYMBOL_SIZE, ARTIST_COLLECTION_URI_SIZE,
+    MINT_SIZE,
+};
+use mpl_token_metadata::state::Creator;
+
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_collection_token: &'a AccountInfo<'b>,
+    collection_mint: &'a AccountInfo<'b>,
+    collection_metaplex_meta: &'a AccountInfo<'b>,
+    collection_master_edition: &'a AccountInfo<'b>,
+    metaplex_meta_program_account: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: CreateArtistCollectionArgs,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+
+    let collection_symbol = args.symbol.trim().to_string();
+    if collection_symbol.is_empty() || collection_symbol.len() > ARTIST_COLLECTION_SYMBOL_SIZE {
+        return Err(AllovrError::InvalidCollectionSymbol.into());
+    }
+
+    let collection_name = args.name.trim().to_string();
+    if collection_name.is_empty() || collection_name.len() > ARTIST_COLLECTION_NAME_SIZE {
+        return Err(AllovrError::InvalidCollectionName.into());
+    }
+
+    let collection_uri = args.uri.trim().to_string();
+    if collection_uri.len() > ARTIST_COLLECTION_URI_SIZE {
+        return Err(AllovrError::InvalidCollectionUri.into());
+    }
+
+    let a = parse_accounts(program_id, accounts)?;
+
+    // only registered artists (artist token mint exists) can create collections
+    assert_pda(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(a.artist_wallet.key).as_slices(),
+    )?;
+    assert_owned_by(a.artist_token_mint, &spl_token::id())?;
+
+    let collection_mint_seeds =
+        pda::artist_collection_mint_seeds(&collection_symbol, a.artist_token_mint.key);
+
+    let (_collection_mint_pda, collection_mint_bump) = assert_pda(
+        a.collection_mint,
+        program_id,
+        &collection_mint_seeds.as_slices(),
+    )?;
+
+    let collection_mint_signer = collection_mint_seeds.with_bump(collection_mint_bump);
+    let collection_mint_signer_seeds = &collection_mint_signer.as_slices()[..];
+
+    msg!("Creating collection mint...");
+    create_raw(
+        spl_token::id(),
+        a.collection_mint,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        MINT_SIZE,
+        collection_mint_signer_seeds,
+    )?;
+
+    initalise_pda_mint_account(
+        a.collection_mint,
+        a.artist_wallet,
+        a.rent_sysvar,
+        collection_mint_signer_seeds,
+        0,
+    )?;
+
+    create_ata(
+        a.artist_wallet,
+        a.artist_wallet,
+        a.artist_collection_token,
+        a.collection_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidAssociatedTokenAccount,
+    )?;
+
+    // The collection NFT is held by the artist
+    mint_tokens_to(
+        a.collection_mint,
+        a.collection_mint,
+        a.artist_collection_token,
+        &[collection_mint_signer_seeds],
+        1,
+        false,
+    )?;
+
+    msg!("Creating collection metaplex metadata...");
+    let creators = vec![Creator {
+        address: *a.artist_wallet.key,
+        verified: false,
+        share: 100,
+    }];
+
+    create_metaplex_metadata_account(
+        a.collection_metaplex_meta,
+        a.collection_mint,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+        a.rent_sysvar,
+        collection_name,
+        collection_symbol.clone(),
+        collection_uri,
+        Some(creators),
+        0,
+        None,
+        collection_mint_signer_seeds,
+    )?;
+
+    sign_metaplex_metadata(
+        a.collection_metaplex_meta,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+    )?;
+
+    // Metaplex requires a master edition for an NFT to act as a collection
+    msg!("Creating collection master edition...");
+    create_metaplex_master_edition_account(
+        a.collection_master_edition,
+        a.collection_metaplex_meta,
+        a.collection_mint,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+        a.token_program,
+        a.system,
+        a.rent_sysvar,
+        Some(0),
+        collection_mint_signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_collection_token: next_account_info(account_iter)?,
+        collection_mint: next_account_info(account_iter)?,
+        collection_metaplex_meta: next_account_info(account_iter)?,
+        collection_master_edition: next_account_info(account_iter)?,
+        metaplex_meta_program_account: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.metaplex_meta_program_account, &[MetaplexProgram]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
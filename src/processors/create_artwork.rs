@@ -1,14 +1,16 @@
 use crate::instruction::CreateArtworkArgs;
-use crate::state::ArtworkMetadata;
+use crate::state::{ArtworkCreator, ArtworkMetadata, ProgramAccount};
 use crate::{
-    error::AllovrError, utils::*, ARTWORK_METADATA_DESCRIPTION_SIZE, ARTWORK_METADATA_SEED_PREFIX,
-    ARTWORK_METADATA_SIZE, ARTWORK_METADATA_SYMBOL_SIZE, ARTWORK_SEED_PREFIX, MINT_SIZE,
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID, ARTWORK_METADATA_SIZE, MINT_SIZE,
 };
-use borsh::BorshSerialize;
+use mpl_token_metadata::state::{Collection, Creator};
 
-use solana_program::borsh::try_from_slice_unchecked;
 use solana_program::msg;
 use solana_program::program::invoke;
+use std::str::FromStr;
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -28,7 +30,14 @@ struct Accounts<'a, 'b: 'a> {
     token_program: &'a AccountInfo<'b>,
     rent_sysvar: &'a AccountInfo<'b>,
     system: &'a AccountInfo<'b>,
-    _apa_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    metaplex_meta_program_account: &'a AccountInfo<'b>,
+    artwork_metaplex_meta: &'a AccountInfo<'b>,
+    artwork_master_edition: &'a AccountInfo<'b>,
+    collection_mint: Option<&'a AccountInfo<'b>>,
+    collection_metaplex_meta: Option<&'a AccountInfo<'b>>,
+    collection_master_edition: Option<&'a AccountInfo<'b>>,
+    co_creators: Vec<&'a AccountInfo<'b>>,
 }
 
 pub fn execute(
@@ -38,19 +47,72 @@ pub fn execute(
 ) -> ProgramResult {
     let rent = Rent::get()?;
 
-    let artwork_token_symbol = args.symbol.trim().to_string();
-    if artwork_token_symbol.len() != ARTWORK_METADATA_SYMBOL_SIZE {
-        return Err(AllovrError::InvalidArtworkSymbol.into());
+    let artwork_data = santitise_artwork_data(args)?;
+
+    let a = parse_accounts(
+        program_id,
+        accounts,
+        artwork_data.collection_symbol.is_some(),
+    )?;
+
+    // The artist is the sole creator unless a collaboration is declared, in which case
+    // the artist must be one of the creators and every other creator must co-sign.
+    let mut artwork_creators = artwork_data.creators.clone();
+    if artwork_creators.is_empty() {
+        artwork_creators.push(ArtworkCreator {
+            address: *a.artist_wallet.key,
+            share: 100,
+        });
     }
 
-    let artwork_description = args.description.trim().to_string();
-    if artwork_description.len() == 0
-        || artwork_description.len() > ARTWORK_METADATA_DESCRIPTION_SIZE
+    if !artwork_creators
+        .iter()
+        .any(|c| c.address == *a.artist_wallet.key)
     {
-        return Err(AllovrError::ArtworkDescriptionMaxLengthExceeded.into());
+        return Err(AllovrError::InvalidArtworkCreators.into());
+    }
+
+    let co_creator_keys: Vec<Pubkey> = artwork_creators
+        .iter()
+        .map(|c| c.address)
+        .filter(|address| address != a.artist_wallet.key)
+        .collect();
+
+    if co_creator_keys.len() != a.co_creators.len() {
+        return Err(AllovrError::InvalidArtworkCreators.into());
+    }
+
+    for (co_creator_key, co_creator) in co_creator_keys.iter().zip(a.co_creators.iter()) {
+        if co_creator.key != co_creator_key {
+            return Err(AllovrError::InvalidArtworkCreators.into());
+        }
+        assert_signer(co_creator)?;
     }
 
-    let a = parse_accounts(program_id, accounts)?;
+    // Artwork optionally joins one of the artist's collections. Keep the collection
+    // seeds around, the collection mint PDA has to sign the collection verification.
+    let mut collection_option: Option<(String, Pubkey, Pubkey, u8)> = None;
+    if let Some(collection_symbol) = &artwork_data.collection_symbol {
+        let collection_mint = a
+            .collection_mint
+            .ok_or(AllovrError::MissingCollectionAccounts)?;
+
+        let (artist_token_mint_key, _) = pda::find_artist_mint(program_id, a.artist_wallet.key);
+
+        let (collection_mint_pda, collection_mint_bump) = assert_pda(
+            collection_mint,
+            program_id,
+            &pda::artist_collection_mint_seeds(collection_symbol, &artist_token_mint_key)
+                .as_slices(),
+        )?;
+
+        collection_option = Some((
+            String::from(collection_symbol),
+            artist_token_mint_key,
+            collection_mint_pda,
+            collection_mint_bump,
+        ));
+    }
 
     // Artist must pay 1000 AOV to create artwork
     msg!("Creating 10K AOVR transfer instruction...");
@@ -74,24 +136,13 @@ pub fn execute(
         ],
     )?;
 
-    let symbol_bytes = artwork_token_symbol.as_bytes();
+    let artwork_mint_seeds = pda::artwork_mint_seeds(&artwork_data.symbol, a.artist_wallet.key);
 
-    let (_artwork_token_mint_pda, artwork_token_mint_bump) = assert_pda(
-        &a.artwork_mint,
-        program_id,
-        &[
-            ARTWORK_SEED_PREFIX.as_bytes(),
-            symbol_bytes,
-            a.artist_wallet.key.as_ref(),
-        ],
-    )?;
+    let (_artwork_token_mint_pda, artwork_token_mint_bump) =
+        assert_pda(&a.artwork_mint, program_id, &artwork_mint_seeds.as_slices())?;
 
-    let artwork_mint_authority_signer_seeds = &[
-        ARTWORK_SEED_PREFIX.as_bytes(),
-        symbol_bytes,
-        a.artist_wallet.key.as_ref(),
-        &[artwork_token_mint_bump],
-    ];
+    let artwork_mint_authority_signer = artwork_mint_seeds.with_bump(artwork_token_mint_bump);
+    let artwork_mint_authority_signer_seeds = &artwork_mint_authority_signer.as_slices()[..];
 
     msg!("Creating artwork token mint...");
     create_raw(
@@ -121,31 +172,109 @@ pub fn execute(
         AllovrError::InvalidArtworkTokenAccount,
     )?;
 
-    // Mint the artwork to the artist
+    // Mint the artwork to the artist. Mint authority is handed over to the
+    // Metaplex master edition below, so no further tokens can be minted.
     mint_tokens_to(
         a.artwork_mint,         // mint account (PDA)
         a.artwork_mint,         // mint authority (same PDA)
         a.artist_artwork_token, // artist assoicated token account
         &[artwork_mint_authority_signer_seeds],
         1,
-        true,
+        false,
+    )?;
+
+    msg!("Creating artwork metaplex metadata...");
+    let creators = artwork_creators
+        .iter()
+        .map(|c| Creator {
+            address: c.address,
+            verified: false,
+            share: c.share,
+        })
+        .collect();
+
+    create_metaplex_metadata_account(
+        a.artwork_metaplex_meta,
+        a.artwork_mint,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+        a.rent_sysvar,
+        String::from(&artwork_data.name),
+        String::from(&artwork_data.symbol),
+        String::from(&artwork_data.uri),
+        Some(creators),
+        artwork_data.seller_fee_basis_points,
+        collection_option
+            .as_ref()
+            .map(|(_, _, collection_mint_pda, _)| Collection {
+                verified: false,
+                key: *collection_mint_pda,
+            }),
+        artwork_mint_authority_signer_seeds,
+    )?;
+
+    // artist and co-creators signed the transaction, so can verify themselves as creators
+    sign_metaplex_metadata(
+        a.artwork_metaplex_meta,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+    )?;
+
+    for co_creator in a.co_creators.iter() {
+        sign_metaplex_metadata(
+            a.artwork_metaplex_meta,
+            co_creator,
+            a.metaplex_meta_program_account,
+        )?;
+    }
+
+    msg!("Creating artwork master edition...");
+    create_metaplex_master_edition_account(
+        a.artwork_master_edition,
+        a.artwork_metaplex_meta,
+        a.artwork_mint,
+        a.artist_wallet,
+        a.metaplex_meta_program_account,
+        a.token_program,
+        a.system,
+        a.rent_sysvar,
+        Some(artwork_data.max_editions),
+        artwork_mint_authority_signer_seeds,
     )?;
 
+    if let Some((collection_symbol, artist_token_mint_key, _, collection_mint_bump)) =
+        &collection_option
+    {
+        msg!("Verifying artwork collection...");
+        let collection_signer =
+            pda::artist_collection_mint_seeds(collection_symbol, artist_token_mint_key)
+                .with_bump(*collection_mint_bump);
+        let collection_signer_seeds = &collection_signer.as_slices()[..];
+
+        verify_metaplex_collection(
+            a.artwork_metaplex_meta,
+            a.collection_mint
+                .ok_or(AllovrError::MissingCollectionAccounts)?,
+            a.collection_metaplex_meta
+                .ok_or(AllovrError::MissingCollectionAccounts)?,
+            a.collection_master_edition
+                .ok_or(AllovrError::MissingCollectionAccounts)?,
+            a.artist_wallet,
+            a.metaplex_meta_program_account,
+            collection_signer_seeds,
+        )?;
+    }
+
     // The PDA of the meta data accountshould be unique per Artist pubkey and Symbol
     // (artist must create unique symbol per artpiece)
     let (_metadata_key, metadata_bump_seed) = assert_pda(
         a.artwork_meta,
         program_id,
-        &[
-            ARTWORK_METADATA_SEED_PREFIX.as_bytes(),
-            a.artwork_mint.key.as_ref(),
-        ],
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
     )?;
-    let metadata_authority_signer_seeds = &[
-        ARTWORK_METADATA_SEED_PREFIX.as_bytes(),
-        a.artwork_mint.key.as_ref(),
-        &[metadata_bump_seed],
-    ];
+    let metadata_authority_signer =
+        pda::artwork_metadata_seeds(a.artwork_mint.key).with_bump(metadata_bump_seed);
+    let metadata_authority_signer_seeds = &metadata_authority_signer.as_slices()[..];
 
     // create Metadata account
     create_raw(
@@ -159,19 +288,34 @@ pub fn execute(
     )?;
 
     // Save meta data
-    let mut metadata: ArtworkMetadata =
-        try_from_slice_unchecked(&a.artwork_meta.data.borrow_mut())?;
-
-    metadata.artist = *a.artist_wallet.key;
-    metadata.symbol = artwork_token_symbol;
-    metadata.description = artwork_description;
-    metadata.holder = *a.artist_wallet.key;
-    metadata.offer_count = 0;
-    metadata.offered_to = None;
-    metadata.offer_price = None;
-    metadata.offer_currency = None;
+    let metadata = ArtworkMetadata {
+        artist: *a.artist_wallet.key,
+        symbol: String::from(&artwork_data.symbol),
+        description: String::from(&artwork_data.description),
+        holder: *a.artist_wallet.key,
+        offer_count: 0,
+        offered_to: None,
+        offer_price: None,
+        offer_currency: None,
+        payment_account: None,
+        dutch_auction: None,
+        swap: None,
+        bundle: None,
+        max_editions: artwork_data.max_editions,
+        editions_printed: 0,
+        print: None,
+        content_uri: artwork_data.content_uri,
+        content_hash: artwork_data.content_hash,
+        creators: artwork_creators,
+        seller_fee_basis_points: artwork_data.seller_fee_basis_points,
+        primary_sale_happened: false,
+        bump: metadata_bump_seed,
+        mint_bump: artwork_token_mint_bump,
+        escrow_bump: None,
+        fraction_mint_bump: None,
+    };
 
-    metadata.serialize(&mut &mut a.artwork_meta.data.borrow_mut()[..])?;
+    metadata.save(a.artwork_meta)?;
 
     Ok(())
 }
@@ -179,9 +323,10 @@ pub fn execute(
 fn parse_accounts<'a, 'b: 'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'b>],
+    has_collection: bool,
 ) -> Result<Accounts<'a, 'b>, ProgramError> {
     let account_iter = &mut accounts.iter();
-    let a = Accounts {
+    let mut a = Accounts {
         artist_wallet: next_account_info(account_iter)?,
         artist_aov_token: next_account_info(account_iter)?,
         artist_artwork_token: next_account_info(account_iter)?,
@@ -191,15 +336,42 @@ fn parse_accounts<'a, 'b: 'a>(
         token_program: next_account_info(account_iter)?,
         rent_sysvar: next_account_info(account_iter)?,
         system: next_account_info(account_iter)?,
-        _apa_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        metaplex_meta_program_account: next_account_info(account_iter)?,
+        artwork_metaplex_meta: next_account_info(account_iter)?,
+        artwork_master_edition: next_account_info(account_iter)?,
+        collection_mint: None,
+        collection_metaplex_meta: None,
+        collection_master_edition: None,
+        co_creators: vec![],
     };
 
-    assert_program_id(program_id)?;
-    assert_token_program_matches_package(a.token_program)?;
-    assert_signer(&a.artist_wallet)?;
-    assert_owned_by(&a.artist_aov_token, &spl_token::id())?;
+    if has_collection {
+        a.collection_mint = Some(next_account_info(account_iter)?);
+        a.collection_metaplex_meta = Some(next_account_info(account_iter)?);
+        a.collection_master_edition = Some(next_account_info(account_iter)?);
+    }
+    a.co_creators = account_iter.collect();
 
-    // TODO: More checks!
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (
+                a.artist_aov_token,
+                &[Ata {
+                    mint: &Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                    owner: a.artist_wallet.key,
+                }],
+            ),
+            (a.treasury_aov_token, &[AllovrTreasury]),
+            (a.artwork_meta, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.metaplex_meta_program_account, &[MetaplexProgram]),
+        ],
+    )?;
 
     Ok(a)
 }
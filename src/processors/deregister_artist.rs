@@ -0,0 +1,140 @@
+use crate::state::{ArtistMarket, ArtistMetadata, ArtistProfile, ArtistSymbol, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_token_meta: &'a AccountInfo<'b>,
+    artist_token_metaplex_meta: &'a AccountInfo<'b>,
+    metaplex_meta_program_account: &'a AccountInfo<'b>,
+    artist_market: &'a AccountInfo<'b>,
+    artist_symbol: &'a AccountInfo<'b>,
+    artist_profile: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    msg!("Checking artist PDAs...");
+    let (metadata, artist_token_mint_pda) =
+        ArtistMetadata::load_for_artist(a.artist_token_meta, program_id, a.artist_wallet.key)?;
+    if artist_token_mint_pda != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidPda.into());
+    }
+
+    let mut market = ArtistMarket::load(a.artist_market, program_id)?;
+    assert_pda_with_bump(
+        a.artist_market,
+        program_id,
+        &pda::artist_market_seeds(a.artist_token_mint.key).as_slices(),
+        market.bump,
+    )?;
+
+    let (metaplex_meta_key, _) =
+        mpl_token_metadata::pda::find_metadata_account(a.artist_token_mint.key);
+    if *a.artist_token_metaplex_meta.key != metaplex_meta_key {
+        return Err(AllovrError::InvalidPda.into());
+    }
+
+    msg!("Checking artist has no artworks under offer...");
+    if metadata.open_offers > 0 {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    let symbol_seed = normalise_artist_symbol(&metadata.symbol);
+    let artist_symbol = ArtistSymbol::load(a.artist_symbol, program_id)?;
+    assert_pda_with_bump(
+        a.artist_symbol,
+        program_id,
+        &pda::artist_symbol_seeds(&metadata.symbol).as_slices(),
+        artist_symbol.bump,
+    )?;
+
+    if artist_symbol.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidPda.into());
+    }
+
+    // personal data is removed from the token metadata, the symbol stays so wallets still show it
+    if !a.artist_token_metaplex_meta.data_is_empty() {
+        msg!("Removing artist details from Metaplex metadata...");
+        update_metaplex_metadata_account(
+            a.artist_token_metaplex_meta,
+            a.artist_token_mint,
+            a.metaplex_meta_program_account,
+            String::new(),
+            metadata.symbol,
+            String::new(),
+            metaplex_metadata_creators(a.artist_token_metaplex_meta)?,
+            &pda::artist_mint_seeds(a.artist_wallet.key)
+                .with_bump(metadata.mint_bump)
+                .as_slices(),
+        )?;
+    }
+
+    msg!("Closing artist metadata account...");
+    close_program_account(a.artist_token_meta, a.artist_wallet)?;
+
+    // the profile only exists once the artist has set one with UpdateArtist
+    if !a.artist_profile.data_is_empty() {
+        let profile = ArtistProfile::load(a.artist_profile, program_id)?;
+        assert_pda_with_bump(
+            a.artist_profile,
+            program_id,
+            &pda::artist_profile_seeds(a.artist_token_mint.key).as_slices(),
+            profile.bump,
+        )?;
+
+        msg!("Closing artist profile account...");
+        close_program_account(a.artist_profile, a.artist_wallet)?;
+    }
+
+    msg!("Releasing artist symbol {}...", symbol_seed);
+    close_program_account(a.artist_symbol, a.artist_wallet)?;
+
+    market.active = false;
+    market.save(a.artist_market)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_token_meta: next_account_info(account_iter)?,
+        artist_token_metaplex_meta: next_account_info(account_iter)?,
+        metaplex_meta_program_account: next_account_info(account_iter)?,
+        artist_market: next_account_info(account_iter)?,
+        artist_symbol: next_account_info(account_iter)?,
+        artist_profile: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.artist_token_meta, &[ProgramOwned, Writable]),
+            (a.artist_market, &[ProgramOwned, Writable]),
+            (a.artist_symbol, &[ProgramOwned, Writable]),
+            (a.metaplex_meta_program_account, &[MetaplexProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,111 @@
+use crate::instruction::DistributeArtistCommunityTokensArgs;
+use crate::state::{AllovrTokenState, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    dao_authority: &'a AccountInfo<'b>,
+    state: &'a AccountInfo<'b>,
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_community: &'a AccountInfo<'b>,
+    artist_community_artist_token: &'a AccountInfo<'b>,
+    recipient_wallet: &'a AccountInfo<'b>,
+    recipient_artist_token: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: DistributeArtistCommunityTokensArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    msg!("Checking artist token mint and community treasury PDAs...");
+    let (artist_token_mint_pda, _artist_token_mint_bump) = assert_pda(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(a.artist_wallet.key).as_slices(),
+    )?;
+
+    let (artist_community_pda, artist_community_bump) = assert_pda(
+        a.artist_community,
+        program_id,
+        &pda::artist_community_seeds(a.artist_token_mint.key).as_slices(),
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.artist_community_artist_token,
+        artist_token_mint_pda,
+        artist_community_pda,
+    )?;
+    assert_ata_mint_and_owner(
+        a.recipient_artist_token,
+        artist_token_mint_pda,
+        *a.recipient_wallet.key,
+    )?;
+
+    let treasury_balance = get_token_account(a.artist_community_artist_token)?.amount;
+    if args.amount == 0 || args.amount > treasury_balance {
+        return Err(AllovrError::InsufficientCommunityTokens.into());
+    }
+
+    msg!("Distributing {} community artist tokens...", args.amount);
+    transfer_token_signed(
+        a.token_program,
+        a.artist_community_artist_token,
+        a.recipient_artist_token,
+        a.artist_community,
+        args.amount,
+        &pda::artist_community_seeds(a.artist_token_mint.key)
+            .with_bump(artist_community_bump)
+            .as_slices(),
+    )?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        dao_authority: next_account_info(account_iter)?,
+        state: next_account_info(account_iter)?,
+        artist_wallet: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_community: next_account_info(account_iter)?,
+        artist_community_artist_token: next_account_info(account_iter)?,
+        recipient_wallet: next_account_info(account_iter)?,
+        recipient_artist_token: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(program_id, &[(a.state, &[AllovrState])])?;
+    let state = AllovrTokenState::load(a.state, program_id)?;
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.dao_authority, &[DaoAuthority(&state.dao_authority)]),
+            (a.artist_community_artist_token, &[Writable]),
+            (a.recipient_artist_token, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,250 @@
+use crate::instruction::FractionaliseArtworkArgs;
+use crate::state::{ArtworkMetadata, ArtworkVault, ArtworkVaultState, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTWORK_VAULT_SIZE, MINT_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_artwork_ata: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    vault: &'a AccountInfo<'b>,
+    vault_artwork_ata: &'a AccountInfo<'b>,
+    fraction_mint: &'a AccountInfo<'b>,
+    holder_fraction_ata: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: FractionaliseArtworkArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let rent = Rent::get()?;
+
+    if args.fraction_supply == 0 || args.reserve_price == 0 {
+        return Err(AllovrError::InvalidArtworkVault.into());
+    }
+
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+
+    if *a.holder_wallet.key != metadata.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if metadata.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    msg!("Checking artwork mint and metadata PDAs...");
+    let artwork_mint_pda = assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    assert_ata_mint_and_owner(a.holder_artwork_ata, artwork_mint_pda, *a.holder_wallet.key)?;
+
+    // the vault is created here, so its bump has to be searched for, later instructions use
+    // the one it stores
+    let (vault_pda, vault_bump) = assert_pda(
+        a.vault,
+        program_id,
+        &pda::artwork_vault_seeds(a.artwork_mint.key).as_slices(),
+    )?;
+
+    let fraction_mint_bump = match metadata.fraction_mint_bump {
+        Some(fraction_mint_bump) => {
+            assert_pda_with_bump(
+                a.fraction_mint,
+                program_id,
+                &pda::artwork_fraction_mint_seeds(a.artwork_mint.key).as_slices(),
+                fraction_mint_bump,
+            )?;
+            fraction_mint_bump
+        }
+        None => {
+            assert_pda(
+                a.fraction_mint,
+                program_id,
+                &pda::artwork_fraction_mint_seeds(a.artwork_mint.key).as_slices(),
+            )?
+            .1
+        }
+    };
+
+    let fraction_mint_signer =
+        pda::artwork_fraction_mint_seeds(a.artwork_mint.key).with_bump(fraction_mint_bump);
+    let fraction_mint_signer_seeds = &fraction_mint_signer.as_slices()[..];
+
+    msg!("Creating artwork vault...");
+    create_raw(
+        *program_id,
+        a.vault,
+        &rent,
+        a.system,
+        a.holder_wallet,
+        ARTWORK_VAULT_SIZE,
+        &pda::artwork_vault_seeds(a.artwork_mint.key)
+            .with_bump(vault_bump)
+            .as_slices(),
+    )?;
+
+    if a.vault_artwork_ata.data_is_empty() {
+        create_ata(
+            a.holder_wallet,
+            a.vault,
+            a.vault_artwork_ata,
+            a.artwork_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(a.vault_artwork_ata, artwork_mint_pda, vault_pda)?;
+    }
+
+    msg!("Locking artwork in vault...");
+    transfer_token(
+        a.token_program,
+        a.holder_artwork_ata,
+        a.vault_artwork_ata,
+        a.holder_wallet,
+        1,
+    )?;
+
+    // an artwork fractionalised before keeps its fraction mint, all of whose supply was burnt
+    if a.fraction_mint.data_is_empty() {
+        msg!("Creating fraction mint...");
+        create_raw(
+            spl_token::id(),
+            a.fraction_mint,
+            &rent,
+            a.system,
+            a.holder_wallet,
+            MINT_SIZE,
+            fraction_mint_signer_seeds,
+        )?;
+
+        initalise_pda_mint_account(
+            a.fraction_mint,
+            a.holder_wallet,
+            a.rent_sysvar,
+            fraction_mint_signer_seeds,
+            0,
+        )?;
+    } else {
+        assert_owned_by(a.fraction_mint, &spl_token::id())?;
+        if get_token_supply(a.fraction_mint) != 0 {
+            return Err(AllovrError::InvalidArtworkVault.into());
+        }
+    }
+
+    if a.holder_fraction_ata.data_is_empty() {
+        create_ata(
+            a.holder_wallet,
+            a.holder_wallet,
+            a.holder_fraction_ata,
+            a.fraction_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidAssociatedTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.holder_fraction_ata,
+            *a.fraction_mint.key,
+            *a.holder_wallet.key,
+        )?;
+    }
+
+    msg!("Minting {} fractions...", args.fraction_supply);
+    mint_tokens_to(
+        a.fraction_mint,
+        a.fraction_mint,
+        a.holder_fraction_ata,
+        &[fraction_mint_signer_seeds],
+        args.fraction_supply,
+        false,
+    )?;
+
+    let vault = ArtworkVault {
+        curator: *a.holder_wallet.key,
+        artwork_mint: *a.artwork_mint.key,
+        fraction_mint: *a.fraction_mint.key,
+        fraction_supply: args.fraction_supply,
+        reserve_price: args.reserve_price,
+        currency: args.currency,
+        state: ArtworkVaultState::Active,
+        proceeds: 0,
+        bump: vault_bump,
+    };
+
+    vault.save(a.vault)?;
+
+    // the vault only holds the artwork in custody, the buyout or redemption that takes it out
+    // records the change of ownership with set_holder
+    metadata.holder = vault_pda;
+    metadata.fraction_mint_bump = Some(fraction_mint_bump);
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_artwork_ata: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+        vault: next_account_info(account_iter)?,
+        vault_artwork_ata: next_account_info(account_iter)?,
+        fraction_mint: next_account_info(account_iter)?,
+        holder_fraction_ata: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.holder_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.vault, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,214 @@
+use crate::instruction::InitArtworkBundleSaleArgs;
+use crate::state::{ArtistMetadata, ArtworkBundle, ArtworkMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTWORK_BUNDLE_MAX_ARTWORKS, ARTWORK_BUNDLE_MIN_ARTWORKS, ARTWORK_BUNDLE_SIZE,
+    TOKEN_ACCOUNT_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct ArtworkAccounts<'a, 'b: 'a> {
+    seller_artwork_ata: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    escrow: &'a AccountInfo<'b>,
+    artist_meta: &'a AccountInfo<'b>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    seller_wallet: &'a AccountInfo<'b>,
+    bundle: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    artworks: Vec<ArtworkAccounts<'a, 'b>>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: InitArtworkBundleSaleArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let rent = Rent::get()?;
+
+    if a.artworks.len() < ARTWORK_BUNDLE_MIN_ARTWORKS
+        || a.artworks.len() > ARTWORK_BUNDLE_MAX_ARTWORKS
+    {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    let first_mint = a.artworks[0].artwork_mint.key;
+    let (bundle_pda, bundle_bump) = assert_pda(
+        a.bundle,
+        program_id,
+        &pda::artwork_bundle_seeds(first_mint).as_slices(),
+    )?;
+
+    msg!("Creating artwork bundle account...");
+    create_raw(
+        *program_id,
+        a.bundle,
+        &rent,
+        a.system,
+        a.seller_wallet,
+        ARTWORK_BUNDLE_SIZE,
+        &pda::artwork_bundle_seeds(first_mint)
+            .with_bump(bundle_bump)
+            .as_slices(),
+    )?;
+
+    for artwork in a.artworks.iter() {
+        // metadata is written back per artwork, so listing the same artwork twice fails here
+        let mut metadata = ArtworkMetadata::load(artwork.artwork_meta, program_id)?;
+
+        if *a.seller_wallet.key != metadata.holder {
+            return Err(AllovrError::IncorrectArtworkHolder.into());
+        }
+
+        if metadata.is_under_offer() {
+            return Err(AllovrError::ArtworkUnderOffer.into());
+        }
+
+        let artwork_mint_pda = assert_pda_with_bump(
+            artwork.artwork_mint,
+            program_id,
+            &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+            metadata.mint_bump,
+        )?;
+
+        assert_pda_with_bump(
+            artwork.artwork_meta,
+            program_id,
+            &pda::artwork_metadata_seeds(artwork.artwork_mint.key).as_slices(),
+            metadata.bump,
+        )?;
+
+        assert_ata_mint_and_owner(
+            artwork.seller_artwork_ata,
+            artwork_mint_pda,
+            *a.seller_wallet.key,
+        )?;
+
+        metadata.offer_count += 1;
+        metadata.bundle = Some(bundle_pda);
+
+        let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+
+        let (escrow_pda, escrow_bump) =
+            assert_pda(artwork.escrow, program_id, &escrow_seeds.as_slices())?;
+        metadata.escrow_bump = Some(escrow_bump);
+
+        msg!("Moving artwork {} to escrow...", artwork.artwork_mint.key);
+        create_raw(
+            spl_token::id(),
+            artwork.escrow,
+            &rent,
+            a.system,
+            a.seller_wallet,
+            TOKEN_ACCOUNT_SIZE,
+            &escrow_seeds.with_bump(escrow_bump).as_slices(),
+        )?;
+
+        initialise_token_account(
+            artwork.escrow,
+            artwork.artwork_mint,
+            escrow_pda,
+            a.rent_sysvar,
+        )?;
+
+        transfer_token(
+            a.token_program,
+            artwork.seller_artwork_ata,
+            artwork.escrow,
+            a.seller_wallet,
+            1,
+        )?;
+
+        metadata.save(artwork.artwork_meta)?;
+        ArtistMetadata::count_open_offer(artwork.artist_meta, program_id, &metadata.artist, true)?;
+    }
+
+    let bundle = ArtworkBundle {
+        seller: *a.seller_wallet.key,
+        artworks: a.artworks.iter().map(|x| *x.artwork_mint.key).collect(),
+        offered_to: args.buyer,
+        price: args.amount,
+        currency: args.currency,
+        payment_account: args.payment_account,
+        bump: bundle_bump,
+    };
+
+    bundle.save(a.bundle)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let seller_wallet = next_account_info(account_iter)?;
+    let bundle = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    let rent_sysvar = next_account_info(account_iter)?;
+    let system = next_account_info(account_iter)?;
+
+    let remaining = account_iter.as_slice();
+    if remaining.len() % 5 != 0 {
+        return Err(AllovrError::InvalidArtworkBundle.into());
+    }
+
+    let artworks = remaining
+        .chunks(5)
+        .map(|x| ArtworkAccounts {
+            seller_artwork_ata: &x[0],
+            artwork_mint: &x[1],
+            artwork_meta: &x[2],
+            escrow: &x[3],
+            artist_meta: &x[4],
+        })
+        .collect();
+
+    let a = Accounts {
+        seller_wallet,
+        bundle,
+        token_program,
+        rent_sysvar,
+        system,
+        artworks,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.seller_wallet, &[Signer, Writable]),
+            (a.bundle, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    for artwork in a.artworks.iter() {
+        validate_accounts(
+            program_id,
+            &[
+                (artwork.artwork_meta, &[ProgramOwned, Writable]),
+                (artwork.artist_meta, &[Writable]),
+            ],
+        )?;
+    }
+
+    Ok(a)
+}
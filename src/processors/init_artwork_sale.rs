@@ -1,14 +1,14 @@
-use crate::instruction::InitArtworkSaleArgs;
-use crate::state::ArtworkMetadata;
-use crate::ARTWORK_SEED_PREFIX;
+use crate::instruction::{InitArtworkDutchAuctionArgs, InitArtworkSaleArgs};
+use crate::state::{ArtistMetadata, ArtworkMetadata, Currency, DutchAuction, ProgramAccount};
 use crate::TOKEN_ACCOUNT_SIZE;
-use crate::{error::AllovrError, utils::*, ARTWORK_ESCROW_SEED_PREFIX};
-use borsh::BorshSerialize;
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
 use solana_program::sysvar::rent::Rent;
 use solana_program::sysvar::Sysvar;
 
-use solana_program::borsh::try_from_slice_unchecked;
-
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
@@ -25,18 +25,67 @@ struct Accounts<'a, 'b: 'a> {
     token_program: &'a AccountInfo<'b>,
     rent_sysvar: &'a AccountInfo<'b>,
     system: &'a AccountInfo<'b>,
+    artist_meta: &'a AccountInfo<'b>,
 }
 
 pub fn execute(
     accounts: &[AccountInfo],
     program_id: &Pubkey,
     args: InitArtworkSaleArgs,
+) -> ProgramResult {
+    init_sale(
+        accounts,
+        program_id,
+        Some(args.buyer),
+        args.amount,
+        args.currency,
+        args.payment_account,
+        None,
+    )
+}
+
+pub fn execute_dutch_auction(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: InitArtworkDutchAuctionArgs,
+) -> ProgramResult {
+    if args.start_price <= args.floor_price || args.end_time <= args.start_time {
+        return Err(AllovrError::InvalidDutchAuction.into());
+    }
+
+    // open to any buyer, the price is worked out when the sale is accepted
+    init_sale(
+        accounts,
+        program_id,
+        None,
+        args.start_price,
+        args.currency,
+        args.payment_account,
+        Some(DutchAuction {
+            start_price: args.start_price,
+            floor_price: args.floor_price,
+            start_time: args.start_time,
+            end_time: args.end_time,
+        }),
+    )
+}
+
+fn init_sale(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    buyer: Option<Pubkey>,
+    price: u64,
+    currency: Currency,
+    payment_account: Pubkey,
+    dutch_auction: Option<DutchAuction>,
 ) -> ProgramResult {
     let a = parse_accounts(program_id, accounts)?;
     let rent = Rent::get()?;
 
-    let mut metadata: ArtworkMetadata =
-        try_from_slice_unchecked(&a.artwork_meta.data.borrow_mut())?;
+    let (mut metadata, mint_pub_key) = ArtworkMetadata::load_with_mint(a.artwork_meta, program_id)?;
+    if mint_pub_key != *a.artwork_mint.key {
+        return Err(AllovrError::InvalidPda.into());
+    }
 
     if *a.artwork_holder_wallet.key != metadata.holder {
         return Err(AllovrError::MissingRequiredSignature.into());
@@ -45,30 +94,26 @@ pub fn execute(
     if metadata.offered_to != None
         || metadata.offer_currency != None
         || metadata.offer_price != None
+        || metadata.swap.is_some()
+        || metadata.bundle.is_some()
     {
         return Err(AllovrError::ArtworkUnderOffer.into());
     }
 
-    metadata.offered_to = Some(args.buyer);
-    metadata.offer_price = Some(args.amount);
-    metadata.offer_currency = Some(args.currency);
-    metadata.payment_account = Some(args.payment_account);
+    metadata.offered_to = buyer;
+    metadata.offer_price = Some(price);
+    metadata.offer_currency = Some(currency);
+    metadata.payment_account = Some(payment_account);
+    metadata.dutch_auction = dutch_auction;
     metadata.offer_count += 1;
 
-    metadata.serialize(&mut &mut a.artwork_meta.data.borrow_mut()[..])?;
-
-    let symbol_bytes = metadata.symbol.as_bytes();
-    let offer_count = metadata.offer_count.to_string();
+    // the escrow is found once here, accept and cancel recreate it from the stored bump
+    let escrow_seeds = pda::escrow_seeds(&metadata, metadata.offer_count);
+    let (escrow_pda, escrow_bump) = assert_pda(&a.escrow, program_id, &escrow_seeds.as_slices())?;
+    metadata.escrow_bump = Some(escrow_bump);
 
-    let (mint_pub_key, _mint_seed_bump) = assert_pda(
-        &a.artwork_mint,
-        program_id,
-        &[
-            ARTWORK_SEED_PREFIX.as_bytes(),
-            symbol_bytes,
-            metadata.artist.as_ref(),
-        ],
-    )?;
+    metadata.save(a.artwork_meta)?;
+    ArtistMetadata::count_open_offer(a.artist_meta, program_id, &metadata.artist, true)?;
 
     // check that the artwork ATA really belongs to the seller wallet
     assert_ata_mint_and_owner(
@@ -77,17 +122,6 @@ pub fn execute(
         *a.artwork_holder_wallet.key,
     )?;
 
-    let (escrow_pda, escrow_bump) = assert_pda(
-        &a.escrow,
-        program_id,
-        &[
-            ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-            symbol_bytes,
-            offer_count.as_bytes(),
-            metadata.artist.as_ref(),
-        ],
-    )?;
-
     create_raw(
         spl_token::id(),
         &a.escrow,
@@ -95,13 +129,7 @@ pub fn execute(
         &a.system,
         &a.artwork_holder_wallet,
         TOKEN_ACCOUNT_SIZE,
-        &[
-            ARTWORK_ESCROW_SEED_PREFIX.as_bytes(),
-            symbol_bytes,
-            offer_count.as_bytes(),
-            metadata.artist.as_ref(),
-            &[escrow_bump],
-        ],
+        &escrow_seeds.with_bump(escrow_bump).as_slices(),
     )?;
 
     initialise_token_account(&a.escrow, &a.artwork_mint, escrow_pda, &a.rent_sysvar)?;
@@ -114,7 +142,6 @@ pub fn execute(
         1,
     )?;
 
-    // return Err(AllovrError::ManualFail.into());
     Ok(())
 }
 
@@ -132,18 +159,24 @@ fn parse_accounts<'a, 'b: 'a>(
         token_program: next_account_info(account_iter)?,
         rent_sysvar: next_account_info(account_iter)?,
         system: next_account_info(account_iter)?,
+        artist_meta: next_account_info(account_iter)?,
     };
 
-    assert_program_id(program_id)?;
-    assert_token_program_matches_package(a.token_program)?;
-    assert_signer(&a.artwork_holder_wallet)?;
-    assert_owned_by(&a.artwork_holder_ata, &spl_token::id())?;
-    assert_owned_by(&a.artwork_meta, &program_id)?;
-
-    assert_ata_mint_and_owner(
-        a.artwork_holder_ata,
-        *a.artwork_mint.key,
-        *a.artwork_holder_wallet.key,
+    validate_accounts(
+        program_id,
+        &[
+            (a.artwork_holder_wallet, &[Signer, Writable]),
+            (
+                a.artwork_holder_ata,
+                &[Ata {
+                    mint: a.artwork_mint.key,
+                    owner: a.artwork_holder_wallet.key,
+                }],
+            ),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.artist_meta, &[Writable]),
+        ],
     )?;
 
     Ok(a)
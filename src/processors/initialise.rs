@@ -1,10 +1,11 @@
 use crate::instruction::InitialisaAllovrArgs;
-use crate::state::AllovrTokenState;
+use crate::state::{AllovrTokenState, ProgramAccount};
 use crate::{
-    error::AllovrError, utils::*, ALLOVR_MINT_SEED_PREFIX, ALL_DECIMAL_PLACES, MINT_SIZE,
-    STATE_SIZE,
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALL_DECIMAL_PLACES, MINT_SIZE, STATE_SIZE,
 };
-use borsh::BorshSerialize;
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -54,8 +55,9 @@ pub fn execute(
         founder_6: args.founder_6,
         founder_7: args.founder_7,
         founder_8: args.founder_8,
+        dao_authority: args.dao_authority,
     };
-    state_data.serialize(&mut &mut a.state.data.borrow_mut()[..])?;
+    state_data.save(a.state)?;
 
     // Create Mint Account. Mint address is known ALLOVR_MINT_ID
     create_account(&rent, MINT_SIZE, &a.payer, &a.mint, spl_token::id())?;
@@ -64,15 +66,16 @@ pub fn execute(
     let (_mint_auth_pda, mint_auth_pda_bump) = assert_pda(
         &a.mint_authority,
         &program_id,
-        &[ALLOVR_MINT_SEED_PREFIX.as_bytes()],
+        &pda::allovr_mint_authority_seeds().as_slices(),
     )?;
 
     initalise_mint_account(
         &a.mint,
         &a.mint_authority, // authority is PDA
         &a.rent_sysvar,
-        ALLOVR_MINT_SEED_PREFIX,
-        mint_auth_pda_bump,
+        &pda::allovr_mint_authority_seeds()
+            .with_bump(mint_auth_pda_bump)
+            .as_slices(),
         ALL_DECIMAL_PLACES,
     )?;
 
@@ -95,16 +98,26 @@ fn parse_accounts<'a, 'b: 'a>(
         system: next_account_info(account_iter)?,
     };
 
-    assert_program_id(program_id)?;
-    assert_system(&a.system)?;
-    assert_state(&a.state.key)?;
-    assert_token_program_matches_package(&a.token_program)?;
-
-    assert_owned_by(a.payer, &solana_program::system_program::id())?; // standard SOL account
-    assert_signer(a.payer)?;
+    validate_accounts(
+        program_id,
+        &[
+            // keys used to delpoy contract must be present as signer
+            (a.initiator, &[Signer]),
+            (a.state, &[AllovrState, Writable]),
+            // standard SOL account
+            (
+                a.payer,
+                &[
+                    Owner(&solana_program::system_program::id()),
+                    Signer,
+                    Writable,
+                ],
+            ),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
 
-    // keys used to delpoy contract must be present as signer
-    assert_signer(a.initiator)?;
     if a.initiator.key != program_id {
         return Err(AllovrError::InvalidInitialiser.into());
     }
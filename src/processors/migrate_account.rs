@@ -0,0 +1,188 @@
+use crate::state::{
+    AllovrTokenState, ArtistMetadata, ArtworkCreator, ArtworkMetadata, LegacyAllovrTokenState,
+    LegacyArtistMetadata, LegacyArtworkMetadata, ProgramAccount,
+};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_METADATA_SIZE, ARTWORK_METADATA_SIZE, LEGACY_ARTIST_METADATA_SIZE,
+    LEGACY_ARTWORK_METADATA_SIZE, LEGACY_STATE_SIZE, STATE_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::borsh::try_from_slice_unchecked;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    payer: &'a AccountInfo<'b>,
+    account: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    artist_wallet: Option<&'a AccountInfo<'b>>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let rent = Rent::get()?;
+
+    // unversioned accounts are told apart by the fixed size they were created with. Headed
+    // accounts are upgraded here once a layout VERSION is bumped.
+    match a.account.data_len() {
+        LEGACY_STATE_SIZE => {
+            msg!("Migrating ALLOVR token state...");
+            let legacy: LegacyAllovrTokenState =
+                try_from_slice_unchecked(&a.account.data.borrow())?;
+
+            // legacy state has no DAO authority, it is left unset, which no signer matches,
+            // until the program keypair sets it with SetDaoAuthority
+            let state = AllovrTokenState {
+                minted: legacy.minted,
+                next_inflation_due: legacy.next_inflation_due,
+                inflation_run_count: legacy.inflation_run_count,
+                founder_1: legacy.founder_1,
+                founder_2: legacy.founder_2,
+                founder_3: legacy.founder_3,
+                founder_4: legacy.founder_4,
+                founder_5: legacy.founder_5,
+                founder_6: legacy.founder_6,
+                founder_7: legacy.founder_7,
+                founder_8: legacy.founder_8,
+                dao_authority: Pubkey::default(),
+            };
+
+            resize_program_account(a.account, a.payer, a.system, &rent, STATE_SIZE)?;
+            state.save(a.account)
+        }
+        LEGACY_ARTIST_METADATA_SIZE => {
+            msg!("Migrating artist metadata...");
+            let legacy: LegacyArtistMetadata = try_from_slice_unchecked(&a.account.data.borrow())?;
+            let (bump, mint_bump) = find_artist_metadata_bumps(&a, program_id)?;
+            let metadata = ArtistMetadata {
+                name: legacy.name,
+                description: legacy.description,
+                symbol: legacy.symbol,
+                uri: legacy.uri,
+                verified: false,
+                bump,
+                mint_bump,
+                // offers opened before the count existed are not in it, closing one leaves it at 0
+                open_offers: 0,
+            };
+
+            resize_program_account(a.account, a.payer, a.system, &rent, ARTIST_METADATA_SIZE)?;
+            metadata.save(a.account)
+        }
+        LEGACY_ARTWORK_METADATA_SIZE => {
+            msg!("Migrating artwork metadata...");
+            let legacy: LegacyArtworkMetadata = try_from_slice_unchecked(&a.account.data.borrow())?;
+
+            // legacy artworks paid the artist in full and had no royalties, an artwork that
+            // has left the artist has had its primary sale
+            let mut metadata = ArtworkMetadata {
+                artist: legacy.artist,
+                symbol: legacy.symbol,
+                description: legacy.description,
+                holder: legacy.holder,
+                offer_count: legacy.offer_count,
+                offered_to: legacy.offered_to,
+                offer_price: legacy.offer_price,
+                offer_currency: legacy.offer_currency,
+                payment_account: legacy.payment_account,
+                max_editions: 0,
+                editions_printed: 0,
+                print: None,
+                content_uri: String::new(),
+                content_hash: [0; 32],
+                creators: vec![ArtworkCreator {
+                    address: legacy.artist,
+                    share: 100,
+                }],
+                seller_fee_basis_points: 0,
+                primary_sale_happened: legacy.holder != legacy.artist,
+                dutch_auction: None,
+                swap: None,
+                bundle: None,
+                bump: 0,
+                mint_bump: 0,
+                escrow_bump: None,
+                fraction_mint_bump: None,
+            };
+            set_artwork_metadata_bumps(&a, program_id, &mut metadata)?;
+
+            resize_program_account(a.account, a.payer, a.system, &rent, ARTWORK_METADATA_SIZE)?;
+            metadata.save(a.account)
+        }
+        _ => Err(AllovrError::AccountAlreadyMigrated.into()),
+    }
+}
+
+/// Artist metadata does not record its artist, so the artist wallet is passed to find the
+/// bumps of the metadata and artist token mint
+fn find_artist_metadata_bumps(a: &Accounts, program_id: &Pubkey) -> Result<(u8, u8), ProgramError> {
+    let artist_wallet = a.artist_wallet.ok_or(AllovrError::MissingArtistWallet)?;
+    let (artist_token_mint, mint_bump) = pda::find_artist_mint(program_id, artist_wallet.key);
+
+    let (_metadata_key, bump) = assert_pda(
+        a.account,
+        program_id,
+        &pda::artist_metadata_seeds(&artist_token_mint).as_slices(),
+    )?;
+
+    Ok((bump, mint_bump))
+}
+
+/// Bumps of the artwork metadata, its mint and the escrow of an artwork under offer
+fn set_artwork_metadata_bumps(
+    a: &Accounts,
+    program_id: &Pubkey,
+    metadata: &mut ArtworkMetadata,
+) -> ProgramResult {
+    let (artwork_mint, mint_bump) = pda::find_artwork_metadata_mint(program_id, metadata);
+
+    let (_metadata_key, bump) = assert_pda(
+        a.account,
+        program_id,
+        &pda::artwork_metadata_seeds(&artwork_mint).as_slices(),
+    )?;
+
+    metadata.bump = bump;
+    metadata.mint_bump = mint_bump;
+    if metadata.is_under_offer() {
+        let (_escrow_key, escrow_bump) =
+            pda::find_escrow(program_id, metadata, metadata.offer_count);
+        metadata.escrow_bump = Some(escrow_bump);
+    }
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        payer: next_account_info(account_iter)?,
+        account: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        artist_wallet: account_iter.next(),
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.payer, &[Signer, Writable]),
+            (a.account, &[ProgramOwned, Writable]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -1,12 +1,14 @@
-use crate::state::AllovrTokenState;
+use crate::state::{AllovrTokenState, ProgramAccount};
 use crate::INFLATION_INTERVAL_IN_SECONDS;
-use crate::{error::AllovrError, utils::*, ALLOVR_MINT_SEED_PREFIX};
-use borsh::BorshSerialize;
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
 use solana_program::sysvar::clock::Clock;
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
-    borsh::try_from_slice_unchecked,
     entrypoint::ProgramResult,
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -46,7 +48,7 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
 
     let clock = Clock::from_account_info(a.clock_sysvar)?;
 
-    let mut state: AllovrTokenState = try_from_slice_unchecked(&a.state.data.borrow())?;
+    let mut state = AllovrTokenState::load(a.state, program_id)?;
 
     if state.minted {
         return Err(AllovrError::AlreadyMinted.into());
@@ -79,7 +81,7 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
 
     state.minted = true;
     state.next_inflation_due = clock.unix_timestamp + INFLATION_INTERVAL_IN_SECONDS;
-    state.serialize(&mut &mut a.state.data.borrow_mut()[..])?;
+    state.save(a.state)?;
 
     let total = 1000000000.0;
     let founder_share = ui_amount_to_amount(total * 0.0375);
@@ -99,10 +101,11 @@ pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
     let (_mint_auth_pda, mint_auth_pda_bump) = assert_pda(
         &a.mint_authority,
         &program_id,
-        &[ALLOVR_MINT_SEED_PREFIX.as_bytes()],
+        &pda::allovr_mint_authority_seeds().as_slices(),
     )?;
 
-    let signer_seeds = &[ALLOVR_MINT_SEED_PREFIX.as_bytes(), &[mint_auth_pda_bump]];
+    let signer = pda::allovr_mint_authority_seeds().with_bump(mint_auth_pda_bump);
+    let signer_seeds = &signer.as_slices()[..];
 
     for r in recipients {
         assert_rent_exempt(&rent, &r.0)?;
@@ -146,34 +149,59 @@ fn parse_accounts<'a, 'b: 'a>(
         system: next_account_info(account_iter)?,
     };
 
-    assert_program_id(program_id)?;
-    assert_system(&a.system)?;
-    assert_state(&a.state.key)?;
-    assert_token_program_matches_package(&a.token_program)?;
-
-    assert_owned_by(a.payer, &solana_program::system_program::id())?;
-    assert_owned_by(a.treasury_token, &spl_token::id())?;
-    assert_owned_by(a.founder_1_token, &spl_token::id())?;
-    assert_owned_by(a.founder_2_token, &spl_token::id())?;
-    assert_owned_by(a.founder_3_token, &spl_token::id())?;
-    assert_owned_by(a.founder_4_token, &spl_token::id())?;
-    assert_owned_by(a.founder_5_token, &spl_token::id())?;
-    assert_owned_by(a.founder_6_token, &spl_token::id())?;
-    assert_owned_by(a.founder_7_token, &spl_token::id())?;
-    assert_owned_by(a.founder_8_token, &spl_token::id())?;
-
-    assert_rent_exempt(rent, a.treasury_token)?;
-    assert_rent_exempt(rent, a.founder_1_token)?;
-    assert_rent_exempt(rent, a.founder_2_token)?;
-    assert_rent_exempt(rent, a.founder_3_token)?;
-    assert_rent_exempt(rent, a.founder_4_token)?;
-    assert_rent_exempt(rent, a.founder_5_token)?;
-    assert_rent_exempt(rent, a.founder_6_token)?;
-    assert_rent_exempt(rent, a.founder_7_token)?;
-    assert_rent_exempt(rent, a.founder_8_token)?;
-
-    assert_signer(a.payer)?;
-    assert_signer(a.initiator)?;
+    validate_accounts(
+        program_id,
+        &[
+            (a.initiator, &[Signer]),
+            (a.state, &[AllovrState, Writable]),
+            (
+                a.payer,
+                &[
+                    Owner(&solana_program::system_program::id()),
+                    Signer,
+                    Writable,
+                ],
+            ),
+            (
+                a.treasury_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_1_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_2_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_3_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_4_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_5_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_6_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_7_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (
+                a.founder_8_token,
+                &[Owner(&spl_token::id()), RentExempt(rent), Writable],
+            ),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
 
     if a.initiator.key != program_id {
         return Err(AllovrError::InvalidInitialiser.into());
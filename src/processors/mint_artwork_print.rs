@@ -0,0 +1,237 @@
+use crate::state::{ArtworkMetadata, ArtworkPrint, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTWORK_METADATA_SIZE, MINT_SIZE,
+};
+
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    master_mint: &'a AccountInfo<'b>,
+    master_meta: &'a AccountInfo<'b>,
+    artist_master_token: &'a AccountInfo<'b>,
+    print_mint: &'a AccountInfo<'b>,
+    print_meta: &'a AccountInfo<'b>,
+    artist_print_token: &'a AccountInfo<'b>,
+    master_metaplex_meta: &'a AccountInfo<'b>,
+    master_edition: &'a AccountInfo<'b>,
+    print_metaplex_meta: &'a AccountInfo<'b>,
+    print_edition: &'a AccountInfo<'b>,
+    edition_marker: &'a AccountInfo<'b>,
+    metaplex_meta_program_account: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let rent = Rent::get()?;
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut master = ArtworkMetadata::load(a.master_meta, program_id)?;
+
+    if master.print.is_some() {
+        return Err(AllovrError::ArtworkIsPrint.into());
+    }
+
+    // only the artist can print, and only while still holding the master
+    if *a.artist_wallet.key != master.artist || *a.artist_wallet.key != master.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if master.editions_printed >= master.max_editions {
+        return Err(AllovrError::MaxEditionsReached.into());
+    }
+
+    msg!("Checking master artwork mint and metadata PDAs...");
+    let master_mint_pda = assert_pda_with_bump(
+        a.master_mint,
+        program_id,
+        &pda::artwork_mint_seeds(&master.symbol, &master.artist).as_slices(),
+        master.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.master_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.master_mint.key).as_slices(),
+        master.bump,
+    )?;
+
+    assert_ata_mint_and_owner(a.artist_master_token, master_mint_pda, *a.artist_wallet.key)?;
+
+    let edition = master.editions_printed + 1;
+    let print_mint_seeds = pda::artwork_print_mint_seeds(a.master_mint.key, edition);
+
+    let (_print_mint_pda, print_mint_bump) =
+        assert_pda(a.print_mint, program_id, &print_mint_seeds.as_slices())?;
+
+    let print_mint_signer = print_mint_seeds.with_bump(print_mint_bump);
+    let print_mint_signer_seeds = &print_mint_signer.as_slices()[..];
+
+    msg!("Creating print mint for edition {}...", edition);
+    create_raw(
+        spl_token::id(),
+        a.print_mint,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        MINT_SIZE,
+        print_mint_signer_seeds,
+    )?;
+
+    initalise_pda_mint_account(
+        a.print_mint,
+        a.artist_wallet,
+        a.rent_sysvar,
+        print_mint_signer_seeds,
+        0,
+    )?;
+
+    create_ata(
+        a.artist_wallet,
+        a.artist_wallet,
+        a.artist_print_token,
+        a.print_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidArtworkTokenAccount,
+    )?;
+
+    // Metaplex requires the print token to exist before printing the edition
+    mint_tokens_to(
+        a.print_mint,
+        a.print_mint,
+        a.artist_print_token,
+        &[print_mint_signer_seeds],
+        1,
+        false,
+    )?;
+
+    msg!("Printing metaplex edition...");
+    mint_metaplex_print_edition(
+        a.print_metaplex_meta,
+        a.print_edition,
+        a.master_edition,
+        a.print_mint,
+        a.edition_marker,
+        a.artist_wallet,
+        a.artist_wallet,
+        a.artist_master_token,
+        a.master_metaplex_meta,
+        a.master_mint,
+        a.metaplex_meta_program_account,
+        a.token_program,
+        a.system,
+        a.rent_sysvar,
+        edition,
+        print_mint_signer_seeds,
+    )?;
+
+    let (_print_meta_key, print_meta_bump) = assert_pda(
+        a.print_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.print_mint.key).as_slices(),
+    )?;
+
+    create_raw(
+        *program_id,
+        a.print_meta,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        ARTWORK_METADATA_SIZE,
+        &pda::artwork_metadata_seeds(a.print_mint.key)
+            .with_bump(print_meta_bump)
+            .as_slices(),
+    )?;
+
+    let print = ArtworkMetadata {
+        artist: master.artist,
+        symbol: String::from(&master.symbol),
+        description: String::from(&master.description),
+        holder: *a.artist_wallet.key,
+        offer_count: 0,
+        offered_to: None,
+        offer_price: None,
+        offer_currency: None,
+        payment_account: None,
+        max_editions: 0,
+        editions_printed: 0,
+        print: Some(ArtworkPrint {
+            parent: *a.master_mint.key,
+            edition,
+        }),
+        content_uri: String::from(&master.content_uri),
+        content_hash: master.content_hash,
+        creators: master.creators.clone(),
+        seller_fee_basis_points: master.seller_fee_basis_points,
+        primary_sale_happened: false,
+        dutch_auction: None,
+        swap: None,
+        bundle: None,
+        bump: print_meta_bump,
+        mint_bump: print_mint_bump,
+        escrow_bump: None,
+        fraction_mint_bump: None,
+    };
+
+    print.save(a.print_meta)?;
+
+    master.editions_printed = edition;
+    master.save(a.master_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        master_mint: next_account_info(account_iter)?,
+        master_meta: next_account_info(account_iter)?,
+        artist_master_token: next_account_info(account_iter)?,
+        print_mint: next_account_info(account_iter)?,
+        print_meta: next_account_info(account_iter)?,
+        artist_print_token: next_account_info(account_iter)?,
+        master_metaplex_meta: next_account_info(account_iter)?,
+        master_edition: next_account_info(account_iter)?,
+        print_metaplex_meta: next_account_info(account_iter)?,
+        print_edition: next_account_info(account_iter)?,
+        edition_marker: next_account_info(account_iter)?,
+        metaplex_meta_program_account: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.master_meta, &[ProgramOwned, Writable]),
+            (a.print_meta, &[Writable]),
+            (a.metaplex_meta_program_account, &[MetaplexProgram]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
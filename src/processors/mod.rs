@@ -1,8 +1,38 @@
+pub mod accept_artwork_bundle_sale;
 pub mod accept_artwork_sale;
+pub mod accept_artwork_swap;
+pub mod airdrop_artist_tokens;
+pub mod buy_artist_tokens;
+pub mod buyout_artwork;
+pub mod cancel_artwork_bundle_sale;
 pub mod cancel_artwork_sale;
+pub mod cancel_artwork_swap;
+pub mod claim_artist_airdrop;
+pub mod claim_artist_revenue;
+pub mod claim_fraction_proceeds;
+pub mod claim_vested_artist_tokens;
+pub mod create_artist_airdrop;
+pub mod create_artist_collection;
 pub mod create_artwork;
+pub mod deregister_artist;
+pub mod distribute_artist_community_tokens;
+pub mod fractionalise_artwork;
+pub mod init_artwork_bundle_sale;
 pub mod init_artwork_sale;
 pub mod initialise;
+pub mod migrate_account;
 pub mod mint_allovr;
+pub mod mint_artwork_print;
+pub mod propose_artwork_swap;
+pub mod redeem_artwork;
 pub mod register_artist;
+pub mod sell_artist_tokens;
+pub mod set_artist_revenue_share;
+pub mod set_dao_authority;
+pub mod stake_artist_tokens;
+pub mod sync_artwork_holder;
+pub mod transfer_artwork;
+pub mod unstake_artist_tokens;
 pub mod update_artist;
+pub mod update_artwork_metadata;
+pub mod verify_artist;
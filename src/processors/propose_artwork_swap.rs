@@ -0,0 +1,273 @@
+use crate::instruction::ProposeArtworkSwapArgs;
+use crate::state::{
+    ArtistMetadata, ArtworkMetadata, ArtworkSwap, ArtworkSwapTopUp, ArtworkSwapTopUpPayer,
+    Currency, ProgramAccount,
+};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID, ARTWORK_SWAP_SIZE, TOKEN_ACCOUNT_SIZE,
+};
+use solana_program::msg;
+use solana_program::program::invoke;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    proposer_wallet: &'a AccountInfo<'b>,
+    proposer_offered_ata: &'a AccountInfo<'b>,
+    offered_mint: &'a AccountInfo<'b>,
+    offered_meta: &'a AccountInfo<'b>,
+    requested_mint: &'a AccountInfo<'b>,
+    requested_meta: &'a AccountInfo<'b>,
+    escrow: &'a AccountInfo<'b>,
+    swap: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    offered_artist_meta: &'a AccountInfo<'b>,
+    proposer_payment: &'a AccountInfo<'b>,
+    swap_aovr_ata: &'a AccountInfo<'b>,
+    aovr_mint: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: ProposeArtworkSwapArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let rent = Rent::get()?;
+
+    if let Some(top_up) = &args.top_up {
+        if top_up.amount == 0 {
+            return Err(AllovrError::InvalidArtworkSwap.into());
+        }
+    }
+
+    let mut offered = ArtworkMetadata::load(a.offered_meta, program_id)?;
+    let requested = ArtworkMetadata::load(a.requested_meta, program_id)?;
+
+    if *a.proposer_wallet.key != offered.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if offered.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    if requested.holder == offered.holder {
+        return Err(AllovrError::InvalidArtworkSwap.into());
+    }
+
+    msg!("Checking artwork mint and metadata PDAs...");
+    let offered_mint_pda = assert_pda_with_bump(
+        a.offered_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&offered).as_slices(),
+        offered.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.offered_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.offered_mint.key).as_slices(),
+        offered.bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.requested_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&requested).as_slices(),
+        requested.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.requested_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.requested_mint.key).as_slices(),
+        requested.bump,
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.proposer_offered_ata,
+        offered_mint_pda,
+        *a.proposer_wallet.key,
+    )?;
+
+    let (swap_pda, swap_bump) = assert_pda(
+        a.swap,
+        program_id,
+        &pda::artwork_swap_seeds(a.offered_mint.key, a.requested_mint.key).as_slices(),
+    )?;
+
+    msg!("Creating artwork swap account...");
+    create_raw(
+        *program_id,
+        a.swap,
+        &rent,
+        a.system,
+        a.proposer_wallet,
+        ARTWORK_SWAP_SIZE,
+        &pda::artwork_swap_seeds(a.offered_mint.key, a.requested_mint.key)
+            .with_bump(swap_bump)
+            .as_slices(),
+    )?;
+
+    let swap = ArtworkSwap {
+        proposer: *a.proposer_wallet.key,
+        offered_mint: *a.offered_mint.key,
+        requested_mint: *a.requested_mint.key,
+        counterparty: requested.holder,
+        top_up: args.top_up,
+        bump: swap_bump,
+    };
+
+    swap.save(a.swap)?;
+
+    if let Some(top_up) = &swap.top_up {
+        if top_up.payer == ArtworkSwapTopUpPayer::Proposer {
+            msg!("Holding proposer top-up in the artwork swap...");
+            hold_top_up(&a, top_up, swap_pda)?;
+        }
+    }
+
+    offered.offer_count += 1;
+    offered.swap = Some(swap_pda);
+
+    let escrow_seeds = pda::escrow_seeds(&offered, offered.offer_count);
+
+    let (escrow_pda, escrow_bump) = assert_pda(a.escrow, program_id, &escrow_seeds.as_slices())?;
+    offered.escrow_bump = Some(escrow_bump);
+
+    msg!("Moving offered artwork to escrow...");
+    create_raw(
+        spl_token::id(),
+        a.escrow,
+        &rent,
+        a.system,
+        a.proposer_wallet,
+        TOKEN_ACCOUNT_SIZE,
+        &escrow_seeds.with_bump(escrow_bump).as_slices(),
+    )?;
+
+    initialise_token_account(a.escrow, a.offered_mint, escrow_pda, a.rent_sysvar)?;
+
+    transfer_token(
+        a.token_program,
+        a.proposer_offered_ata,
+        a.escrow,
+        a.proposer_wallet,
+        1,
+    )?;
+
+    offered.save(a.offered_meta)?;
+    ArtistMetadata::count_open_offer(a.offered_artist_meta, program_id, &offered.artist, true)?;
+
+    Ok(())
+}
+
+fn hold_top_up<'a, 'b: 'a>(
+    a: &Accounts<'a, 'b>,
+    top_up: &ArtworkSwapTopUp,
+    swap_pda: Pubkey,
+) -> ProgramResult {
+    if *a.proposer_payment.key != top_up.payment_account {
+        return Err(AllovrError::IncorrectPaymentAccount.into());
+    }
+
+    match top_up.currency {
+        // held as lamports of the swap account, closing it refunds the proposer wallet
+        Currency::SOL => {
+            if *a.proposer_payment.key != *a.proposer_wallet.key {
+                return Err(AllovrError::IncorrectPaymentAccount.into());
+            }
+            invoke(
+                &solana_program::system_instruction::transfer(
+                    a.proposer_wallet.key,
+                    a.swap.key,
+                    top_up.amount,
+                ),
+                &[a.proposer_wallet.clone(), a.swap.clone(), a.system.clone()],
+            )
+        }
+        Currency::AOVR => {
+            let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+            assert_ata_mint_and_owner(a.proposer_payment, aovr_mint, *a.proposer_wallet.key)?;
+
+            if a.swap_aovr_ata.data_is_empty() {
+                if *a.aovr_mint.key != aovr_mint {
+                    return Err(AllovrError::InvalidArtworkSwap.into());
+                }
+                create_ata(
+                    a.proposer_wallet,
+                    a.swap,
+                    a.swap_aovr_ata,
+                    a.aovr_mint,
+                    a.rent_sysvar,
+                    AllovrError::InvalidAssociatedTokenAccount,
+                )?;
+            } else {
+                assert_ata_mint_and_owner(a.swap_aovr_ata, aovr_mint, swap_pda)?;
+            }
+
+            transfer_token(
+                a.token_program,
+                a.proposer_payment,
+                a.swap_aovr_ata,
+                a.proposer_wallet,
+                top_up.amount,
+            )
+        }
+    }
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        proposer_wallet: next_account_info(account_iter)?,
+        proposer_offered_ata: next_account_info(account_iter)?,
+        offered_mint: next_account_info(account_iter)?,
+        offered_meta: next_account_info(account_iter)?,
+        requested_mint: next_account_info(account_iter)?,
+        requested_meta: next_account_info(account_iter)?,
+        escrow: next_account_info(account_iter)?,
+        swap: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        offered_artist_meta: next_account_info(account_iter)?,
+        proposer_payment: next_account_info(account_iter)?,
+        swap_aovr_ata: next_account_info(account_iter)?,
+        aovr_mint: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.proposer_wallet, &[Signer, Writable]),
+            (a.offered_meta, &[ProgramOwned, Writable]),
+            (a.requested_meta, &[ProgramOwned]),
+            (a.swap, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+            (a.offered_artist_meta, &[Writable]),
+            (a.associated_token_account_program, &[AtaProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,161 @@
+use crate::state::{ArtworkMetadata, ArtworkVault, ArtworkVaultState, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    redeemer_wallet: &'a AccountInfo<'b>,
+    redeemer_fraction_ata: &'a AccountInfo<'b>,
+    fraction_mint: &'a AccountInfo<'b>,
+    redeemer_artwork_ata: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    vault: &'a AccountInfo<'b>,
+    vault_artwork_ata: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+    let vault = ArtworkVault::load(a.vault, program_id)?;
+
+    if vault.state != ArtworkVaultState::Active {
+        return Err(AllovrError::ArtworkVaultNotActive.into());
+    }
+
+    if vault.artwork_mint != *a.artwork_mint.key
+        || vault.fraction_mint != *a.fraction_mint.key
+        || metadata.holder != *a.vault.key
+    {
+        return Err(AllovrError::InvalidArtworkVault.into());
+    }
+
+    msg!("Checking artwork and vault PDAs...");
+    let artwork_mint_pda = assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    let vault_pda = assert_pda_with_bump(
+        a.vault,
+        program_id,
+        &pda::artwork_vault_seeds(a.artwork_mint.key).as_slices(),
+        vault.bump,
+    )?;
+
+    assert_ata_mint_and_owner(a.vault_artwork_ata, artwork_mint_pda, vault_pda)?;
+
+    msg!("Checking redeemer holds every fraction...");
+    let fractions = get_token_account(a.redeemer_fraction_ata)?;
+    if fractions.mint != vault.fraction_mint || fractions.owner != *a.redeemer_wallet.key {
+        return Err(AllovrError::IncorrectTokenOwnerOrMint.into());
+    }
+
+    if fractions.amount != vault.fraction_supply {
+        return Err(AllovrError::InsufficientFractions.into());
+    }
+
+    burn_tokens(
+        a.token_program,
+        a.redeemer_fraction_ata,
+        a.fraction_mint,
+        a.redeemer_wallet,
+        fractions.amount,
+    )?;
+
+    if a.redeemer_artwork_ata.data_is_empty() {
+        create_ata(
+            a.redeemer_wallet,
+            a.redeemer_wallet,
+            a.redeemer_artwork_ata,
+            a.artwork_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.redeemer_artwork_ata,
+            artwork_mint_pda,
+            *a.redeemer_wallet.key,
+        )?;
+    }
+
+    msg!("Sending artwork to redeemer...");
+    transfer_token_signed(
+        a.token_program,
+        a.vault_artwork_ata,
+        a.redeemer_artwork_ata,
+        a.vault,
+        1,
+        &pda::artwork_vault_seeds(a.artwork_mint.key)
+            .with_bump(vault.bump)
+            .as_slices(),
+    )?;
+
+    msg!("Closing artwork vault...");
+    close_program_account(a.vault, a.redeemer_wallet)?;
+
+    metadata.set_holder(*a.redeemer_wallet.key);
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        redeemer_wallet: next_account_info(account_iter)?,
+        redeemer_fraction_ata: next_account_info(account_iter)?,
+        fraction_mint: next_account_info(account_iter)?,
+        redeemer_artwork_ata: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+        vault: next_account_info(account_iter)?,
+        vault_artwork_ata: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.redeemer_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.vault, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
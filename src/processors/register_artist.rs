@@ -1,12 +1,13 @@
 use crate::instruction::RegisterArtistArgs;
-use crate::state::ArtistMetadata;
+use crate::state::{ArtistMarket, ArtistMetadata, ArtistSymbol, ArtistVesting, ProgramAccount};
 use crate::{
-    error::AllovrError, utils::*, ALLOVR_MINT_ID, ARTIST_METADATA_SEED_PREFIX,
-    ARTIST_METADATA_SIZE, ARTIST_SEED_PREFIX, MINT_SIZE,
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID, ARTIST_MARKET_SIZE, ARTIST_METADATA_SIZE, ARTIST_SYMBOL_SIZE,
+    ARTIST_VESTING_SIZE, MINT_SIZE,
 };
 
-use borsh::BorshSerialize;
-use solana_program::borsh::try_from_slice_unchecked;
 use solana_program::program::invoke;
 use std::str::FromStr;
 
@@ -16,7 +17,7 @@ use solana_program::{
     msg,
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvar::{rent::Rent, Sysvar},
+    sysvar::{clock::Clock, rent::Rent, Sysvar},
 };
 
 #[derive(Debug)]
@@ -30,9 +31,18 @@ struct Accounts<'a, 'b: 'a> {
     artist_token_metaplex_meta: &'a AccountInfo<'b>,
     metaplex_meta_program_account: &'a AccountInfo<'b>,
     token_program: &'a AccountInfo<'b>,
-    _associated_token_account_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
     rent_sysvar: &'a AccountInfo<'b>,
     system: &'a AccountInfo<'b>,
+    artist_market: &'a AccountInfo<'b>,
+    artist_market_aovr_reserve: &'a AccountInfo<'b>,
+    aovr_mint: &'a AccountInfo<'b>,
+    artist_market_artist_token: &'a AccountInfo<'b>,
+    artist_vesting: &'a AccountInfo<'b>,
+    artist_vesting_artist_token: &'a AccountInfo<'b>,
+    artist_community: &'a AccountInfo<'b>,
+    artist_community_artist_token: &'a AccountInfo<'b>,
+    artist_symbol: &'a AccountInfo<'b>,
 }
 
 pub fn execute(
@@ -69,16 +79,51 @@ pub fn execute(
         ],
     )?;
 
+    // Reserve the symbol, the registry PDA only exists while an artist holds the ticker
+    let symbol_seed = normalise_artist_symbol(&artist_data.token_symbol);
+    let artist_symbol_seeds = pda::artist_symbol_seeds(&artist_data.token_symbol);
+    let (_artist_symbol_key, artist_symbol_bump) = assert_pda(
+        a.artist_symbol,
+        program_id,
+        &artist_symbol_seeds.as_slices(),
+    )?;
+
+    if !a.artist_symbol.data_is_empty() {
+        return Err(AllovrError::ArtistSymbolTaken.into());
+    }
+
+    msg!("Registering artist symbol {}", symbol_seed);
+    create_raw(
+        *program_id,
+        a.artist_symbol,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        ARTIST_SYMBOL_SIZE,
+        &artist_symbol_seeds
+            .with_bump(artist_symbol_bump)
+            .as_slices(),
+    )?;
+
+    let artist_symbol = ArtistSymbol {
+        artist: *a.artist_wallet.key,
+        artist_mint: *a.artist_token_mint.key,
+        bump: artist_symbol_bump,
+    };
+
+    artist_symbol.save(a.artist_symbol)?;
+
     // check the artist token mint PDA passed in is correct
+    let artist_token_mint_seeds = pda::artist_mint_seeds(a.artist_wallet.key);
     let (_artist_token_mint_pda, artist_token_mint_bump) = assert_pda(
         &a.artist_token_mint,
         program_id,
-        &[
-            ARTIST_SEED_PREFIX.as_bytes(),
-            &a.artist_wallet.key.to_bytes(),
-        ],
+        &artist_token_mint_seeds.as_slices(),
     )?;
 
+    let signers = artist_token_mint_seeds.with_bump(artist_token_mint_bump);
+    let signers_seeds = &signers.as_slices()[..];
+
     // create artist token mint account
     msg!("Creating artist token mint");
     create_pda_account(
@@ -87,23 +132,16 @@ pub fn execute(
         &a.artist_wallet,
         &a.artist_token_mint,
         spl_token::id(),
-        ARTIST_SEED_PREFIX,
-        artist_token_mint_bump,
+        signers_seeds,
     )?;
 
-    let signers_seeds = &[
-        ARTIST_SEED_PREFIX.as_bytes(),
-        &a.artist_wallet.key.to_bytes(),
-        &[artist_token_mint_bump],
-    ];
-
     // inti artist token mint account
     initalise_pda_mint_account(
         &a.artist_token_mint,
         &a.artist_wallet,
         &a.rent_sysvar,
         signers_seeds,
-        0,
+        artist_data.token_decimals,
     )?;
 
     msg!("Creating artist ATA");
@@ -117,20 +155,85 @@ pub fn execute(
         AllovrError::InvalidArtistTokenAccount,
     )?;
 
-    let mint_signer_seeds = &[
-        ARTIST_SEED_PREFIX.as_bytes(),
-        &a.artist_wallet.key.to_bytes(),
-        &[artist_token_mint_bump],
-    ];
+    let (artist_amount, community_amount, curve_amount) =
+        calculate_artist_token_allocation(artist_data.token_supply, &artist_data.allocation)?;
+
+    // Artist allocation is locked in the vesting PDA and released by ClaimVestedArtistTokens
+    let (_artist_vesting_key, artist_vesting_bump) = assert_pda(
+        a.artist_vesting,
+        program_id,
+        &pda::artist_vesting_seeds(a.artist_token_mint.key).as_slices(),
+    )?;
+
+    msg!("Creating artist vesting account");
+    create_raw(
+        *program_id,
+        a.artist_vesting,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        ARTIST_VESTING_SIZE,
+        &pda::artist_vesting_seeds(a.artist_token_mint.key)
+            .with_bump(artist_vesting_bump)
+            .as_slices(),
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let vesting = ArtistVesting {
+        artist: *a.artist_wallet.key,
+        artist_mint: *a.artist_token_mint.key,
+        total: artist_amount,
+        claimed: 0,
+        start_time: now,
+        cliff_time: now + artist_data.vesting.cliff_seconds,
+        end_time: now + artist_data.vesting.duration_seconds,
+        bump: artist_vesting_bump,
+    };
+
+    vesting.save(a.artist_vesting)?;
+
+    create_ata(
+        a.artist_wallet,
+        a.artist_vesting,
+        a.artist_vesting_artist_token,
+        a.artist_token_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidArtistTokenAccount,
+    )?;
+
+    msg!("minting {} vesting artist tokens", artist_amount);
+    mint_tokens_to(
+        a.artist_token_mint,
+        a.artist_token_mint,
+        a.artist_vesting_artist_token,
+        &[signers_seeds],
+        artist_amount,
+        false,
+    )?;
+
+    // Community allocation is held by the community treasury PDA
+    let (_artist_community_key, _artist_community_bump) = assert_pda(
+        a.artist_community,
+        program_id,
+        &pda::artist_community_seeds(a.artist_token_mint.key).as_slices(),
+    )?;
+
+    create_ata(
+        a.artist_wallet,
+        a.artist_community,
+        a.artist_community_artist_token,
+        a.artist_token_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidArtistTokenAccount,
+    )?;
 
-    // mint 10 000 000 artist tokens to artist
-    msg!("minting artist tokens");
+    msg!("minting {} community artist tokens", community_amount);
     mint_tokens_to(
-        a.artist_token_mint,   // mint account (PDA)
-        a.artist_token_mint,   // mint authority (same PDA)
-        a.artist_artist_token, // artist assoicated token account
-        &[mint_signer_seeds],
-        10000000,
+        a.artist_token_mint,
+        a.artist_token_mint,
+        a.artist_community_artist_token,
+        &[signers_seeds],
+        community_amount,
         false,
     )?;
 
@@ -138,16 +241,11 @@ pub fn execute(
     let (_metadata_key, metadata_bump_seed) = assert_pda(
         a.artist_token_meta,
         program_id,
-        &[
-            ARTIST_METADATA_SEED_PREFIX.as_bytes(),
-            a.artist_token_mint.key.as_ref(),
-        ],
+        &pda::artist_metadata_seeds(a.artist_token_mint.key).as_slices(),
     )?;
-    let metadata_authority_signer_seeds = &[
-        ARTIST_METADATA_SEED_PREFIX.as_bytes(),
-        a.artist_token_mint.key.as_ref(),
-        &[metadata_bump_seed],
-    ];
+    let metadata_authority_signer =
+        pda::artist_metadata_seeds(a.artist_token_mint.key).with_bump(metadata_bump_seed);
+    let metadata_authority_signer_seeds = &metadata_authority_signer.as_slices()[..];
 
     create_raw(
         *program_id,
@@ -161,15 +259,89 @@ pub fn execute(
 
     msg!("Saving metadata");
     // Save meta data
-    let mut metadata: ArtistMetadata =
-        try_from_slice_unchecked(&a.artist_token_meta.data.borrow_mut())?;
+    let metadata = ArtistMetadata {
+        name: String::from(&artist_data.name),
+        description: String::from(&artist_data.description),
+        symbol: String::from(&artist_data.token_symbol),
+        uri: artist_data.uri.clone(),
+        verified: false,
+        bump: metadata_bump_seed,
+        mint_bump: artist_token_mint_bump,
+        open_offers: 0,
+    };
 
-    metadata.name = String::from(&artist_data.name);
-    metadata.description = String::from(&artist_data.description);
-    metadata.symbol = String::from(&artist_data.token_symbol);
-    metadata.uri = artist_data.uri.clone();
+    metadata.save(a.artist_token_meta)?;
+
+    // Create artist market, fans buy and sell artist tokens on the bonding curve against
+    // an AOVR reserve held by the market PDA
+    let (artist_market_key, artist_market_bump) = assert_pda(
+        a.artist_market,
+        program_id,
+        &pda::artist_market_seeds(a.artist_token_mint.key).as_slices(),
+    )?;
+
+    msg!("Creating artist market");
+    create_raw(
+        *program_id,
+        a.artist_market,
+        &rent,
+        a.system,
+        a.artist_wallet,
+        ARTIST_MARKET_SIZE,
+        &pda::artist_market_seeds(a.artist_token_mint.key)
+            .with_bump(artist_market_bump)
+            .as_slices(),
+    )?;
+
+    let market = ArtistMarket {
+        artist: *a.artist_wallet.key,
+        artist_mint: *a.artist_token_mint.key,
+        curve: artist_data.bonding_curve.clone(),
+        supply: 0,
+        active: true,
+        bump: artist_market_bump,
+        mint_bump: artist_token_mint_bump,
+    };
 
-    metadata.serialize(&mut &mut a.artist_token_meta.data.borrow_mut()[..])?;
+    market.save(a.artist_market)?;
+
+    if a.artist_market_aovr_reserve.data_is_empty() {
+        create_ata(
+            a.artist_wallet,
+            a.artist_market,
+            a.artist_market_aovr_reserve,
+            a.aovr_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidAssociatedTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.artist_market_aovr_reserve,
+            *a.aovr_mint.key,
+            artist_market_key,
+        )?;
+    }
+
+    // Bonding curve allocation is the market's inventory, the curve sells from and buys back
+    // into it so total supply never changes
+    create_ata(
+        a.artist_wallet,
+        a.artist_market,
+        a.artist_market_artist_token,
+        a.artist_token_mint,
+        a.rent_sysvar,
+        AllovrError::InvalidArtistTokenAccount,
+    )?;
+
+    msg!("minting {} bonding curve artist tokens", curve_amount);
+    mint_tokens_to(
+        a.artist_token_mint,
+        a.artist_token_mint,
+        a.artist_market_artist_token,
+        &[signers_seeds],
+        curve_amount,
+        false,
+    )?;
 
     let mut uri = String::new();
     if !artist_data.uri.is_none() {
@@ -189,6 +361,9 @@ pub fn execute(
             String::from(&metadata.name),
             String::from(&metadata.symbol),
             uri,
+            None,
+            0,
+            None,
             signers_seeds,
         )?;
     }
@@ -211,27 +386,43 @@ fn parse_accounts<'a, 'b: 'a>(
         artist_token_metaplex_meta: next_account_info(account_iter)?,
         metaplex_meta_program_account: next_account_info(account_iter)?,
         token_program: next_account_info(account_iter)?,
-        _associated_token_account_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
         rent_sysvar: next_account_info(account_iter)?,
         system: next_account_info(account_iter)?,
+        artist_market: next_account_info(account_iter)?,
+        artist_market_aovr_reserve: next_account_info(account_iter)?,
+        aovr_mint: next_account_info(account_iter)?,
+        artist_market_artist_token: next_account_info(account_iter)?,
+        artist_vesting: next_account_info(account_iter)?,
+        artist_vesting_artist_token: next_account_info(account_iter)?,
+        artist_community: next_account_info(account_iter)?,
+        artist_community_artist_token: next_account_info(account_iter)?,
+        artist_symbol: next_account_info(account_iter)?,
     };
 
-    assert_system(accounts.system)?;
-    assert_token_program_matches_package(accounts.token_program)?;
-    assert_program_id(program_id)?;
-    assert_signer(accounts.artist_wallet)?;
-    assert_aovr_treasury(accounts.treasury_aovr_token.key)?;
-    assert_metaplex_program(accounts.metaplex_meta_program_account)?;
-
-    let mint_key = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
-
-    assert_ata_mint_and_owner(
-        accounts.artist_aovr_token,
-        mint_key,
-        *accounts.artist_wallet.key,
+    validate_accounts(
+        program_id,
+        &[
+            (accounts.artist_wallet, &[Signer, Writable]),
+            (
+                accounts.artist_aovr_token,
+                &[Ata {
+                    mint: &Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                    owner: accounts.artist_wallet.key,
+                }],
+            ),
+            (accounts.treasury_aovr_token, &[AllovrTreasury]),
+            (accounts.artist_token_meta, &[Writable]),
+            (accounts.artist_market, &[Writable]),
+            (accounts.artist_vesting, &[Writable]),
+            (accounts.artist_symbol, &[Writable]),
+            (accounts.aovr_mint, &[AllovrMint]),
+            (accounts.metaplex_meta_program_account, &[MetaplexProgram]),
+            (accounts.token_program, &[TokenProgram]),
+            (accounts.associated_token_account_program, &[AtaProgram]),
+            (accounts.system, &[SystemProgram]),
+        ],
     )?;
 
-    assert_ata_program_matches_package(accounts._associated_token_account_program)?;
-
     Ok(accounts)
 }
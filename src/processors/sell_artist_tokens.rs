@@ -0,0 +1,135 @@
+use crate::instruction::SellArtistTokensArgs;
+use crate::state::{ArtistMarket, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ALLOVR_MINT_ID,
+};
+use solana_program::msg;
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    seller_wallet: &'a AccountInfo<'b>,
+    seller_aovr_ata: &'a AccountInfo<'b>,
+    seller_artist_ata: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_market: &'a AccountInfo<'b>,
+    artist_market_aovr_reserve: &'a AccountInfo<'b>,
+    artist_market_artist_token: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: SellArtistTokensArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut market = ArtistMarket::load(a.artist_market, program_id)?;
+
+    if market.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidBondingCurve.into());
+    }
+
+    msg!("Checking artist mint and market PDAs...");
+    let artist_token_mint_pda = assert_pda_with_bump(
+        a.artist_token_mint,
+        program_id,
+        &pda::artist_mint_seeds(&market.artist).as_slices(),
+        market.mint_bump,
+    )?;
+
+    let artist_market_pda = assert_pda_with_bump(
+        a.artist_market,
+        program_id,
+        &pda::artist_market_seeds(a.artist_token_mint.key).as_slices(),
+        market.bump,
+    )?;
+
+    let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+    assert_ata_mint_and_owner(a.seller_aovr_ata, aovr_mint, *a.seller_wallet.key)?;
+    assert_ata_mint_and_owner(a.artist_market_aovr_reserve, aovr_mint, artist_market_pda)?;
+    assert_ata_mint_and_owner(
+        a.seller_artist_ata,
+        artist_token_mint_pda,
+        *a.seller_wallet.key,
+    )?;
+    assert_ata_mint_and_owner(
+        a.artist_market_artist_token,
+        artist_token_mint_pda,
+        artist_market_pda,
+    )?;
+
+    // only tokens the curve sold can be sold back to it
+    let remaining_supply = market
+        .supply
+        .checked_sub(args.amount)
+        .ok_or(AllovrError::InvalidBondingCurve)?;
+
+    let refund = calculate_bonding_curve_cost(&market.curve, remaining_supply, args.amount, false)?;
+    if refund < args.min_return {
+        return Err(AllovrError::PriceBelowMinPrice.into());
+    }
+
+    msg!("Returning {} artist tokens to the market...", args.amount);
+    transfer_token(
+        a.token_program,
+        a.seller_artist_ata,
+        a.artist_market_artist_token,
+        a.seller_wallet,
+        args.amount,
+    )?;
+
+    msg!("Paying {} AOVR from the market reserve...", refund);
+    transfer_token_signed(
+        a.token_program,
+        a.artist_market_aovr_reserve,
+        a.seller_aovr_ata,
+        a.artist_market,
+        refund,
+        &pda::artist_market_seeds(a.artist_token_mint.key)
+            .with_bump(market.bump)
+            .as_slices(),
+    )?;
+
+    market.supply = remaining_supply;
+    market.save(a.artist_market)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        seller_wallet: next_account_info(account_iter)?,
+        seller_aovr_ata: next_account_info(account_iter)?,
+        seller_artist_ata: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_market: next_account_info(account_iter)?,
+        artist_market_aovr_reserve: next_account_info(account_iter)?,
+        artist_market_artist_token: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.seller_wallet, &[Signer]),
+            (a.artist_market, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
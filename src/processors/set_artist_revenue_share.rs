@@ -0,0 +1,169 @@
+use crate::instruction::SetArtistRevenueShareArgs;
+use crate::state::{ArtistRevenueVault, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_MAX_REVENUE_SHARE_BASIS_POINTS, ARTIST_REVENUE_VAULT_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    revenue_vault: &'a AccountInfo<'b>,
+    revenue_vault_artist_ata: &'a AccountInfo<'b>,
+    revenue_vault_aovr_ata: &'a AccountInfo<'b>,
+    aovr_mint: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: SetArtistRevenueShareArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    if args.share_basis_points > ARTIST_MAX_REVENUE_SHARE_BASIS_POINTS {
+        return Err(AllovrError::InvalidRevenueShare.into());
+    }
+
+    msg!("Checking artist token mint and revenue vault PDAs...");
+    let revenue_vault_seeds = pda::artist_revenue_vault_seeds(a.artist_token_mint.key);
+    let mut vault = if a.revenue_vault.data_is_empty() {
+        assert_pda(
+            a.artist_token_mint,
+            program_id,
+            &pda::artist_mint_seeds(a.artist_wallet.key).as_slices(),
+        )?;
+        let (_revenue_vault_pda, revenue_vault_bump) = assert_pda(
+            a.revenue_vault,
+            program_id,
+            &revenue_vault_seeds.as_slices(),
+        )?;
+
+        msg!("Creating artist revenue vault...");
+        create_raw(
+            *program_id,
+            a.revenue_vault,
+            &Rent::get()?,
+            a.system,
+            a.artist_wallet,
+            ARTIST_REVENUE_VAULT_SIZE,
+            &revenue_vault_seeds
+                .with_bump(revenue_vault_bump)
+                .as_slices(),
+        )?;
+
+        ArtistRevenueVault {
+            artist: *a.artist_wallet.key,
+            artist_mint: *a.artist_token_mint.key,
+            share_basis_points: 0,
+            total_staked: 0,
+            sol_reward_per_token: 0,
+            aovr_reward_per_token: 0,
+            bump: revenue_vault_bump,
+        }
+    } else {
+        // the vault was opened for this artist and mint, its stored bump proves both
+        let vault = ArtistRevenueVault::load(a.revenue_vault, program_id)?;
+        if vault.artist != *a.artist_wallet.key {
+            return Err(AllovrError::InvalidRevenueShare.into());
+        }
+        assert_pda_with_bump(
+            a.revenue_vault,
+            program_id,
+            &revenue_vault_seeds.as_slices(),
+            vault.bump,
+        )?;
+        vault
+    };
+
+    if a.revenue_vault_artist_ata.data_is_empty() {
+        create_ata(
+            a.artist_wallet,
+            a.revenue_vault,
+            a.revenue_vault_artist_ata,
+            a.artist_token_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtistTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.revenue_vault_artist_ata,
+            *a.artist_token_mint.key,
+            *a.revenue_vault.key,
+        )?;
+    }
+
+    if a.revenue_vault_aovr_ata.data_is_empty() {
+        create_ata(
+            a.artist_wallet,
+            a.revenue_vault,
+            a.revenue_vault_aovr_ata,
+            a.aovr_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidAssociatedTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(
+            a.revenue_vault_aovr_ata,
+            *a.aovr_mint.key,
+            *a.revenue_vault.key,
+        )?;
+    }
+
+    msg!(
+        "Setting revenue share to {} basis points...",
+        args.share_basis_points
+    );
+    vault.share_basis_points = args.share_basis_points;
+    vault.save(a.revenue_vault)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        revenue_vault: next_account_info(account_iter)?,
+        revenue_vault_artist_ata: next_account_info(account_iter)?,
+        revenue_vault_aovr_ata: next_account_info(account_iter)?,
+        aovr_mint: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artist_wallet, &[Signer, Writable]),
+            (a.revenue_vault, &[Writable]),
+            (a.aovr_mint, &[AllovrMint]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
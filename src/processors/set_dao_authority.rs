@@ -0,0 +1,69 @@
+use crate::instruction::SetDaoAuthorityArgs;
+use crate::state::{AllovrTokenState, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    authority: &'a AccountInfo<'b>,
+    state: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: SetDaoAuthorityArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut state = AllovrTokenState::load(a.state, program_id)?;
+
+    // the program keypair sets the first authority of state migrated without one, from then on
+    // only the current DAO authority can hand over to its successor
+    if state.dao_authority == Pubkey::default() {
+        if a.authority.key != program_id {
+            return Err(AllovrError::InvalidDaoAuthority.into());
+        }
+    } else {
+        assert_dao_authority(a.authority, &state.dao_authority)?;
+    }
+
+    if args.dao_authority == Pubkey::default() {
+        return Err(AllovrError::InvalidDaoAuthority.into());
+    }
+
+    msg!("Setting DAO authority to {}...", args.dao_authority);
+    state.dao_authority = args.dao_authority;
+    state.save(a.state)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        authority: next_account_info(account_iter)?,
+        state: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.authority, &[Signer]),
+            (a.state, &[AllovrState, Writable]),
+        ],
+    )?;
+
+    Ok(a)
+}
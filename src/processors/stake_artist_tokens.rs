@@ -0,0 +1,151 @@
+use crate::instruction::ArtistStakeArgs;
+use crate::state::{ArtistRevenueStake, ArtistRevenueVault, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_REVENUE_STAKE_SIZE,
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_artist_ata: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    revenue_vault: &'a AccountInfo<'b>,
+    revenue_vault_artist_ata: &'a AccountInfo<'b>,
+    stake: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: ArtistStakeArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut vault = ArtistRevenueVault::load(a.revenue_vault, program_id)?;
+
+    if args.amount == 0 {
+        return Err(AllovrError::InsufficientStake.into());
+    }
+
+    if vault.artist_mint != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidRevenueShare.into());
+    }
+
+    msg!("Checking revenue vault and stake PDAs...");
+    let revenue_vault_pda = assert_pda_with_bump(
+        a.revenue_vault,
+        program_id,
+        &pda::artist_revenue_vault_seeds(a.artist_token_mint.key).as_slices(),
+        vault.bump,
+    )?;
+
+    let stake_seeds = pda::artist_stake_seeds(a.artist_token_mint.key, a.holder_wallet.key);
+
+    assert_ata_mint_and_owner(
+        a.holder_artist_ata,
+        *a.artist_token_mint.key,
+        *a.holder_wallet.key,
+    )?;
+    assert_ata_mint_and_owner(
+        a.revenue_vault_artist_ata,
+        *a.artist_token_mint.key,
+        revenue_vault_pda,
+    )?;
+
+    let mut stake = if a.stake.data_is_empty() {
+        let (_stake_pda, stake_bump) = assert_pda(a.stake, program_id, &stake_seeds.as_slices())?;
+
+        msg!("Creating artist stake account...");
+        create_raw(
+            *program_id,
+            a.stake,
+            &Rent::get()?,
+            a.system,
+            a.holder_wallet,
+            ARTIST_REVENUE_STAKE_SIZE,
+            &stake_seeds.with_bump(stake_bump).as_slices(),
+        )?;
+
+        ArtistRevenueStake {
+            holder: *a.holder_wallet.key,
+            artist_mint: *a.artist_token_mint.key,
+            amount: 0,
+            sol_reward_per_token_paid: vault.sol_reward_per_token,
+            aovr_reward_per_token_paid: vault.aovr_reward_per_token,
+            sol_owed: 0,
+            aovr_owed: 0,
+            bump: stake_bump,
+        }
+    } else {
+        let stake = ArtistRevenueStake::load(a.stake, program_id)?;
+        assert_pda_with_bump(a.stake, program_id, &stake_seeds.as_slices(), stake.bump)?;
+        stake
+    };
+
+    accrue_artist_revenue(&mut stake, &vault)?;
+
+    msg!("Staking {} artist tokens...", args.amount);
+    transfer_token(
+        a.token_program,
+        a.holder_artist_ata,
+        a.revenue_vault_artist_ata,
+        a.holder_wallet,
+        args.amount,
+    )?;
+
+    stake.amount = stake
+        .amount
+        .checked_add(args.amount)
+        .ok_or(AllovrError::InsufficientStake)?;
+    vault.total_staked = vault
+        .total_staked
+        .checked_add(args.amount)
+        .ok_or(AllovrError::InsufficientStake)?;
+
+    stake.save(a.stake)?;
+    vault.save(a.revenue_vault)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_artist_ata: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        revenue_vault: next_account_info(account_iter)?,
+        revenue_vault_artist_ata: next_account_info(account_iter)?,
+        stake: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.holder_wallet, &[Signer, Writable]),
+            (a.revenue_vault, &[ProgramOwned, Writable]),
+            (a.stake, &[Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -0,0 +1,86 @@
+use crate::state::{ArtworkMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_artwork_token: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+}
+
+/// Anyone can call this: the artwork token itself is the source of truth, so pointing
+/// `holder` at the wallet that actually owns the single token is always safe.
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+
+    if metadata.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    msg!("Checking artwork mint and metadata PDAs...");
+    let artwork_mint_pda = assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    msg!("Checking holder token account holds the artwork...");
+    let token_account = get_token_account(a.holder_artwork_token)?;
+    if token_account.mint != artwork_mint_pda || token_account.owner != *a.holder_wallet.key {
+        return Err(AllovrError::IncorrectTokenOwnerOrMint.into());
+    }
+
+    if token_account.amount != 1 {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if metadata.holder == *a.holder_wallet.key {
+        msg!("Artwork holder already up to date");
+        return Ok(());
+    }
+
+    metadata.set_holder(*a.holder_wallet.key);
+
+    msg!("Setting artwork metadata...");
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_artwork_token: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(program_id, &[(a.artwork_meta, &[ProgramOwned, Writable])])?;
+
+    Ok(a)
+}
@@ -0,0 +1,124 @@
+use crate::state::{ArtworkMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artwork_holder_wallet: &'a AccountInfo<'b>,
+    artwork_holder_ata: &'a AccountInfo<'b>,
+    recipient_wallet: &'a AccountInfo<'b>,
+    recipient_ata: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    associated_token_account_program: &'a AccountInfo<'b>,
+    rent_sysvar: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+}
+
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+
+    if *a.artwork_holder_wallet.key != metadata.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    // an artwork under offer sits in escrow and can only leave it through cancel or accept
+    if metadata.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    msg!("Checking artwork mint and metadata PDAs...");
+    let artwork_mint_pda = assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    assert_ata_mint_and_owner(
+        a.artwork_holder_ata,
+        artwork_mint_pda,
+        *a.artwork_holder_wallet.key,
+    )?;
+
+    if a.recipient_ata.data_is_empty() {
+        msg!("Creating recipient artwork ATA...");
+        create_ata(
+            a.artwork_holder_wallet,
+            a.recipient_wallet,
+            a.recipient_ata,
+            a.artwork_mint,
+            a.rent_sysvar,
+            AllovrError::InvalidArtworkTokenAccount,
+        )?;
+    } else {
+        assert_ata_mint_and_owner(a.recipient_ata, artwork_mint_pda, *a.recipient_wallet.key)?;
+    }
+
+    msg!("Sending artwork to recipient...");
+    transfer_token(
+        a.token_program,
+        a.artwork_holder_ata,
+        a.recipient_ata,
+        a.artwork_holder_wallet,
+        1,
+    )?;
+
+    metadata.set_holder(*a.recipient_wallet.key);
+
+    msg!("Setting artwork metadata...");
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        artwork_holder_wallet: next_account_info(account_iter)?,
+        artwork_holder_ata: next_account_info(account_iter)?,
+        recipient_wallet: next_account_info(account_iter)?,
+        recipient_ata: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+        associated_token_account_program: next_account_info(account_iter)?,
+        rent_sysvar: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.artwork_holder_wallet, &[Signer, Writable]),
+            (a.artwork_meta, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+            (a.associated_token_account_program, &[AtaProgram]),
+            (a.system, &[SystemProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
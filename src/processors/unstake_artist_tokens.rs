@@ -0,0 +1,122 @@
+use crate::instruction::ArtistStakeArgs;
+use crate::state::{ArtistRevenueStake, ArtistRevenueVault, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    holder_wallet: &'a AccountInfo<'b>,
+    holder_artist_ata: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    revenue_vault: &'a AccountInfo<'b>,
+    revenue_vault_artist_ata: &'a AccountInfo<'b>,
+    stake: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: ArtistStakeArgs,
+) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut vault = ArtistRevenueVault::load(a.revenue_vault, program_id)?;
+    let mut stake = ArtistRevenueStake::load(a.stake, program_id)?;
+
+    msg!("Checking revenue vault and stake PDAs...");
+    assert_pda_with_bump(
+        a.revenue_vault,
+        program_id,
+        &pda::artist_revenue_vault_seeds(a.artist_token_mint.key).as_slices(),
+        vault.bump,
+    )?;
+    assert_pda_with_bump(
+        a.stake,
+        program_id,
+        &pda::artist_stake_seeds(a.artist_token_mint.key, a.holder_wallet.key).as_slices(),
+        stake.bump,
+    )?;
+
+    if stake.holder != *a.holder_wallet.key
+        || stake.artist_mint != *a.artist_token_mint.key
+        || vault.artist_mint != *a.artist_token_mint.key
+    {
+        return Err(AllovrError::InvalidRevenueShare.into());
+    }
+
+    if args.amount == 0 || args.amount > stake.amount {
+        return Err(AllovrError::InsufficientStake.into());
+    }
+
+    assert_ata_mint_and_owner(
+        a.holder_artist_ata,
+        *a.artist_token_mint.key,
+        *a.holder_wallet.key,
+    )?;
+    assert_ata_mint_and_owner(
+        a.revenue_vault_artist_ata,
+        *a.artist_token_mint.key,
+        *a.revenue_vault.key,
+    )?;
+
+    accrue_artist_revenue(&mut stake, &vault)?;
+
+    msg!("Unstaking {} artist tokens...", args.amount);
+    transfer_token_signed(
+        a.token_program,
+        a.revenue_vault_artist_ata,
+        a.holder_artist_ata,
+        a.revenue_vault,
+        args.amount,
+        &pda::artist_revenue_vault_seeds(a.artist_token_mint.key)
+            .with_bump(vault.bump)
+            .as_slices(),
+    )?;
+
+    stake.amount -= args.amount;
+    vault.total_staked -= args.amount;
+
+    stake.save(a.stake)?;
+    vault.save(a.revenue_vault)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        holder_wallet: next_account_info(account_iter)?,
+        holder_artist_ata: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        revenue_vault: next_account_info(account_iter)?,
+        revenue_vault_artist_ata: next_account_info(account_iter)?,
+        stake: next_account_info(account_iter)?,
+        token_program: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.holder_wallet, &[Signer]),
+            (a.revenue_vault, &[ProgramOwned, Writable]),
+            (a.stake, &[ProgramOwned, Writable]),
+            (a.token_program, &[TokenProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
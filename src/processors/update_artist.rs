@@ -1,15 +1,19 @@
-use crate::instruction::RegisterArtistArgs;
-use crate::state::ArtistMetadata;
-use crate::{utils::*, ARTIST_METADATA_SEED_PREFIX, ARTIST_SEED_PREFIX};
-
-use borsh::BorshSerialize;
-use solana_program::borsh::try_from_slice_unchecked;
+use crate::instruction::UpdateArtistArgs;
+use crate::state::{ArtistMetadata, ArtistProfile, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+    ARTIST_PROFILE_SIZE,
+};
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::{rent::Rent, Sysvar},
 };
 
 struct Accounts<'a, 'b: 'a> {
@@ -18,46 +22,40 @@ struct Accounts<'a, 'b: 'a> {
     artist_token_meta: &'a AccountInfo<'b>,
     artist_token_metaplex_meta: &'a AccountInfo<'b>,
     metaplex_meta_program_account: &'a AccountInfo<'b>,
-    _system: &'a AccountInfo<'b>,
+    system: &'a AccountInfo<'b>,
+    artist_profile: Option<&'a AccountInfo<'b>>,
 }
 
 pub fn execute(
     accounts: &[AccountInfo],
     program_id: &Pubkey,
-    args: RegisterArtistArgs,
+    args: UpdateArtistArgs,
 ) -> ProgramResult {
-    let artist_data = santitise_artist_data(args)?;
+    let artist_data = santitise_update_artist_data(args)?;
     let a = parse_accounts(program_id, accounts)?;
 
-    // derive artist token mint for signer account
-    let (artist_token_mint_key, _) = Pubkey::find_program_address(
-        &[
-            ARTIST_SEED_PREFIX.as_bytes(),
-            &a.artist_wallet.key.to_bytes(),
-        ],
-        &program_id,
-    );
-
     // Check artist Metadata account passed in matches signer artist token
-    let (_metadata_key, _) = assert_pda(
-        a.artist_token_meta,
-        program_id,
-        &[
-            ARTIST_METADATA_SEED_PREFIX.as_bytes(),
-            artist_token_mint_key.as_ref(),
-        ],
-    )?;
+    let (mut metadata, artist_token_mint_pda) =
+        ArtistMetadata::load_for_artist(a.artist_token_meta, program_id, a.artist_wallet.key)?;
 
-    // Save meta data
-    let mut metadata: ArtistMetadata =
-        try_from_slice_unchecked(&a.artist_token_meta.data.borrow_mut())?;
+    // a verified name is what the DAO checked, it can only change after revocation
+    if metadata.verified && metadata.name != artist_data.name {
+        return Err(AllovrError::VerifiedArtistNameLocked.into());
+    }
+
+    // the symbol registry entry is keyed by the ticker, changing case is all that is allowed
+    if normalise_artist_symbol(&metadata.symbol)
+        != normalise_artist_symbol(&artist_data.token_symbol)
+    {
+        return Err(AllovrError::ArtistSymbolLocked.into());
+    }
 
     metadata.name = artist_data.name;
     metadata.description = artist_data.description;
     metadata.symbol = artist_data.token_symbol;
     metadata.uri = artist_data.uri.clone();
 
-    metadata.serialize(&mut &mut a.artist_token_meta.data.borrow_mut()[..])?;
+    metadata.save(a.artist_token_meta)?;
 
     let mut uri = String::new();
     if !artist_data.uri.is_none() {
@@ -65,20 +63,60 @@ pub fn execute(
     }
 
     // check the artist token mint PDA passed in is correct
-    let (_artist_token_mint_pda, artist_token_mint_bump) = assert_pda(
-        &a.artist_token_mint,
-        program_id,
-        &[
-            ARTIST_SEED_PREFIX.as_bytes(),
-            &a.artist_wallet.key.to_bytes(),
-        ],
-    )?;
+    if artist_token_mint_pda != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidPda.into());
+    }
 
-    let signers_seeds = &[
-        ARTIST_SEED_PREFIX.as_bytes(),
-        &a.artist_wallet.key.to_bytes(),
-        &[artist_token_mint_bump],
-    ];
+    let signers = pda::artist_mint_seeds(a.artist_wallet.key).with_bump(metadata.mint_bump);
+    let signers_seeds = &signers.as_slices()[..];
+
+    if let Some(profile_args) = artist_data.profile {
+        let artist_profile = a.artist_profile.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let artist_profile_seeds = pda::artist_profile_seeds(a.artist_token_mint.key);
+
+        let artist_profile_bump = if artist_profile.data_is_empty() {
+            let (_artist_profile_key, artist_profile_bump) = assert_pda(
+                artist_profile,
+                program_id,
+                &artist_profile_seeds.as_slices(),
+            )?;
+
+            msg!("Creating artist profile");
+            create_raw(
+                *program_id,
+                artist_profile,
+                &Rent::get()?,
+                a.system,
+                a.artist_wallet,
+                ARTIST_PROFILE_SIZE,
+                &artist_profile_seeds
+                    .with_bump(artist_profile_bump)
+                    .as_slices(),
+            )?;
+            artist_profile_bump
+        } else {
+            let profile = ArtistProfile::load(artist_profile, program_id)?;
+            assert_pda_with_bump(
+                artist_profile,
+                program_id,
+                &artist_profile_seeds.as_slices(),
+                profile.bump,
+            )?;
+            profile.bump
+        };
+
+        let profile = ArtistProfile {
+            artist: *a.artist_wallet.key,
+            links: profile_args.links,
+            avatar_uri: profile_args.avatar_uri,
+            banner_uri: profile_args.banner_uri,
+            genres: profile_args.genres,
+            location: profile_args.location,
+            bump: artist_profile_bump,
+        };
+
+        profile.save(artist_profile)?;
+    }
 
     if artist_data.create_metaplex_metadata.is_none()
         || artist_data.create_metaplex_metadata.unwrap() == true
@@ -91,6 +129,7 @@ pub fn execute(
             String::from(&metadata.name),
             String::from(&metadata.symbol),
             uri,
+            metaplex_metadata_creators(a.artist_token_metaplex_meta)?,
             signers_seeds,
         )?;
     }
@@ -109,11 +148,19 @@ fn parse_accounts<'a, 'b: 'a>(
         artist_token_meta: next_account_info(account_iter)?,
         artist_token_metaplex_meta: next_account_info(account_iter)?,
         metaplex_meta_program_account: next_account_info(account_iter)?,
-        _system: next_account_info(account_iter)?,
+        system: next_account_info(account_iter)?,
+        // only needed when the profile is updated
+        artist_profile: account_iter.next(),
     };
-    assert_program_id(program_id)?;
-    assert_signer(accounts.artist_wallet)?;
-    assert_metaplex_program(accounts.metaplex_meta_program_account)?;
+    validate_accounts(
+        program_id,
+        &[
+            (accounts.artist_wallet, &[Signer]),
+            (accounts.artist_token_meta, &[ProgramOwned, Writable]),
+            (accounts.metaplex_meta_program_account, &[MetaplexProgram]),
+            (accounts.system, &[SystemProgram]),
+        ],
+    )?;
 
     Ok(accounts)
 }
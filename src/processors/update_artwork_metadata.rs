@@ -0,0 +1,84 @@
+use crate::instruction::UpdateArtworkMetadataArgs;
+use crate::state::{ArtworkMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    artist_wallet: &'a AccountInfo<'b>,
+    artwork_mint: &'a AccountInfo<'b>,
+    artwork_meta: &'a AccountInfo<'b>,
+}
+
+pub fn execute(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    args: UpdateArtworkMetadataArgs,
+) -> ProgramResult {
+    let artwork_data = santitise_artwork_update_data(args)?;
+    let a = parse_accounts(program_id, accounts)?;
+
+    let mut metadata = ArtworkMetadata::load(a.artwork_meta, program_id)?;
+
+    // once the work has left the artist, the buyer's provenance must not change under them
+    if *a.artist_wallet.key != metadata.artist || *a.artist_wallet.key != metadata.holder {
+        return Err(AllovrError::IncorrectArtworkHolder.into());
+    }
+
+    if metadata.is_under_offer() {
+        return Err(AllovrError::ArtworkUnderOffer.into());
+    }
+
+    assert_pda_with_bump(
+        a.artwork_mint,
+        program_id,
+        &pda::artwork_metadata_mint_seeds(&metadata).as_slices(),
+        metadata.mint_bump,
+    )?;
+
+    assert_pda_with_bump(
+        a.artwork_meta,
+        program_id,
+        &pda::artwork_metadata_seeds(a.artwork_mint.key).as_slices(),
+        metadata.bump,
+    )?;
+
+    metadata.description = artwork_data.description;
+    metadata.content_uri = artwork_data.content_uri;
+    metadata.content_hash = artwork_data.content_hash;
+
+    metadata.save(a.artwork_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let accounts = Accounts {
+        artist_wallet: next_account_info(account_iter)?,
+        artwork_mint: next_account_info(account_iter)?,
+        artwork_meta: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(
+        program_id,
+        &[
+            (accounts.artist_wallet, &[Signer]),
+            (accounts.artwork_meta, &[ProgramOwned, Writable]),
+        ],
+    )?;
+
+    Ok(accounts)
+}
@@ -0,0 +1,145 @@
+use crate::state::{AllovrTokenState, ArtistMetadata, ProgramAccount};
+use crate::{
+    error::AllovrError,
+    pda,
+    utils::{AccountConstraint::*, *},
+};
+use solana_program::msg;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+struct Accounts<'a, 'b: 'a> {
+    dao_authority: &'a AccountInfo<'b>,
+    state: &'a AccountInfo<'b>,
+    artist_wallet: &'a AccountInfo<'b>,
+    artist_token_mint: &'a AccountInfo<'b>,
+    artist_token_meta: &'a AccountInfo<'b>,
+    artist_token_metaplex_meta: &'a AccountInfo<'b>,
+    metaplex_meta_program_account: &'a AccountInfo<'b>,
+}
+
+/// Handles both VerifyArtist and RevokeArtistVerification, verified is the new state
+pub fn execute(accounts: &[AccountInfo], program_id: &Pubkey, verified: bool) -> ProgramResult {
+    let a = parse_accounts(program_id, accounts)?;
+
+    msg!("Checking artist PDAs...");
+    let (mut metadata, artist_token_mint_pda) =
+        ArtistMetadata::load_for_artist(a.artist_token_meta, program_id, a.artist_wallet.key)?;
+    if artist_token_mint_pda != *a.artist_token_mint.key {
+        return Err(AllovrError::InvalidPda.into());
+    }
+
+    let (metaplex_meta_key, _) =
+        mpl_token_metadata::pda::find_metadata_account(a.artist_token_mint.key);
+    if *a.artist_token_metaplex_meta.key != metaplex_meta_key {
+        return Err(AllovrError::InvalidPda.into());
+    }
+
+    if metadata.verified == verified {
+        msg!("Artist verification unchanged");
+        return Ok(());
+    }
+
+    if !a.artist_token_metaplex_meta.data_is_empty() {
+        let artist_token_mint_signer =
+            pda::artist_mint_seeds(a.artist_wallet.key).with_bump(metadata.mint_bump);
+        let artist_token_mint_signer_seeds = &artist_token_mint_signer.as_slices()[..];
+
+        // metaplex only lets a creator verify or unverify itself, so the DAO signs directly.
+        // An update can add the DAO unverified, but only drop it once unverified.
+        if verified {
+            msg!("Adding DAO as verified creator...");
+            update_metaplex_metadata_account(
+                a.artist_token_metaplex_meta,
+                a.artist_token_mint,
+                a.metaplex_meta_program_account,
+                metadata.name.clone(),
+                metadata.symbol.clone(),
+                metadata.uri.clone().unwrap_or_default(),
+                artist_metaplex_creators(a.dao_authority.key, true, false),
+                artist_token_mint_signer_seeds,
+            )?;
+
+            sign_metaplex_metadata(
+                a.artist_token_metaplex_meta,
+                a.dao_authority,
+                a.metaplex_meta_program_account,
+            )?;
+        } else {
+            // an authority rotated out since verifying can no longer sign, the update below
+            // drops its entry without one
+            let dao_verified = metaplex_metadata_creators(a.artist_token_metaplex_meta)?
+                .unwrap_or_default()
+                .iter()
+                .any(|creator| creator.address == *a.dao_authority.key && creator.verified);
+
+            if dao_verified {
+                msg!("Removing DAO as verified creator...");
+                invoke(
+                    &mpl_token_metadata::instruction::remove_creator_verification(
+                        mpl_token_metadata::ID,
+                        *a.artist_token_metaplex_meta.key,
+                        *a.dao_authority.key,
+                    ),
+                    &[
+                        a.artist_token_metaplex_meta.clone(),
+                        a.dao_authority.clone(),
+                        a.metaplex_meta_program_account.clone(),
+                    ],
+                )?;
+            }
+
+            update_metaplex_metadata_account(
+                a.artist_token_metaplex_meta,
+                a.artist_token_mint,
+                a.metaplex_meta_program_account,
+                metadata.name.clone(),
+                metadata.symbol.clone(),
+                metadata.uri.clone().unwrap_or_default(),
+                artist_metaplex_creators(a.dao_authority.key, false, false),
+                artist_token_mint_signer_seeds,
+            )?;
+        }
+    }
+
+    metadata.verified = verified;
+    metadata.save(a.artist_token_meta)?;
+
+    Ok(())
+}
+
+fn parse_accounts<'a, 'b: 'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<Accounts<'a, 'b>, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let a = Accounts {
+        dao_authority: next_account_info(account_iter)?,
+        state: next_account_info(account_iter)?,
+        artist_wallet: next_account_info(account_iter)?,
+        artist_token_mint: next_account_info(account_iter)?,
+        artist_token_meta: next_account_info(account_iter)?,
+        artist_token_metaplex_meta: next_account_info(account_iter)?,
+        metaplex_meta_program_account: next_account_info(account_iter)?,
+    };
+
+    validate_accounts(program_id, &[(a.state, &[AllovrState])])?;
+    let state = AllovrTokenState::load(a.state, program_id)?;
+
+    validate_accounts(
+        program_id,
+        &[
+            (a.dao_authority, &[DaoAuthority(&state.dao_authority)]),
+            (a.artist_token_meta, &[ProgramOwned, Writable]),
+            (a.metaplex_meta_program_account, &[MetaplexProgram]),
+        ],
+    )?;
+
+    Ok(a)
+}
@@ -1,7 +1,76 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::borsh::try_from_slice_unchecked;
 use solana_program::clock::UnixTimestamp;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::error::AllovrError;
+use crate::pda;
+use crate::utils::{assert_owned_by, assert_pda, assert_pda_with_bump};
+use crate::ACCOUNT_HEADER_SIZE;
+
+/// First byte of every program account, identifies the layout that follows
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AccountKey {
+    Uninitialised,
+    AllovrTokenState,
+    ArtistMetadata,
+    ArtistMarket,
+    ArtistVesting,
+    ArtistAirdrop,
+    ArtistAirdropClaim,
+    ArtistProfile,
+    ArtistSymbol,
+    ArtistRevenueVault,
+    ArtistRevenueStake,
+    ArtworkMetadata,
+    ArtworkSwap,
+    ArtworkBundle,
+    ArtworkVault,
+}
+
+/// Program accounts are laid out as [AccountKey, version, Borsh body]. VERSION is bumped
+/// whenever the body changes and MigrateAccount upgrades accounts written by older versions.
+///
+/// Processors only read program accounts through load, then check the address against the
+/// bump the account stores, so an account of another type, owner or address cannot be passed
+/// in place of the expected one.
+pub trait ProgramAccount: BorshSerialize + BorshDeserialize {
+    const KEY: AccountKey;
+    const VERSION: u8;
+
+    /// Account owned by the program with this type's key and current version
+    fn load(account: &AccountInfo, program_id: &Pubkey) -> Result<Self, ProgramError> {
+        assert_owned_by(account, program_id)?;
+
+        let data = account.data.borrow();
+        if data.len() < ACCOUNT_HEADER_SIZE || data[0] != Self::KEY as u8 {
+            return Err(AllovrError::InvalidAccountKey.into());
+        }
+
+        if data[1] != Self::VERSION {
+            return Err(AllovrError::AccountNeedsMigration.into());
+        }
+
+        Ok(try_from_slice_unchecked(&data[ACCOUNT_HEADER_SIZE..])?)
+    }
+
+    fn save(&self, account: &AccountInfo) -> ProgramResult {
+        let mut data = account.data.borrow_mut();
+        if data.len() < ACCOUNT_HEADER_SIZE {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        data[0] = Self::KEY as u8;
+        data[1] = Self::VERSION;
+        self.serialize(&mut &mut data[ACCOUNT_HEADER_SIZE..])?;
+
+        Ok(())
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct AllovrTokenState {
     pub minted: bool,
@@ -15,6 +84,12 @@ pub struct AllovrTokenState {
     pub founder_6: Pubkey,
     pub founder_7: Pubkey,
     pub founder_8: Pubkey,
+    pub dao_authority: Pubkey, // verifies artists, rotated with SetDaoAuthority
+}
+
+impl ProgramAccount for AllovrTokenState {
+    const KEY: AccountKey = AccountKey::AllovrTokenState;
+    const VERSION: u8 = 1;
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -23,6 +98,249 @@ pub struct ArtistMetadata {
     pub description: String,
     pub symbol: String,
     pub uri: Option<String>,
+    pub verified: bool, // set by the DAO with VerifyArtist
+    pub bump: u8,
+    pub mint_bump: u8,    // bump of the artist token mint PDA
+    pub open_offers: u32, // artworks under a sale, swap or bundle offer, see count_open_offer
+}
+
+impl ProgramAccount for ArtistMetadata {
+    const KEY: AccountKey = AccountKey::ArtistMetadata;
+    const VERSION: u8 = 1;
+}
+
+impl ArtistMetadata {
+    /// Metadata of the artist registered by artist_wallet, returned with the artist token mint.
+    /// Both addresses are recreated from the stored bumps instead of searched for.
+    pub fn load_for_artist(
+        account: &AccountInfo,
+        program_id: &Pubkey,
+        artist_wallet: &Pubkey,
+    ) -> Result<(Self, Pubkey), ProgramError> {
+        let metadata = Self::load(account, program_id)?;
+
+        let artist_token_mint =
+            pda::artist_mint_seeds(artist_wallet).create(metadata.mint_bump, program_id)?;
+        assert_pda_with_bump(
+            account,
+            program_id,
+            &pda::artist_metadata_seeds(&artist_token_mint).as_slices(),
+            metadata.bump,
+        )?;
+
+        Ok((metadata, artist_token_mint))
+    }
+
+    /// Counts an offer opened (or closed) on one of the artist's artworks, so DeregisterArtist
+    /// can require that none are open. Once the artist has deregistered there is no metadata
+    /// to count in, the account must then be the empty artist metadata PDA.
+    pub fn count_open_offer(
+        account: &AccountInfo,
+        program_id: &Pubkey,
+        artist_wallet: &Pubkey,
+        opened: bool,
+    ) -> ProgramResult {
+        if account.data_is_empty() {
+            let artist_token_mint = pda::find_artist_mint(program_id, artist_wallet).0;
+            assert_pda(
+                account,
+                program_id,
+                &pda::artist_metadata_seeds(&artist_token_mint).as_slices(),
+            )?;
+            return Ok(());
+        }
+
+        let (mut metadata, _artist_token_mint) =
+            Self::load_for_artist(account, program_id, artist_wallet)?;
+        metadata.open_offers = if opened {
+            metadata
+                .open_offers
+                .checked_add(1)
+                .ok_or(AllovrError::ArtworkUnderOffer)?
+        } else {
+            metadata.open_offers.saturating_sub(1)
+        };
+
+        metadata.save(account)
+    }
+}
+
+/// Linear bonding curve, the next artist token costs
+/// base_price + slope * supply / ARTIST_BONDING_CURVE_SLOPE_SCALE AOVR (in base units)
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct BondingCurve {
+    pub base_price: u64,
+    pub slope: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistMarket {
+    pub artist: Pubkey,
+    pub artist_mint: Pubkey,
+    pub curve: BondingCurve,
+    pub supply: u64,  // artist tokens sold by the curve and not sold back
+    pub active: bool, // false once the artist deregisters, fans can still sell back
+    pub bump: u8,
+    pub mint_bump: u8, // bump of the artist token mint PDA
+}
+
+impl ProgramAccount for ArtistMarket {
+    const KEY: AccountKey = AccountKey::ArtistMarket;
+    const VERSION: u8 = 1;
+}
+
+/// Artist's token allocation, held by the vesting PDA and released linearly from the cliff
+/// to the end time
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistVesting {
+    pub artist: Pubkey,
+    pub artist_mint: Pubkey,
+    pub total: u64,
+    pub claimed: u64,
+    pub start_time: UnixTimestamp,
+    pub cliff_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistVesting {
+    const KEY: AccountKey = AccountKey::ArtistVesting;
+    const VERSION: u8 = 1;
+}
+
+impl ArtistVesting {
+    /// Amount vested at `now`, nothing before the cliff and everything from the end time.
+    pub fn vested_at(&self, now: UnixTimestamp) -> u64 {
+        if now < self.cliff_time {
+            return 0;
+        }
+
+        if now >= self.end_time {
+            return self.total;
+        }
+
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+
+        (self.total as u128 * elapsed / duration) as u64
+    }
+}
+
+/// Merkle airdrop of artist tokens, held by the airdrop PDA's ATA. Leaves are
+/// keccak(index, claimant, amount) and pairs are hashed in sorted order.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistAirdrop {
+    pub artist: Pubkey,
+    pub artist_mint: Pubkey,
+    pub id: u64,
+    pub merkle_root: [u8; 32],
+    pub total: u64,
+    pub claimed: u64,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistAirdrop {
+    const KEY: AccountKey = AccountKey::ArtistAirdrop;
+    const VERSION: u8 = 1;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub enum ArtistLinkPlatform {
+    Website,
+    Twitter,
+    Instagram,
+    Discord,
+    YouTube,
+    TikTok,
+    Spotify,
+    Other,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtistLink {
+    pub platform: ArtistLinkPlatform,
+    pub uri: String,
+}
+
+/// Artist profile extension, kept out of ArtistMetadata so it can grow
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistProfile {
+    pub artist: Pubkey,
+    pub links: Vec<ArtistLink>,
+    pub avatar_uri: Option<String>,
+    pub banner_uri: Option<String>,
+    pub genres: Vec<String>,
+    pub location: Option<String>,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistProfile {
+    const KEY: AccountKey = AccountKey::ArtistProfile;
+    const VERSION: u8 = 1;
+}
+
+/// Registry entry, one per normalised artist token symbol, so no two artists share a ticker
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistSymbol {
+    pub artist: Pubkey,
+    pub artist_mint: Pubkey,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistSymbol {
+    const KEY: AccountKey = AccountKey::ArtistSymbol;
+    const VERSION: u8 = 1;
+}
+
+/// Receipt whose existence marks an airdrop leaf as claimed
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistAirdropClaim {
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+impl ProgramAccount for ArtistAirdropClaim {
+    const KEY: AccountKey = AccountKey::ArtistAirdropClaim;
+    const VERSION: u8 = 1;
+}
+
+/// Per-artist distribution vault. The opted-in share of the artist's sale proceeds is paid in
+/// and added to the reward per staked artist token (scaled by ARTIST_REWARD_PRECISION).
+/// SOL rewards are held by the vault account itself, AOVR rewards in its AOVR ATA.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistRevenueVault {
+    pub artist: Pubkey,
+    pub artist_mint: Pubkey,
+    pub share_basis_points: u16,
+    pub total_staked: u64,
+    pub sol_reward_per_token: u128,
+    pub aovr_reward_per_token: u128,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistRevenueVault {
+    const KEY: AccountKey = AccountKey::ArtistRevenueVault;
+    const VERSION: u8 = 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtistRevenueStake {
+    pub holder: Pubkey,
+    pub artist_mint: Pubkey,
+    pub amount: u64,
+    pub sol_reward_per_token_paid: u128,
+    pub aovr_reward_per_token_paid: u128,
+    pub sol_owed: u64,
+    pub aovr_owed: u64,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtistRevenueStake {
+    const KEY: AccountKey = AccountKey::ArtistRevenueStake;
+    const VERSION: u8 = 1;
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -36,6 +354,189 @@ pub struct ArtworkMetadata {
     pub offer_price: Option<u64>,
     pub offer_currency: Option<Currency>,
     pub payment_account: Option<Pubkey>,
+    pub max_editions: u64,
+    pub editions_printed: u64,
+    pub print: Option<ArtworkPrint>,
+    pub content_uri: String,
+    pub content_hash: [u8; 32],
+    pub creators: Vec<ArtworkCreator>,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub dutch_auction: Option<DutchAuction>,
+    pub swap: Option<Pubkey>, // swap PDA while the artwork is offered in a swap
+    pub bundle: Option<Pubkey>, // bundle PDA while the artwork is listed in a bundle
+    pub bump: u8,
+    pub mint_bump: u8,                  // bump of the artwork mint PDA
+    pub escrow_bump: Option<u8>,        // bump of the escrow PDA while the artwork is in escrow
+    pub fraction_mint_bump: Option<u8>, // bump of the fraction mint PDA once fractionalised
+}
+
+impl ProgramAccount for ArtworkMetadata {
+    const KEY: AccountKey = AccountKey::ArtworkMetadata;
+    const VERSION: u8 = 1;
+}
+
+impl ArtworkMetadata {
+    /// Artwork metadata at the PDA of the mint it describes, returned with that mint. Both
+    /// addresses are recreated from the stored bumps instead of searched for.
+    pub fn load_with_mint(
+        account: &AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<(Self, Pubkey), ProgramError> {
+        let metadata = Self::load(account, program_id)?;
+
+        let artwork_mint = metadata.mint(program_id)?;
+        assert_pda_with_bump(
+            account,
+            program_id,
+            &pda::artwork_metadata_seeds(&artwork_mint).as_slices(),
+            metadata.bump,
+        )?;
+
+        Ok((metadata, artwork_mint))
+    }
+
+    /// Address of the artwork mint, from the stored bump
+    pub fn mint(&self, program_id: &Pubkey) -> Result<Pubkey, AllovrError> {
+        pda::artwork_metadata_mint_seeds(self).create(self.mint_bump, program_id)
+    }
+
+    /// Records the new holder. Once the artwork leaves its creators any later sale is secondary,
+    /// so the seller keeps the price less royalties.
+    pub fn set_holder(&mut self, holder: Pubkey) {
+        if !self
+            .creators
+            .iter()
+            .any(|creator| creator.address == holder)
+        {
+            self.primary_sale_happened = true;
+        }
+        self.holder = holder;
+    }
+
+    /// An artwork is under offer while it has an asking price or is offered in a swap or bundle.
+    /// Dutch auctions are open to any buyer, so `offered_to` alone does not tell.
+    pub fn is_under_offer(&self) -> bool {
+        self.offer_price.is_some() || self.swap.is_some() || self.bundle.is_some()
+    }
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtworkCreator {
+    pub address: Pubkey,
+    pub share: u8, // percentage of primary sale proceeds and royalties
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct DutchAuction {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+}
+
+impl DutchAuction {
+    /// Price declines linearly from the start price at `start_time` to the floor price at
+    /// `end_time`, and stays at the floor after that.
+    pub fn price_at(&self, now: UnixTimestamp) -> u64 {
+        if now <= self.start_time {
+            return self.start_price;
+        }
+
+        if now >= self.end_time {
+            return self.floor_price;
+        }
+
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        let drop = (self.start_price - self.floor_price) as u128 * elapsed / duration;
+
+        self.start_price - drop as u64
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtworkSwap {
+    pub proposer: Pubkey,
+    pub offered_mint: Pubkey,
+    pub requested_mint: Pubkey,
+    pub counterparty: Pubkey, // holder of the requested artwork when proposed
+    pub top_up: Option<ArtworkSwapTopUp>,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtworkSwap {
+    const KEY: AccountKey = AccountKey::ArtworkSwap;
+    const VERSION: u8 = 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtworkBundle {
+    pub seller: Pubkey,
+    pub artworks: Vec<Pubkey>, // artwork mints
+    pub offered_to: Option<Pubkey>,
+    pub price: u64,
+    pub currency: Currency,
+    pub payment_account: Pubkey,
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtworkBundle {
+    const KEY: AccountKey = AccountKey::ArtworkBundle;
+    const VERSION: u8 = 1;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub enum ArtworkVaultState {
+    Active,
+    BoughtOut,
+}
+
+/// Holds a fractionalised artwork. The vault PDA is the artwork holder until it is bought
+/// out or redeemed by whoever holds every fraction.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ArtworkVault {
+    pub curator: Pubkey,
+    pub artwork_mint: Pubkey,
+    pub fraction_mint: Pubkey,
+    pub fraction_supply: u64,
+    pub reserve_price: u64,
+    pub currency: Currency,
+    pub state: ArtworkVaultState,
+    pub proceeds: u64, // buyout proceeds not yet claimed by fraction holders
+    pub bump: u8,
+}
+
+impl ProgramAccount for ArtworkVault {
+    const KEY: AccountKey = AccountKey::ArtworkVault;
+    const VERSION: u8 = 1;
+}
+
+/// Paid to the other side when the swap is accepted. A proposer top-up is held by the swap
+/// account (SOL) or its AOVR ATA until then, and refunded if the swap is cancelled.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub struct ArtworkSwapTopUp {
+    pub amount: u64,
+    pub currency: Currency,
+    pub payment_account: Pubkey, // the proposer's, receives or pays the top-up
+    pub payer: ArtworkSwapTopUpPayer,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, BorshSchema)]
+pub enum ArtworkSwapTopUpPayer {
+    Counterparty,
+    Proposer,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ArtworkPrint {
+    pub parent: Pubkey, // master artwork mint
+    pub edition: u64,
 }
 
 #[repr(C)]
@@ -44,3 +545,42 @@ pub enum Currency {
     SOL,
     AOVR,
 }
+
+/// ArtistMetadata as written before account headers, only read by MigrateAccount
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyArtistMetadata {
+    pub name: String,
+    pub description: String,
+    pub symbol: String,
+    pub uri: Option<String>,
+}
+
+/// ArtworkMetadata as written before account headers, only read by MigrateAccount
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyArtworkMetadata {
+    pub artist: Pubkey,
+    pub symbol: String,
+    pub description: String,
+    pub holder: Pubkey,
+    pub offer_count: u32,
+    pub offered_to: Option<Pubkey>,
+    pub offer_price: Option<u64>,
+    pub offer_currency: Option<Currency>,
+    pub payment_account: Option<Pubkey>,
+}
+
+/// AllovrTokenState as written before account headers, only read by MigrateAccount
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyAllovrTokenState {
+    pub minted: bool,
+    pub next_inflation_due: UnixTimestamp,
+    pub inflation_run_count: u32,
+    pub founder_1: Pubkey,
+    pub founder_2: Pubkey,
+    pub founder_3: Pubkey,
+    pub founder_4: Pubkey,
+    pub founder_5: Pubkey,
+    pub founder_6: Pubkey,
+    pub founder_7: Pubkey,
+    pub founder_8: Pubkey,
+}
@@ -1,12 +1,41 @@
 use crate::error::AllovrError;
-use crate::instruction::RegisterArtistArgs;
+use crate::instruction::{
+    ArtistProfileArgs, ArtistTokenAllocation, CreateArtworkArgs, RegisterArtistArgs,
+    UpdateArtistArgs, UpdateArtworkMetadataArgs,
+};
+use crate::pda;
+use crate::state::{
+    ArtistRevenueStake, ArtistRevenueVault, ArtworkCreator, BondingCurve, Currency, ProgramAccount,
+};
+use crate::ALLOVR_MINT_ID;
 use crate::ALL_DECIMAL_PLACES;
+use crate::ARTIST_BONDING_CURVE_SLOPE_SCALE;
+use crate::ARTIST_COLLECTION_SYMBOL_SIZE;
 use crate::ARTIST_METADATA_DESCRIPTION_SIZE;
 use crate::ARTIST_METADATA_NAME_SIZE;
 use crate::ARTIST_METADATA_URI_SIZE;
+use crate::ARTIST_MIN_VESTING_SECONDS;
+use crate::ARTIST_REWARD_PRECISION;
+use crate::ARTIST_TOKEN_MAX_DECIMALS;
+use crate::ARTWORK_MAX_CREATORS;
+use crate::ARTWORK_MAX_EDITIONS;
+use crate::ARTWORK_MAX_SELLER_FEE_BASIS_POINTS;
+use crate::ARTWORK_METADATA_CONTENT_URI_SIZE;
+use crate::ARTWORK_METADATA_DESCRIPTION_SIZE;
+use crate::ARTWORK_METADATA_SYMBOL_SIZE;
+use crate::ARTWORK_METAPLEX_NAME_SIZE;
+use crate::ARTWORK_METAPLEX_URI_SIZE;
+use crate::{
+    ARTIST_PROFILE_GENRE_SIZE, ARTIST_PROFILE_LOCATION_SIZE, ARTIST_PROFILE_MAX_GENRES,
+    ARTIST_PROFILE_MAX_LINKS, ARTIST_PROFILE_URI_SIZE,
+};
+use mpl_token_metadata::state::Collection;
+use mpl_token_metadata::state::Creator;
 use mpl_token_metadata::state::DataV2;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
+use solana_program::keccak;
 use solana_program::msg;
 use solana_program::program::invoke;
 use solana_program::program::invoke_signed;
@@ -18,8 +47,12 @@ use solana_program::sysvar::rent::Rent;
 use std::convert::TryInto;
 use std::str::FromStr;
 
+use mpl_token_metadata::instruction::create_master_edition_v3;
 use mpl_token_metadata::instruction::create_metadata_accounts_v3;
+use mpl_token_metadata::instruction::mint_new_edition_from_master_edition_via_token;
+use mpl_token_metadata::instruction::sign_metadata;
 use mpl_token_metadata::instruction::update_metadata_accounts_v2;
+use mpl_token_metadata::instruction::verify_collection;
 
 pub fn ui_amount_to_amount(aov: f64) -> u64 {
     spl_token::ui_amount_to_amount(aov, ALL_DECIMAL_PLACES)
@@ -52,6 +85,33 @@ pub fn assert_pda(
     Ok((derived_pub_key, derived_seed_bump))
 }
 
+/// As assert_pda, with the bump stored when the PDA was created so the address is recreated
+/// with create_program_address rather than searched for
+pub fn assert_pda_with_bump(
+    account_info: &AccountInfo,
+    program_id: &Pubkey,
+    seeds: &[&[u8]],
+    bump: u8,
+) -> Result<Pubkey, AllovrError> {
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+
+    let derived_pub_key = Pubkey::create_program_address(&signer_seeds, program_id)
+        .map_err(|_| AllovrError::InvalidPda)?;
+
+    if derived_pub_key != *account_info.key {
+        msg!(
+            "assert_pda_with_bump failed - DERIVED. {} - PROVIDED. {}",
+            &derived_pub_key,
+            account_info.key
+        );
+        return Err(AllovrError::InvalidPda);
+    }
+
+    Ok(derived_pub_key)
+}
+
 pub fn assert_token_program_matches_package(token_program_info: &AccountInfo) -> ProgramResult {
     if *token_program_info.key != spl_token::id() {
         return Err(AllovrError::InvalidTokenProgram.into());
@@ -120,6 +180,11 @@ pub fn get_token_supply(mint: &AccountInfo) -> u64 {
     return mint_data.supply;
 }
 
+pub fn get_token_account(account: &AccountInfo) -> Result<spl_token::state::Account, ProgramError> {
+    assert_owned_by(account, &spl_token::id())?;
+    spl_token::state::Account::unpack(&account.try_borrow_data()?)
+}
+
 pub fn assert_signer(account_info: &AccountInfo) -> ProgramResult {
     if !account_info.is_signer {
         Err(AllovrError::MissingRequiredSignature.into())
@@ -160,6 +225,15 @@ pub fn assert_aovr_treasury(treasury: &Pubkey) -> ProgramResult {
     }
 }
 
+/// The DAO authority recorded in the ALLOVR state must sign, an unset authority matches no one
+pub fn assert_dao_authority(dao_authority: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if *expected == Pubkey::default() || dao_authority.key != expected {
+        return Err(AllovrError::InvalidDaoAuthority.into());
+    }
+
+    assert_signer(dao_authority)
+}
+
 pub fn assert_clock(clock: &AccountInfo) -> ProgramResult {
     if *clock.key != solana_program::sysvar::clock::id() {
         Err(AllovrError::InvalidClockSysvarId.into())
@@ -168,6 +242,97 @@ pub fn assert_clock(clock: &AccountInfo) -> ProgramResult {
     }
 }
 
+/// A requirement on one instruction account, checked by validate_accounts
+#[derive(Debug)]
+pub enum AccountConstraint<'c> {
+    Signer,
+    Writable,
+    /// Owned by the given program
+    Owner(&'c Pubkey),
+    /// Owned by this program
+    ProgramOwned,
+    /// Derived from this program id with the given seeds
+    Pda(&'c [&'c [u8]]),
+    /// SPL token account for the given mint, owned by the given wallet
+    Ata {
+        mint: &'c Pubkey,
+        owner: &'c Pubkey,
+    },
+    RentExempt(&'c Rent),
+    SystemProgram,
+    TokenProgram,
+    AtaProgram,
+    MetaplexProgram,
+    ClockSysvar,
+    AllovrState,
+    AllovrTreasury,
+    AllovrMint,
+    /// Signer matching the DAO authority in the ALLOVR state
+    DaoAuthority(&'c Pubkey),
+}
+
+pub fn assert_account_constraint(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+    constraint: &AccountConstraint,
+) -> ProgramResult {
+    match constraint {
+        AccountConstraint::Signer => assert_signer(account),
+        AccountConstraint::Writable => {
+            if !account.is_writable {
+                Err(AllovrError::AccountNotWritable.into())
+            } else {
+                Ok(())
+            }
+        }
+        AccountConstraint::Owner(owner) => assert_owned_by(account, owner),
+        AccountConstraint::ProgramOwned => assert_owned_by(account, program_id),
+        AccountConstraint::Pda(seeds) => {
+            assert_pda(account, program_id, seeds)?;
+            Ok(())
+        }
+        AccountConstraint::Ata { mint, owner } => {
+            assert_ata_mint_and_owner(account, **mint, **owner)
+        }
+        AccountConstraint::RentExempt(rent) => assert_rent_exempt(rent, account),
+        AccountConstraint::SystemProgram => assert_system(account),
+        AccountConstraint::TokenProgram => assert_token_program_matches_package(account),
+        AccountConstraint::AtaProgram => assert_ata_program_matches_package(account),
+        AccountConstraint::MetaplexProgram => assert_metaplex_program(account),
+        AccountConstraint::ClockSysvar => assert_clock(account),
+        AccountConstraint::AllovrState => assert_state(account.key),
+        AccountConstraint::AllovrTreasury => assert_aovr_treasury(account.key),
+        AccountConstraint::AllovrMint => {
+            if *account.key != Pubkey::from_str(ALLOVR_MINT_ID).unwrap() {
+                Err(AllovrError::InvalidAllovrMint.into())
+            } else {
+                Ok(())
+            }
+        }
+        AccountConstraint::DaoAuthority(expected) => assert_dao_authority(account, expected),
+    }
+}
+
+/// Checks the program id and then every account against its declared constraints, in order.
+/// The first failing account and constraint are logged before the error is returned.
+pub fn validate_accounts(
+    program_id: &Pubkey,
+    accounts: &[(&AccountInfo, &[AccountConstraint])],
+) -> ProgramResult {
+    assert_program_id(program_id)?;
+
+    for (account, constraints) in accounts.iter() {
+        for constraint in constraints.iter() {
+            if let Err(err) = assert_account_constraint(account, program_id, constraint) {
+                msg!("{} failed {:?} constraint", account.key, constraint);
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn create_account<'a>(
     rent: &Rent,
     account_size: usize,
@@ -263,23 +428,247 @@ pub fn transfer_token<'a>(
     Ok(())
 }
 
+/// Like `transfer_token` for token accounts owned by a program PDA.
+pub fn transfer_token_signed<'a>(
+    token_program: &AccountInfo<'a>,
+    holder: &AccountInfo<'a>,
+    recipient: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    amount: u64,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let ix = spl_token::instruction::transfer(
+        token_program.key,
+        holder.key,
+        recipient.key,
+        authority.key,
+        &[],
+        amount,
+    )?;
+
+    invoke_signed(
+        &ix,
+        &[
+            holder.clone(),
+            recipient.clone(),
+            authority.clone(),
+            token_program.clone(),
+        ],
+        &[signer_seeds],
+    )
+}
+
+pub fn burn_tokens<'a>(
+    token_program: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let ix = spl_token::instruction::burn(
+        token_program.key,
+        token_account.key,
+        mint.key,
+        owner.key,
+        &[],
+        amount,
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            token_account.clone(),
+            mint.clone(),
+            owner.clone(),
+            token_program.clone(),
+        ],
+    )
+}
+
+/// Sends the escrowed artwork to `recipient` and closes the escrow, returning its rent to
+/// `rent_recipient`.
+pub fn release_escrow<'a>(
+    token_program: &AccountInfo<'a>,
+    escrow: &AccountInfo<'a>,
+    recipient: &AccountInfo<'a>,
+    rent_recipient: &AccountInfo<'a>,
+    escrow_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let transfer_ix = spl_token::instruction::transfer(
+        token_program.key,
+        escrow.key,
+        recipient.key,
+        escrow.key,
+        &[],
+        1,
+    )?;
+
+    invoke_signed(
+        &transfer_ix,
+        &[escrow.clone(), recipient.clone(), token_program.clone()],
+        &[escrow_signer_seeds],
+    )?;
+
+    let close_ix = spl_token::instruction::close_account(
+        token_program.key,
+        escrow.key,
+        rent_recipient.key,
+        escrow.key,
+        &[],
+    )?;
+
+    invoke_signed(
+        &close_ix,
+        &[
+            escrow.clone(),
+            rent_recipient.clone(),
+            token_program.clone(),
+        ],
+        &[escrow_signer_seeds],
+    )
+}
+
+/// Pays the whole balance of a token account owned by a program PDA to recipient and closes
+/// it, its rent goes to rent_recipient.
+pub fn release_token_account<'a>(
+    token_program: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    recipient: &AccountInfo<'a>,
+    rent_recipient: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let amount = get_token_account(token_account)?.amount;
+    transfer_token_signed(
+        token_program,
+        token_account,
+        recipient,
+        authority,
+        amount,
+        signer_seeds,
+    )?;
+
+    let close_ix = spl_token::instruction::close_account(
+        token_program.key,
+        token_account.key,
+        rent_recipient.key,
+        authority.key,
+        &[],
+    )?;
+
+    invoke_signed(
+        &close_ix,
+        &[
+            token_account.clone(),
+            rent_recipient.clone(),
+            authority.clone(),
+            token_program.clone(),
+        ],
+        &[signer_seeds],
+    )
+}
+
+/// Pays `amount` from the buyer to `recipient`. SOL is paid from the buyer wallet, AOVR from
+/// the buyer's AOVR token account. Zero amounts are skipped.
+pub fn transfer_payment<'a>(
+    currency: &Currency,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    buyer_wallet: &AccountInfo<'a>,
+    buyer_payment: &AccountInfo<'a>,
+    recipient: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match currency {
+        Currency::SOL => invoke(
+            &system_instruction::transfer(buyer_payment.key, recipient.key, amount),
+            &[
+                buyer_payment.clone(),
+                recipient.clone(),
+                system_program.clone(),
+            ],
+        ),
+        Currency::AOVR => transfer_token(
+            token_program,
+            buyer_payment,
+            recipient,
+            buyer_wallet,
+            amount,
+        ),
+    }
+}
+
+/// Creators are paid to their wallet for SOL and to their AOVR token account for AOVR.
+pub fn assert_creator_payment_account(
+    account: &AccountInfo,
+    currency: &Currency,
+    creator: Pubkey,
+) -> ProgramResult {
+    match currency {
+        Currency::SOL => {
+            if *account.key != creator {
+                return Err(AllovrError::IncorrectCreatorPaymentAccount.into());
+            }
+            Ok(())
+        }
+        Currency::AOVR => {
+            assert_ata_mint_and_owner(account, Pubkey::from_str(ALLOVR_MINT_ID).unwrap(), creator)
+        }
+    }
+}
+
+/// Closes an account owned by this program, sending its lamports to `destination`.
+pub fn close_program_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    let lamports = account.lamports();
+    **destination.lamports.borrow_mut() = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::InvalidArgument)?;
+    **account.lamports.borrow_mut() = 0;
+    account.data.borrow_mut().fill(0);
+
+    Ok(())
+}
+
+/// Reallocs a program account to new_size, zeroes it and tops up rent from the payer
+pub fn resize_program_account<'a>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system: &AccountInfo<'a>,
+    rent: &Rent,
+    new_size: usize,
+) -> ProgramResult {
+    let required_lamports = rent
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, required_lamports),
+            &[payer.clone(), account.clone(), system.clone()],
+        )?;
+    }
+
+    account.realloc(new_size, false)?;
+    account.data.borrow_mut().fill(0);
+
+    Ok(())
+}
+
 pub fn create_pda_account<'a>(
     rent: &Rent,
     account_size: usize,
     payer_account: &AccountInfo<'a>,
     pda_account: &AccountInfo<'a>,
     program_id: Pubkey,
-    pda_seed: &str,
-    pda_seed_bump: u8,
+    signers_seeds: &[&[u8]],
 ) -> ProgramResult {
     let lamports_required = rent.minimum_balance(account_size);
 
-    let signers_seeds = &[
-        pda_seed.as_bytes(),
-        &payer_account.key.to_bytes(),
-        &[pda_seed_bump],
-    ];
-
     let create_pda_account_ix = system_instruction::create_account(
         &payer_account.key,
         &pda_account.key,
@@ -315,12 +704,9 @@ pub fn initalise_mint_account<'a>(
     mint_account: &AccountInfo<'a>,
     mint_authority: &AccountInfo<'a>,
     rent_account: &AccountInfo<'a>,
-    seed_prefix: &str,
-    seed_bump: u8,
+    signers_seeds: &[&[u8]],
     decimals: u8,
 ) -> ProgramResult {
-    let signers_seeds = &[seed_prefix.as_bytes(), &[seed_bump]];
-
     let initialize_mint_ix = spl_token::instruction::initialize_mint(
         &spl_token::id(),
         mint_account.key,
@@ -491,26 +877,104 @@ pub fn create_ata<'a>(
     Ok(())
 }
 
+/// Symbol registry key, "art" and "ART" are the same ticker
+pub fn normalise_artist_symbol(symbol: &str) -> String {
+    symbol.trim().to_ascii_uppercase()
+}
+
 pub fn santitise_artist_data(args: RegisterArtistArgs) -> Result<RegisterArtistArgs, ProgramError> {
-    let artist_name = args.name.trim().to_string();
+    let (name, description, token_symbol, uri) =
+        santitise_artist_details(args.name, args.description, args.token_symbol, args.uri)?;
+
+    // a flat curve (slope 0) is allowed, free tokens are not
+    if args.bonding_curve.base_price == 0 {
+        return Err(AllovrError::InvalidBondingCurve.into());
+    }
+
+    if args.token_supply == 0 || args.token_decimals > ARTIST_TOKEN_MAX_DECIMALS {
+        return Err(AllovrError::InvalidArtistTokenAllocation.into());
+    }
+
+    // checks the split adds up
+    calculate_artist_token_allocation(args.token_supply, &args.allocation)?;
+
+    // the artist's allocation always vests, so it cannot all be sold on day one
+    if args.vesting.duration_seconds < ARTIST_MIN_VESTING_SECONDS
+        || args.vesting.cliff_seconds < 0
+        || args.vesting.cliff_seconds > args.vesting.duration_seconds
+    {
+        return Err(AllovrError::InvalidVestingSchedule.into());
+    }
+
+    let response = RegisterArtistArgs {
+        name,
+        description,
+        token_symbol,
+        uri,
+        create_metaplex_metadata: args.create_metaplex_metadata,
+        bonding_curve: args.bonding_curve,
+        token_supply: args.token_supply,
+        token_decimals: args.token_decimals,
+        allocation: args.allocation,
+        vesting: args.vesting,
+    };
+
+    Ok(response)
+}
+
+pub fn santitise_update_artist_data(
+    args: UpdateArtistArgs,
+) -> Result<UpdateArtistArgs, ProgramError> {
+    let (name, description, token_symbol, uri) =
+        santitise_artist_details(args.name, args.description, args.token_symbol, args.uri)?;
+
+    let profile = match args.profile {
+        Some(profile) => Some(santitise_artist_profile(profile)?),
+        None => None,
+    };
+
+    let response = UpdateArtistArgs {
+        name,
+        description,
+        token_symbol,
+        uri,
+        create_metaplex_metadata: args.create_metaplex_metadata,
+        profile,
+    };
+
+    Ok(response)
+}
+
+/// Trims and checks the artist details RegisterArtist and UpdateArtist share
+fn santitise_artist_details(
+    name: String,
+    description: String,
+    token_symbol: String,
+    uri: Option<String>,
+) -> Result<(String, String, String, Option<String>), ProgramError> {
+    let artist_name = name.trim().to_string();
     if artist_name.len() == 0 || artist_name.len() > ARTIST_METADATA_NAME_SIZE {
         return Err(AllovrError::InvalidArtistName.into());
     }
 
-    let artist_description = args.description.trim().to_string();
+    let artist_description = description.trim().to_string();
     if artist_description.len() == 0 || artist_description.len() > ARTIST_METADATA_DESCRIPTION_SIZE
     {
         return Err(AllovrError::InvalidArtistDescription.into());
     }
 
-    let artist_token_symbol = args.token_symbol.trim().to_string();
-    if artist_token_symbol.len() != 3 && artist_token_symbol.len() != 4 {
+    let artist_token_symbol = token_symbol.trim().to_string();
+    if (artist_token_symbol.len() != 3 && artist_token_symbol.len() != 4)
+        || !artist_token_symbol
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric())
+    {
         return Err(AllovrError::InvalidArtistSymbol.into());
     }
 
     let mut artist_uri_option = None;
-    if args.uri.is_some() {
-        let artist_uri = args.uri.unwrap().trim().to_string();
+    if let Some(uri) = uri {
+        let artist_uri = uri.trim().to_string();
         if artist_uri.len() > ARTIST_METADATA_URI_SIZE {
             return Err(AllovrError::InvalidArtistUri.into());
         }
@@ -518,23 +982,231 @@ pub fn santitise_artist_data(args: RegisterArtistArgs) -> Result<RegisterArtistA
         artist_uri_option = Some(artist_uri);
     }
 
-    let mut create_metaplex_metadata_option: Option<bool> = None;
-    if args.create_metaplex_metadata.is_some() {
-        let create_metaplex_metadata = args.create_metaplex_metadata.unwrap();
-        create_metaplex_metadata_option = Some(create_metaplex_metadata);
+    Ok((
+        artist_name,
+        artist_description,
+        artist_token_symbol,
+        artist_uri_option,
+    ))
+}
+
+/// Trims optional profile text, empty text is stored as None
+fn santitise_artist_profile_text(
+    text: Option<String>,
+    max_size: usize,
+) -> Result<Option<String>, ProgramError> {
+    match text.map(|x| x.trim().to_string()) {
+        Some(x) if x.len() > max_size => Err(AllovrError::InvalidArtistProfile.into()),
+        Some(x) if x.is_empty() => Ok(None),
+        x => Ok(x),
+    }
+}
+
+pub fn santitise_artist_profile(
+    args: ArtistProfileArgs,
+) -> Result<ArtistProfileArgs, ProgramError> {
+    if args.links.len() > ARTIST_PROFILE_MAX_LINKS || args.genres.len() > ARTIST_PROFILE_MAX_GENRES
+    {
+        return Err(AllovrError::InvalidArtistProfile.into());
     }
 
-    let response = RegisterArtistArgs {
-        name: artist_name,
-        description: artist_description,
-        token_symbol: artist_token_symbol,
-        uri: artist_uri_option,
-        create_metaplex_metadata: create_metaplex_metadata_option,
+    let mut links = Vec::with_capacity(args.links.len());
+    for mut link in args.links {
+        link.uri = link.uri.trim().to_string();
+        if link.uri.is_empty() || link.uri.len() > ARTIST_PROFILE_URI_SIZE {
+            return Err(AllovrError::InvalidArtistProfile.into());
+        }
+
+        links.push(link);
+    }
+
+    // genres are tags, lower case so "Jazz" and "jazz" match
+    let mut genres: Vec<String> = Vec::with_capacity(args.genres.len());
+    for genre in args.genres {
+        let genre = genre.trim().to_lowercase();
+        if genre.is_empty() || genre.len() > ARTIST_PROFILE_GENRE_SIZE || genres.contains(&genre) {
+            return Err(AllovrError::InvalidArtistProfile.into());
+        }
+
+        genres.push(genre);
+    }
+
+    Ok(ArtistProfileArgs {
+        links,
+        avatar_uri: santitise_artist_profile_text(args.avatar_uri, ARTIST_PROFILE_URI_SIZE)?,
+        banner_uri: santitise_artist_profile_text(args.banner_uri, ARTIST_PROFILE_URI_SIZE)?,
+        genres,
+        location: santitise_artist_profile_text(args.location, ARTIST_PROFILE_LOCATION_SIZE)?,
+    })
+}
+
+pub fn santitise_artwork_data(args: CreateArtworkArgs) -> Result<CreateArtworkArgs, ProgramError> {
+    let artwork_symbol = args.symbol.trim().to_string();
+    if artwork_symbol.len() != ARTWORK_METADATA_SYMBOL_SIZE {
+        return Err(AllovrError::InvalidArtworkSymbol.into());
+    }
+
+    let artwork_description = santitise_artwork_description(&args.description)?;
+
+    let artwork_name = args.name.trim().to_string();
+    if artwork_name.is_empty() || artwork_name.len() > ARTWORK_METAPLEX_NAME_SIZE {
+        return Err(AllovrError::InvalidArtworkName.into());
+    }
+
+    let artwork_uri = args.uri.trim().to_string();
+    if artwork_uri.len() > ARTWORK_METAPLEX_URI_SIZE {
+        return Err(AllovrError::InvalidArtworkUri.into());
+    }
+
+    if args.seller_fee_basis_points > ARTWORK_MAX_SELLER_FEE_BASIS_POINTS {
+        return Err(AllovrError::InvalidSellerFeeBasisPoints.into());
+    }
+
+    let mut collection_symbol_option = None;
+    if let Some(collection_symbol) = args.collection_symbol {
+        let collection_symbol = collection_symbol.trim().to_string();
+        if collection_symbol.is_empty() || collection_symbol.len() > ARTIST_COLLECTION_SYMBOL_SIZE {
+            return Err(AllovrError::InvalidCollectionSymbol.into());
+        }
+
+        collection_symbol_option = Some(collection_symbol);
+    }
+
+    if args.max_editions > ARTWORK_MAX_EDITIONS {
+        return Err(AllovrError::InvalidMaxEditions.into());
+    }
+
+    let artwork_content_uri = santitise_artwork_content(&args.content_uri, &args.content_hash)?;
+
+    santitise_artwork_creators(&args.creators)?;
+
+    let response = CreateArtworkArgs {
+        symbol: artwork_symbol,
+        description: artwork_description,
+        name: artwork_name,
+        uri: artwork_uri,
+        seller_fee_basis_points: args.seller_fee_basis_points,
+        collection_symbol: collection_symbol_option,
+        max_editions: args.max_editions,
+        content_uri: artwork_content_uri,
+        content_hash: args.content_hash,
+        creators: args.creators,
     };
 
     Ok(response)
 }
 
+pub fn santitise_artwork_update_data(
+    args: UpdateArtworkMetadataArgs,
+) -> Result<UpdateArtworkMetadataArgs, ProgramError> {
+    let artwork_description = santitise_artwork_description(&args.description)?;
+    let artwork_content_uri = santitise_artwork_content(&args.content_uri, &args.content_hash)?;
+
+    let response = UpdateArtworkMetadataArgs {
+        description: artwork_description,
+        content_uri: artwork_content_uri,
+        content_hash: args.content_hash,
+    };
+
+    Ok(response)
+}
+
+fn santitise_artwork_description(description: &str) -> Result<String, ProgramError> {
+    let artwork_description = description.trim().to_string();
+    if artwork_description.is_empty()
+        || artwork_description.len() > ARTWORK_METADATA_DESCRIPTION_SIZE
+    {
+        return Err(AllovrError::ArtworkDescriptionMaxLengthExceeded.into());
+    }
+
+    Ok(artwork_description)
+}
+
+fn santitise_artwork_creators(creators: &[ArtworkCreator]) -> ProgramResult {
+    // empty means the artist is the sole creator
+    if creators.is_empty() {
+        return Ok(());
+    }
+
+    if creators.len() > ARTWORK_MAX_CREATORS {
+        return Err(AllovrError::InvalidArtworkCreators.into());
+    }
+
+    let mut total_share: u16 = 0;
+    for (i, creator) in creators.iter().enumerate() {
+        if creator.share == 0 || creators[..i].iter().any(|c| c.address == creator.address) {
+            return Err(AllovrError::InvalidArtworkCreators.into());
+        }
+        total_share += u16::from(creator.share);
+    }
+
+    if total_share != 100 {
+        return Err(AllovrError::InvalidArtworkCreators.into());
+    }
+
+    Ok(())
+}
+
+/// Splits a sale price between the artwork creators by share. On the primary sale the
+/// creators receive the whole price, on secondary sales they receive the royalty only.
+/// Whatever is not paid to creators (including rounding dust) goes to the seller.
+pub fn calculate_creator_payouts(
+    price: u64,
+    creators: &[ArtworkCreator],
+    seller_fee_basis_points: u16,
+    primary_sale: bool,
+) -> Result<Vec<u64>, ProgramError> {
+    let creators_total = if primary_sale {
+        u128::from(price)
+    } else {
+        u128::from(price) * u128::from(seller_fee_basis_points) / 10000
+    };
+
+    creators
+        .iter()
+        .map(|c| {
+            (creators_total * u128::from(c.share) / 100)
+                .try_into()
+                .map_err(|_| AllovrError::InvalidOffer.into())
+        })
+        .collect()
+}
+
+/// Share of the remaining buyout proceeds for `fractions` out of the `outstanding` fractions.
+pub fn calculate_fraction_payout(
+    proceeds: u64,
+    fractions: u64,
+    outstanding: u64,
+) -> Result<u64, ProgramError> {
+    if fractions == 0 || fractions > outstanding {
+        return Err(AllovrError::InsufficientFractions.into());
+    }
+
+    (u128::from(proceeds) * u128::from(fractions) / u128::from(outstanding))
+        .try_into()
+        .map_err(|_| AllovrError::InsufficientFractions.into())
+}
+
+fn santitise_artwork_content(
+    content_uri: &str,
+    content_hash: &[u8; 32],
+) -> Result<String, ProgramError> {
+    let artwork_content_uri = content_uri.trim().to_string();
+    if artwork_content_uri.is_empty()
+        || artwork_content_uri.len() > ARTWORK_METADATA_CONTENT_URI_SIZE
+    {
+        return Err(AllovrError::InvalidArtworkContentUri.into());
+    }
+
+    // an all zero hash is what an unset hash looks like on chain
+    if content_hash.iter().all(|b| *b == 0) {
+        return Err(AllovrError::InvalidArtworkContentHash.into());
+    }
+
+    Ok(artwork_content_uri)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_metaplex_metadata_account<'a>(
     metadata_account: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
@@ -544,6 +1216,9 @@ pub fn create_metaplex_metadata_account<'a>(
     name: String,
     symbol: String,
     uri: String,
+    creators: Option<Vec<Creator>>,
+    seller_fee_basis_points: u16,
+    collection: Option<Collection>,
     signer_seeds: &[&[u8]],
 ) -> ProgramResult {
     let cmda_instruction = create_metadata_accounts_v3(
@@ -556,11 +1231,11 @@ pub fn create_metaplex_metadata_account<'a>(
         name,
         symbol,
         uri,
-        None,
-        0,
+        creators,
+        seller_fee_basis_points,
         true,
         true,
-        None,
+        collection,
         None,
         None,
     );
@@ -583,6 +1258,169 @@ pub fn create_metaplex_metadata_account<'a>(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn create_metaplex_master_edition_account<'a>(
+    master_edition_account: &AccountInfo<'a>,
+    metadata_account: &AccountInfo<'a>,
+    mint_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    meta_program_account: &AccountInfo<'a>,
+    token_program_account: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    rent_account: &AccountInfo<'a>,
+    max_supply: Option<u64>,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    // the mint PDA is both update and mint authority, Metaplex takes over mint authority
+    let cme_instruction = create_master_edition_v3(
+        mpl_token_metadata::ID,
+        *master_edition_account.key,
+        *mint_account.key,
+        *mint_account.key,
+        *mint_account.key,
+        *metadata_account.key,
+        *payer_account.key,
+        max_supply,
+    );
+
+    invoke_signed(
+        &cme_instruction,
+        &[
+            master_edition_account.clone(),
+            mint_account.clone(),
+            payer_account.clone(),
+            metadata_account.clone(),
+            meta_program_account.clone(),
+            token_program_account.clone(),
+            system_account.clone(),
+            rent_account.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn mint_metaplex_print_edition<'a>(
+    print_metadata_account: &AccountInfo<'a>,
+    print_edition_account: &AccountInfo<'a>,
+    master_edition_account: &AccountInfo<'a>,
+    print_mint_account: &AccountInfo<'a>,
+    edition_marker_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    master_token_owner_account: &AccountInfo<'a>,
+    master_token_account: &AccountInfo<'a>,
+    master_metadata_account: &AccountInfo<'a>,
+    master_mint_account: &AccountInfo<'a>,
+    meta_program_account: &AccountInfo<'a>,
+    token_program_account: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    rent_account: &AccountInfo<'a>,
+    edition: u64,
+    print_mint_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    // the print mint PDA is mint authority and becomes update authority of the print metadata
+    let print_instruction = mint_new_edition_from_master_edition_via_token(
+        mpl_token_metadata::ID,
+        *print_metadata_account.key,
+        *print_edition_account.key,
+        *master_edition_account.key,
+        *print_mint_account.key,
+        *print_mint_account.key,
+        *payer_account.key,
+        *master_token_owner_account.key,
+        *master_token_account.key,
+        *print_mint_account.key,
+        *master_metadata_account.key,
+        *master_mint_account.key,
+        edition,
+    );
+
+    invoke_signed(
+        &print_instruction,
+        &[
+            print_metadata_account.clone(),
+            print_edition_account.clone(),
+            master_edition_account.clone(),
+            print_mint_account.clone(),
+            edition_marker_account.clone(),
+            payer_account.clone(),
+            master_token_owner_account.clone(),
+            master_token_account.clone(),
+            master_metadata_account.clone(),
+            meta_program_account.clone(),
+            token_program_account.clone(),
+            system_account.clone(),
+            rent_account.clone(),
+        ],
+        &[print_mint_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+pub fn sign_metaplex_metadata<'a>(
+    metadata_account: &AccountInfo<'a>,
+    creator_account: &AccountInfo<'a>,
+    meta_program_account: &AccountInfo<'a>,
+) -> ProgramResult {
+    let sign_instruction = sign_metadata(
+        mpl_token_metadata::ID,
+        *metadata_account.key,
+        *creator_account.key,
+    );
+
+    invoke(
+        &sign_instruction,
+        &[
+            metadata_account.clone(),
+            creator_account.clone(),
+            meta_program_account.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn verify_metaplex_collection<'a>(
+    metadata_account: &AccountInfo<'a>,
+    collection_mint_account: &AccountInfo<'a>,
+    collection_metadata_account: &AccountInfo<'a>,
+    collection_master_edition_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    meta_program_account: &AccountInfo<'a>,
+    collection_signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    // the collection mint PDA is the update authority of the collection and signs as collection authority
+    let verify_instruction = verify_collection(
+        mpl_token_metadata::ID,
+        *metadata_account.key,
+        *collection_mint_account.key,
+        *payer_account.key,
+        *collection_mint_account.key,
+        *collection_metadata_account.key,
+        *collection_master_edition_account.key,
+        None,
+    );
+
+    invoke_signed(
+        &verify_instruction,
+        &[
+            metadata_account.clone(),
+            collection_mint_account.clone(),
+            payer_account.clone(),
+            collection_metadata_account.clone(),
+            collection_master_edition_account.clone(),
+            meta_program_account.clone(),
+        ],
+        &[collection_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_metaplex_metadata_account<'a>(
     metadata_account: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
@@ -590,6 +1428,7 @@ pub fn update_metaplex_metadata_account<'a>(
     name: String,
     symbol: String,
     uri: String,
+    creators: Option<Vec<Creator>>,
     signer_seeds: &[&[u8]],
 ) -> ProgramResult {
     let data = DataV2 {
@@ -597,7 +1436,7 @@ pub fn update_metaplex_metadata_account<'a>(
         symbol: symbol,
         uri: uri,
         seller_fee_basis_points: 0,
-        creators: None,
+        creators,
         collection: None,
         uses: None,
     };
@@ -626,3 +1465,281 @@ pub fn update_metaplex_metadata_account<'a>(
 
     Ok(())
 }
+
+/// Creators for an artist token's Metaplex metadata. A verified artist has the DAO authority as
+/// verified creator, which is how wallets and marketplaces see the verification.
+pub fn artist_metaplex_creators(
+    dao_authority: &Pubkey,
+    verified: bool,
+    dao_verified: bool,
+) -> Option<Vec<Creator>> {
+    if !verified {
+        return None;
+    }
+
+    Some(vec![Creator {
+        address: *dao_authority,
+        verified: dao_verified,
+        share: 100,
+    }])
+}
+
+/// Creators already in a Metaplex metadata account. Updates other than Verify and
+/// RevokeArtistVerification pass these back unchanged, metaplex only lets a creator mark
+/// itself verified and the DAO authority that signed may since have been rotated.
+pub fn metaplex_metadata_creators(
+    metadata_account: &AccountInfo,
+) -> Result<Option<Vec<Creator>>, ProgramError> {
+    let metadata: Metadata = Metadata::from_account_info(metadata_account)?;
+
+    Ok(metadata.data.creators)
+}
+
+/// AOVR cost of amount artist tokens on the curve, starting from supply tokens sold. Buys round
+/// up and sells round down, so the reserve always covers selling back every token sold.
+pub fn calculate_bonding_curve_cost(
+    curve: &BondingCurve,
+    supply: u64,
+    amount: u64,
+    round_up: bool,
+) -> Result<u64, ProgramError> {
+    if amount == 0 {
+        return Err(AllovrError::InvalidBondingCurve.into());
+    }
+
+    // sum of base_price + slope * i / scale for i in supply..supply + amount
+    let n = u128::from(amount);
+    let s = u128::from(supply);
+    let scale = u128::from(ARTIST_BONDING_CURVE_SLOPE_SCALE);
+    let slope_sum = n
+        .checked_mul(s)
+        .and_then(|x| x.checked_add(n * (n - 1) / 2))
+        .and_then(|x| x.checked_mul(u128::from(curve.slope)))
+        .ok_or(AllovrError::InvalidBondingCurve)?;
+
+    let mut slope_cost = slope_sum / scale;
+    if round_up && slope_sum % scale != 0 {
+        slope_cost += 1;
+    }
+
+    n.checked_mul(u128::from(curve.base_price))
+        .and_then(|x| x.checked_add(slope_cost))
+        .and_then(|x| x.try_into().ok())
+        .ok_or_else(|| AllovrError::InvalidBondingCurve.into())
+}
+
+/// Part of an artist's payout shared with artist token holders
+pub fn calculate_revenue_share(amount: u64, share_basis_points: u16) -> u64 {
+    (u128::from(amount) * u128::from(share_basis_points) / 10000) as u64
+}
+
+/// Increase in reward per staked artist token when amount is paid into the revenue vault
+pub fn calculate_reward_per_token(amount: u64, total_staked: u64) -> Result<u128, ProgramError> {
+    if total_staked == 0 {
+        return Err(AllovrError::InsufficientStake.into());
+    }
+
+    Ok(u128::from(amount) * ARTIST_REWARD_PRECISION / u128::from(total_staked))
+}
+
+/// Rewards earned by staked tokens since reward_per_token_paid, rounded down so the vault
+/// always holds enough to pay every staker
+pub fn calculate_rewards_earned(
+    staked: u64,
+    reward_per_token: u128,
+    reward_per_token_paid: u128,
+) -> Result<u64, ProgramError> {
+    let earned = reward_per_token
+        .checked_sub(reward_per_token_paid)
+        .and_then(|x| x.checked_mul(u128::from(staked)))
+        .ok_or(AllovrError::InsufficientStake)?
+        / ARTIST_REWARD_PRECISION;
+
+    earned
+        .try_into()
+        .map_err(|_| AllovrError::InsufficientStake.into())
+}
+
+/// Moves rewards earned by the stake so far into its owed balances, must run before the staked
+/// amount changes or rewards are claimed
+pub fn accrue_artist_revenue(
+    stake: &mut ArtistRevenueStake,
+    vault: &ArtistRevenueVault,
+) -> ProgramResult {
+    let sol_earned = calculate_rewards_earned(
+        stake.amount,
+        vault.sol_reward_per_token,
+        stake.sol_reward_per_token_paid,
+    )?;
+    let aovr_earned = calculate_rewards_earned(
+        stake.amount,
+        vault.aovr_reward_per_token,
+        stake.aovr_reward_per_token_paid,
+    )?;
+
+    stake.sol_owed = stake
+        .sol_owed
+        .checked_add(sol_earned)
+        .ok_or(AllovrError::InsufficientStake)?;
+    stake.aovr_owed = stake
+        .aovr_owed
+        .checked_add(aovr_earned)
+        .ok_or(AllovrError::InsufficientStake)?;
+    stake.sol_reward_per_token_paid = vault.sol_reward_per_token;
+    stake.aovr_reward_per_token_paid = vault.aovr_reward_per_token;
+
+    Ok(())
+}
+
+/// Artist revenue vault an artwork sale pays into, with the account that receives the sale
+/// currency (the vault itself for SOL, its AOVR ATA for AOVR)
+pub struct ArtistRevenueShare<'b, 'a> {
+    pub vault: ArtistRevenueVault,
+    pub vault_account: &'b AccountInfo<'a>,
+    pub payment_account: &'b AccountInfo<'a>,
+    pub currency: Currency,
+}
+
+/// Revenue vault of the artist when the artist shares sale revenue and artist tokens are
+/// staked, None otherwise. An opened vault is checked against the bump it stores. Until the
+/// artist opens one there is no stored bump, so the address is searched for, otherwise an empty
+/// account could be passed to skip the share.
+pub fn load_artist_revenue_share<'b, 'a>(
+    program_id: &Pubkey,
+    artist: &Pubkey,
+    currency: &Currency,
+    revenue_vault: &'b AccountInfo<'a>,
+    revenue_vault_aovr: &'b AccountInfo<'a>,
+) -> Result<Option<ArtistRevenueShare<'b, 'a>>, ProgramError> {
+    msg!("Checking artist revenue vault PDA is correct...");
+    if revenue_vault.owner != program_id || revenue_vault.data_is_empty() {
+        let artist_token_mint = pda::find_artist_mint(program_id, artist).0;
+        assert_pda(
+            revenue_vault,
+            program_id,
+            &pda::artist_revenue_vault_seeds(&artist_token_mint).as_slices(),
+        )?;
+        return Ok(None);
+    }
+
+    let vault = ArtistRevenueVault::load(revenue_vault, program_id)?;
+    if vault.artist != *artist {
+        return Err(AllovrError::InvalidRevenueShare.into());
+    }
+
+    assert_pda_with_bump(
+        revenue_vault,
+        program_id,
+        &pda::artist_revenue_vault_seeds(&vault.artist_mint).as_slices(),
+        vault.bump,
+    )?;
+
+    if vault.share_basis_points == 0 || vault.total_staked == 0 {
+        return Ok(None);
+    }
+
+    let payment_account = match currency {
+        Currency::SOL => revenue_vault,
+        Currency::AOVR => {
+            assert_ata_mint_and_owner(
+                revenue_vault_aovr,
+                Pubkey::from_str(ALLOVR_MINT_ID).unwrap(),
+                *revenue_vault.key,
+            )?;
+            revenue_vault_aovr
+        }
+    };
+
+    Ok(Some(ArtistRevenueShare {
+        vault,
+        vault_account: revenue_vault,
+        payment_account,
+        currency: currency.clone(),
+    }))
+}
+
+/// Pays the revenue share of the artist's payout into the vault and adds it to the reward per
+/// staked artist token. Other creators' payouts are not shared. Returns what is left of amount
+/// to pay the creator.
+pub fn pay_artist_revenue_share<'a>(
+    revenue_share: Option<&mut ArtistRevenueShare<'_, 'a>>,
+    creator: &Pubkey,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    buyer_wallet: &AccountInfo<'a>,
+    buyer_payment: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    let share = match revenue_share {
+        Some(share) if share.vault.artist == *creator => share,
+        _ => return Ok(amount),
+    };
+
+    let revenue = calculate_revenue_share(amount, share.vault.share_basis_points);
+    if revenue == 0 {
+        return Ok(amount);
+    }
+
+    msg!("Paying {} to artist revenue vault...", revenue);
+    transfer_payment(
+        &share.currency,
+        token_program,
+        system_program,
+        buyer_wallet,
+        buyer_payment,
+        share.payment_account,
+        revenue,
+    )?;
+
+    let increase = calculate_reward_per_token(revenue, share.vault.total_staked)?;
+    match share.currency {
+        Currency::SOL => share.vault.sol_reward_per_token += increase,
+        Currency::AOVR => share.vault.aovr_reward_per_token += increase,
+    }
+    // saved straight away, a bundle can load the same vault again for the artist's next artwork
+    share.vault.save(share.vault_account)?;
+
+    Ok(amount - revenue)
+}
+
+/// Splits the artist token supply into (artist, community treasury, bonding curve) amounts
+pub fn calculate_artist_token_allocation(
+    supply: u64,
+    allocation: &ArtistTokenAllocation,
+) -> Result<(u64, u64, u64), ProgramError> {
+    let total = u32::from(allocation.artist_basis_points)
+        + u32::from(allocation.community_basis_points)
+        + u32::from(allocation.bonding_curve_basis_points);
+    if total != 10000 {
+        return Err(AllovrError::InvalidArtistTokenAllocation.into());
+    }
+
+    let artist = (u128::from(supply) * u128::from(allocation.artist_basis_points) / 10000) as u64;
+    let curve =
+        (u128::from(supply) * u128::from(allocation.bonding_curve_basis_points) / 10000) as u64;
+
+    Ok((artist, supply - artist - curve, curve))
+}
+
+/// Merkle leaf for an artist airdrop claim
+pub fn artist_airdrop_leaf(index: u64, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &index.to_le_bytes(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Checks leaf is in the tree with root, hashing each pair in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == root
+}
@@ -0,0 +1,392 @@
+//! Accounts and a program context shared by the BPF integration tests. Fixtures are seeded
+//! directly into the test bank so each test starts from the state it needs.
+
+#![allow(dead_code)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ovr_program::instruction::*;
+use ovr_program::pda::*;
+use ovr_program::state::*;
+use ovr_program::{
+    ACCOUNT_HEADER_SIZE, ALLOVR_AOVR_TREASURY_ID, ALLOVR_MINT_ID, ALLOVR_PROGRAM_ID,
+    ALLOVR_STATE_ID, ALL_DECIMAL_PLACES, ARTIST_MIN_VESTING_SECONDS, ARTWORK_METADATA_SIZE,
+    STATE_SIZE,
+};
+use solana_program::borsh::try_from_slice_unchecked;
+use solana_program::clock::Clock;
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_program;
+use solana_program_test::*;
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use spl_associated_token_account::get_associated_token_address;
+use std::str::FromStr;
+
+pub const SOL: u64 = 1_000_000_000;
+pub const AOVR: u64 = 1_000_000_000;
+pub const ARTWORK_PRICE: u64 = SOL;
+
+/// A started ProgramTest with helpers to send instructions and read back accounts
+pub struct TestContext {
+    pub context: ProgramTestContext,
+    pub program_id: Pubkey,
+}
+
+impl TestContext {
+    pub async fn start(program_test: ProgramTest) -> Self {
+        TestContext {
+            context: program_test.start_with_context().await,
+            program_id: program_id(),
+        }
+    }
+
+    pub async fn transaction(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Transaction {
+        let payer = &self.context.payer;
+        let blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let mut tx = Transaction::new_with_payer(ixs, Some(&payer.pubkey()));
+        tx.sign(&[&[payer], signers].concat(), blockhash);
+        tx
+    }
+
+    /// Sends the instructions in one transaction signed by the payer and signers, a failed
+    /// simulation comes back with the program logs
+    pub async fn process(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let tx = self.transaction(ixs, signers).await;
+        self.context
+            .banks_client
+            .process_transaction_with_preflight(tx)
+            .await
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Account {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("{} does not exist", address))
+    }
+
+    /// Closed accounts are gone once the transaction that emptied them lands
+    pub async fn is_closed(&mut self, address: &Pubkey) -> bool {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .is_none()
+    }
+
+    /// Body of a program account, after the account header
+    pub async fn load<T: BorshDeserialize>(&mut self, address: &Pubkey) -> T {
+        try_from_slice_unchecked(&self.account(address).await.data[ACCOUNT_HEADER_SIZE..]).unwrap()
+    }
+
+    pub async fn artwork(&mut self, artwork_mint: &Pubkey) -> ArtworkMetadata {
+        let artwork_meta = find_artwork_metadata(&self.program_id, artwork_mint).0;
+        self.load(&artwork_meta).await
+    }
+
+    pub async fn token_amount(&mut self, address: &Pubkey) -> u64 {
+        spl_token::state::Account::unpack(&self.account(address).await.data)
+            .unwrap()
+            .amount
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context.banks_client.get_sysvar().await.unwrap()
+    }
+
+    pub async fn warp(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+}
+
+/// The program error a failed instruction at index returned
+pub fn program_error(index: u8, error: ovr_program::error::AllovrError) -> TransactionError {
+    TransactionError::InstructionError(index, InstructionError::Custom(error as u32))
+}
+
+pub fn program_id() -> Pubkey {
+    Pubkey::from_str(ALLOVR_PROGRAM_ID).unwrap()
+}
+
+pub fn aovr_mint() -> Pubkey {
+    Pubkey::from_str(ALLOVR_MINT_ID).unwrap()
+}
+
+pub fn allovr_state() -> Pubkey {
+    Pubkey::from_str(ALLOVR_STATE_ID).unwrap()
+}
+
+/// BPF build of the ALLOVR program with the AOVR mint and DAO treasury at their known addresses
+pub fn program_test() -> ProgramTest {
+    if find_file("ovr_program.so").is_none() {
+        panic!("ovr_program.so not found, run the BPF tests with cargo test-bpf");
+    }
+
+    // the native processor cannot resize accounts, every test needs the BPF loader
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(true);
+    program_test.add_program("ovr_program", program_id(), None);
+
+    program_test.add_account(
+        aovr_mint(),
+        mint(
+            Pubkey::new_unique(),
+            1_000_000_000 * AOVR,
+            ALL_DECIMAL_PLACES,
+        ),
+    );
+    program_test.add_account(
+        Pubkey::from_str(ALLOVR_AOVR_TREASURY_ID).unwrap(),
+        token_account(aovr_mint(), Pubkey::new_unique(), 0),
+    );
+
+    program_test
+}
+
+/// program_test with the Metaplex token metadata program dumped to tests/fixtures
+pub fn metaplex_program_test() -> ProgramTest {
+    let mut program_test = program_test();
+    if find_file("mpl_token_metadata.so").is_none() {
+        panic!(
+            "tests/fixtures/mpl_token_metadata.so not found, dump it with solana program dump {} \
+             tests/fixtures/mpl_token_metadata.so",
+            mpl_token_metadata::ID
+        );
+    }
+
+    program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+
+    program_test
+}
+
+/// Wallet holding 100 SOL and aovr_amount AOVR in its AOVR ATA
+pub fn add_wallet(program_test: &mut ProgramTest, aovr_amount: u64) -> Keypair {
+    let wallet = Keypair::new();
+    program_test.add_account(
+        wallet.pubkey(),
+        Account {
+            lamports: 100 * SOL,
+            ..account(vec![], system_program::id())
+        },
+    );
+    program_test.add_account(
+        get_associated_token_address(&wallet.pubkey(), &aovr_mint()),
+        token_account(aovr_mint(), wallet.pubkey(), aovr_amount),
+    );
+
+    wallet
+}
+
+pub fn account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn mint(mint_authority: Pubkey, supply: u64, decimals: u8) -> Account {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+
+    account(data, spl_token::id())
+}
+
+pub fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+
+    account(data, spl_token::id())
+}
+
+/// Program account with the current layout header
+pub fn program_account<T: BorshSerialize>(
+    key: AccountKey,
+    version: u8,
+    body: &T,
+    size: usize,
+) -> Account {
+    let mut data = vec![0; size];
+    data[0] = key as u8;
+    data[1] = version;
+    body.serialize(&mut &mut data[ACCOUNT_HEADER_SIZE..])
+        .unwrap();
+
+    account(data, program_id())
+}
+
+pub fn artwork_metadata(artist: &Pubkey, symbol: &str) -> ArtworkMetadata {
+    let (artwork_mint, mint_bump) = find_artwork_mint(&program_id(), symbol, artist);
+
+    ArtworkMetadata {
+        artist: *artist,
+        symbol: symbol.to_string(),
+        description: "Seeded by the compute unit benchmarks".to_string(),
+        holder: *artist,
+        offer_count: 0,
+        offered_to: None,
+        offer_price: None,
+        offer_currency: None,
+        payment_account: None,
+        max_editions: 0,
+        editions_printed: 0,
+        print: None,
+        content_uri: "ipfs://content".to_string(),
+        content_hash: [1; 32],
+        creators: vec![ArtworkCreator {
+            address: *artist,
+            share: 100,
+        }],
+        seller_fee_basis_points: 500,
+        primary_sale_happened: false,
+        dutch_auction: None,
+        swap: None,
+        bundle: None,
+        bump: find_artwork_metadata(&program_id(), &artwork_mint).1,
+        mint_bump,
+        escrow_bump: None,
+        fraction_mint_bump: None,
+    }
+}
+
+/// Artwork held by the artist, created directly rather than through CreateArtwork so scenarios
+/// without Metaplex can trade it
+pub fn add_artwork(program_test: &mut ProgramTest, artist: &Pubkey, symbol: &str) -> Pubkey {
+    let artwork_mint = find_artwork_mint(&program_id(), symbol, artist).0;
+
+    program_test.add_account(artwork_mint, mint(artwork_mint, 1, 0));
+    program_test.add_account(
+        get_associated_token_address(artist, &artwork_mint),
+        token_account(artwork_mint, *artist, 1),
+    );
+    program_test.add_account(
+        find_artwork_metadata(&program_id(), &artwork_mint).0,
+        program_account(
+            AccountKey::ArtworkMetadata,
+            ArtworkMetadata::VERSION,
+            &artwork_metadata(artist, symbol),
+            ARTWORK_METADATA_SIZE,
+        ),
+    );
+
+    artwork_mint
+}
+
+/// ALLOVR state as IntialiseAllovr leaves it, with dao_authority verifying artists
+pub fn add_allovr_state(program_test: &mut ProgramTest, dao_authority: &Pubkey) {
+    let state = AllovrTokenState {
+        minted: false,
+        next_inflation_due: 0,
+        inflation_run_count: 0,
+        founder_1: Pubkey::new_unique(),
+        founder_2: Pubkey::new_unique(),
+        founder_3: Pubkey::new_unique(),
+        founder_4: Pubkey::new_unique(),
+        founder_5: Pubkey::new_unique(),
+        founder_6: Pubkey::new_unique(),
+        founder_7: Pubkey::new_unique(),
+        founder_8: Pubkey::new_unique(),
+        dao_authority: *dao_authority,
+    };
+
+    program_test.add_account(
+        allovr_state(),
+        program_account(
+            AccountKey::AllovrTokenState,
+            AllovrTokenState::VERSION,
+            &state,
+            STATE_SIZE,
+        ),
+    );
+}
+
+/// Empty ATA for the artwork, buyers must hold one before accepting a sale
+pub fn add_artwork_account(program_test: &mut ProgramTest, owner: &Pubkey, artwork_mint: &Pubkey) {
+    program_test.add_account(
+        get_associated_token_address(owner, artwork_mint),
+        token_account(*artwork_mint, *owner, 0),
+    );
+}
+
+pub fn register_artist_args(
+    token_symbol: &str,
+    create_metaplex_metadata: bool,
+) -> RegisterArtistArgs {
+    RegisterArtistArgs {
+        name: "Benchmark Artist".to_string(),
+        description: "Registered by the compute unit benchmarks".to_string(),
+        token_symbol: token_symbol.to_string(),
+        uri: Some("https://allovr.io/artist.json".to_string()),
+        create_metaplex_metadata: Some(create_metaplex_metadata),
+        bonding_curve: BondingCurve {
+            base_price: AOVR / 1000,
+            slope: 1000,
+        },
+        token_supply: 1_000_000,
+        token_decimals: 0,
+        allocation: ArtistTokenAllocation {
+            artist_basis_points: 2000,
+            community_basis_points: 3000,
+            bonding_curve_basis_points: 5000,
+        },
+        vesting: ArtistVestingArgs {
+            cliff_seconds: 0,
+            duration_seconds: ARTIST_MIN_VESTING_SECONDS,
+        },
+    }
+}
+
+pub fn update_artist_args(token_symbol: &str, create_metaplex_metadata: bool) -> UpdateArtistArgs {
+    UpdateArtistArgs {
+        name: "Benchmark Artist".to_string(),
+        description: "Updated by the compute unit benchmarks".to_string(),
+        token_symbol: token_symbol.to_string(),
+        uri: Some("https://allovr.io/artist-updated.json".to_string()),
+        create_metaplex_metadata: Some(create_metaplex_metadata),
+        profile: None,
+    }
+}
+
+pub fn sale_args(buyer: &Pubkey, seller: &Pubkey) -> InitArtworkSaleArgs {
+    InitArtworkSaleArgs {
+        buyer: *buyer,
+        amount: ARTWORK_PRICE,
+        currency: Currency::SOL,
+        payment_account: *seller,
+    }
+}
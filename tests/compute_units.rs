@@ -0,0 +1,944 @@
+//! Compute unit benchmarks. Each scenario sets up realistic accounts, runs a user flow and sends
+//! every instruction in its own transaction, reading the units it consumed from the simulation.
+//!
+//! `cargo test-bpf --test compute_units -- --nocapture` builds the program, runs the scenarios
+//! and fails when an instruction uses more than THRESHOLD_PERCENT above its entry in
+//! tests/compute_units.baseline, or has no entry. Run it with UPDATE_COMPUTE_UNITS=1 to write
+//! the measured units to the baseline instead. A plain `cargo test` does not build the suite.
+//!
+//! Scenarios CPI-ing into Metaplex need tests/fixtures/mpl_token_metadata.so, a BPF build of the
+//! mpl-token-metadata version in Cargo.toml, and the Anchor scenario needs
+//! anchor/target/deploy/ovr_program.so from `anchor build`. Both fail without them.
+//!
+//! IntialiseAllovr and MintAllovr are signed by the program keypair, which the tests do not
+//! hold, so those two instructions are not benchmarked. The scenarios seed the ALLOVR state
+//! directly with a DAO authority they hold instead.
+
+#![cfg(feature = "test-bpf")]
+
+mod common;
+
+use borsh::BorshSerialize;
+use common::*;
+use ovr_program::instruction::*;
+use ovr_program::pda::*;
+use ovr_program::state::*;
+use ovr_program::{LEGACY_ARTIST_METADATA_SIZE, LEGACY_ARTWORK_METADATA_SIZE, LEGACY_STATE_SIZE};
+use solana_program::bpf_loader;
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::keccak;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::system_program;
+use solana_program::sysvar;
+use solana_program_test::*;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::TransactionError;
+use spl_associated_token_account::get_associated_token_address;
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+const BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/compute_units.baseline");
+const ANCHOR_PROGRAM_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/anchor/target/deploy/ovr_program.so"
+);
+const ANCHOR_TEST_KEYS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/anchor/tests/test-keys");
+const ANCHOR_PROGRAM_ID: &str = "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS";
+const ANCHOR_TREASURY_ID: &str = "FYCLzQeuDmBFvU8uXfHs5FLuvnBkwYHUrnbFr5XEbLpN";
+/// How far above its baseline an instruction may go before the benchmark fails
+const THRESHOLD_PERCENT: u64 = 5;
+/// When set, measured units are written to the baseline instead of checked against it
+const UPDATE_BASELINE_VAR: &str = "UPDATE_COMPUTE_UNITS";
+
+/// Scenarios run in parallel, baseline updates are read-modify-write. The benchmarks run on the
+/// host toolchain, only the program is held to the BPF rust-version.
+#[allow(clippy::incompatible_msrv)]
+static BASELINE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Units a transaction gets without a compute budget instruction
+const DEFAULT_COMPUTE_UNITS: u64 = 200_000;
+/// Largest compute budget a transaction can request
+const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Runs instructions against a started ProgramTest and records their compute units
+struct Bench {
+    test: TestContext,
+    units: Vec<(String, u64)>,
+}
+
+impl Deref for Bench {
+    type Target = TestContext;
+
+    fn deref(&self) -> &TestContext {
+        &self.test
+    }
+}
+
+impl DerefMut for Bench {
+    fn deref_mut(&mut self) -> &mut TestContext {
+        &mut self.test
+    }
+}
+
+impl Bench {
+    async fn start(program_test: ProgramTest) -> Self {
+        Bench {
+            test: TestContext::start(program_test).await,
+            units: vec![],
+        }
+    }
+
+    /// Sends the instruction in its own transaction and returns the compute units it consumed,
+    /// panicking with the program logs if it fails
+    async fn send(&mut self, ix: Instruction, signers: &[&Keypair]) -> u64 {
+        // the largest budget is requested so instructions over the default are still measured
+        let budget_ix = ComputeBudgetInstruction::request_units(MAX_COMPUTE_UNITS);
+
+        // The banks server only returns simulation logs for failed transactions, so the
+        // instruction is first simulated ahead of a transfer the payer cannot afford
+        let payer = self.context.payer.pubkey();
+        let failing_ix = system_instruction::transfer(&payer, &payer, u64::MAX);
+        let tx = self
+            .transaction(&[budget_ix.clone(), ix.clone(), failing_ix], signers)
+            .await;
+
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_preflight_and_commitment_and_context(
+                tarpc::context::current(),
+                tx,
+                CommitmentLevel::default(),
+            )
+            .await
+            .unwrap();
+
+        let details = result.simulation_details.unwrap();
+        match result.result {
+            Some(Err(TransactionError::InstructionError(2, _))) => (),
+            err => panic!("{:?}\n{}", err, details.logs.join("\n")),
+        }
+
+        let consumed = format!("Program {} consumed ", ix.program_id);
+        let units = details
+            .logs
+            .iter()
+            .find_map(|log| log.strip_prefix(&consumed)?.split(' ').next()?.parse().ok())
+            .unwrap_or(details.units_consumed);
+
+        self.process(&[budget_ix, ix], signers).await.unwrap();
+
+        units
+    }
+
+    /// Sends the instruction and records its compute units under name
+    async fn measure(&mut self, name: &str, ix: Instruction, signers: &[&Keypair]) {
+        let units = self.send(ix, signers).await;
+        self.units.push((name.to_string(), units));
+    }
+
+    /// Prints the recorded units and checks them against the baseline, or updates it
+    fn finish(self) {
+        for (name, units) in self.units.iter() {
+            if *units > DEFAULT_COMPUTE_UNITS {
+                println!(
+                    "{}: {} compute units, needs a compute budget instruction",
+                    name, units
+                );
+            } else {
+                println!("{}: {} compute units", name, units);
+            }
+        }
+
+        let _lock = BASELINE_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut baseline = read_baseline();
+
+        if std::env::var(UPDATE_BASELINE_VAR).is_ok() {
+            baseline.extend(self.units);
+            write_baseline(&baseline);
+            return;
+        }
+
+        let mut failures = vec![];
+        for (name, units) in self.units.iter() {
+            match baseline.get(name) {
+                Some(expected) if *units > expected + expected * THRESHOLD_PERCENT / 100 => {
+                    failures.push(format!(
+                        "{}: {} compute units, baseline {}",
+                        name, units, expected
+                    ))
+                }
+                Some(_) => (),
+                None => failures.push(format!(
+                    "{}: {} compute units, no baseline, run with {}=1 to record it",
+                    name, units, UPDATE_BASELINE_VAR
+                )),
+            }
+        }
+
+        assert!(
+            failures.is_empty(),
+            "Compute units missing from or more than {}% above baseline:\n{}",
+            THRESHOLD_PERCENT,
+            failures.join("\n")
+        );
+    }
+}
+
+/// Baseline lines are `<instruction>: <compute units>`, lines starting with # are comments
+fn read_baseline() -> BTreeMap<String, u64> {
+    let baseline = std::fs::read_to_string(BASELINE_PATH).unwrap_or_default();
+    baseline
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, units) = line
+                .rsplit_once(':')
+                .unwrap_or_else(|| panic!("Invalid baseline line {}", line));
+            (name.trim().to_string(), units.trim().parse().unwrap())
+        })
+        .collect()
+}
+
+fn write_baseline(baseline: &BTreeMap<String, u64>) {
+    let mut contents = format!(
+        "# Compute units per instruction on the BPF build, written by\n\
+         # {}=1 cargo test-bpf --test compute_units\n",
+        UPDATE_BASELINE_VAR
+    );
+    for (name, units) in baseline.iter() {
+        contents.push_str(&format!("{}: {}\n", name, units));
+    }
+    std::fs::write(BASELINE_PATH, contents).unwrap();
+}
+
+/// The Anchor build of the AOVR token and staking program with an empty treasury token account
+fn anchor_program_test() -> ProgramTest {
+    let program = std::fs::read(ANCHOR_PROGRAM_PATH).unwrap_or_else(|err| {
+        panic!(
+            "{} not readable ({}), run anchor build in anchor/",
+            ANCHOR_PROGRAM_PATH, err
+        )
+    });
+
+    let mut program_test = ProgramTest::default();
+    program_test.prefer_bpf(true);
+    program_test.add_account(
+        anchor_program_id(),
+        Account {
+            executable: true,
+            ..account(program, bpf_loader::id())
+        },
+    );
+
+    // the treasury only has to be a token account of the AOVR mint created by initialise_aovr
+    let aovr_mint = anchor_test_key("allovr-mint.json").pubkey();
+    program_test.add_account(
+        Pubkey::from_str(ANCHOR_TREASURY_ID).unwrap(),
+        token_account(aovr_mint, Pubkey::new_unique(), 0),
+    );
+
+    program_test
+}
+
+fn anchor_program_id() -> Pubkey {
+    Pubkey::from_str(ANCHOR_PROGRAM_ID).unwrap()
+}
+
+/// Keypair the Anchor integration tests sign with, its address is hard coded in the program
+fn anchor_test_key(file_name: &str) -> Keypair {
+    read_keypair_file(format!("{}/{}", ANCHOR_TEST_KEYS_PATH, file_name)).unwrap()
+}
+
+/// Anchor instruction data is the first 8 bytes of sha256("global:<name>") then the borsh args
+fn anchor_instruction<T: BorshSerialize>(
+    name: &str,
+    args: &T,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+    args.serialize(&mut data).unwrap();
+
+    Instruction {
+        program_id: anchor_program_id(),
+        accounts,
+        data,
+    }
+}
+
+fn airdrop_leaf_hash(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    if left <= right {
+        keccak::hashv(&[&left, &right]).to_bytes()
+    } else {
+        keccak::hashv(&[&right, &left]).to_bytes()
+    }
+}
+
+#[tokio::test]
+async fn artwork_sale_lifecycle() {
+    let mut program_test = program_test();
+    let artist = add_wallet(&mut program_test, 0);
+    let buyer = add_wallet(&mut program_test, 0);
+    let collector = add_wallet(&mut program_test, 0);
+    let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+    add_artwork_account(&mut program_test, &buyer.pubkey(), &artwork_mint);
+    add_artwork_account(&mut program_test, &collector.pubkey(), &artwork_mint);
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = bench.program_id;
+
+    let args = UpdateArtworkMetadataArgs {
+        description: "Updated by the compute unit benchmarks".to_string(),
+        content_uri: "ipfs://updated".to_string(),
+        content_hash: [2; 32],
+    };
+    let ix = update_artwork_metadata(&program_id, &artist.pubkey(), &artwork_mint, args);
+    bench.measure("UpdateArtworkMetadata", ix, &[&artist]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = sale_args(&buyer.pubkey(), &artist.pubkey());
+    let ix = init_artwork_sale(&program_id, &artist.pubkey(), &artwork, args);
+    bench.measure("InitArtworkSale", ix, &[&artist]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    let ix = cancel_artwork_sale(&program_id, &artist.pubkey(), &artwork);
+    bench.measure("CancelArtworkSale", ix, &[&artist]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = sale_args(&buyer.pubkey(), &artist.pubkey());
+    let ix = init_artwork_sale(&program_id, &artist.pubkey(), &artwork, args);
+    bench.send(ix, &[&artist]).await;
+
+    // primary sale, the whole price goes to the creators
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = AcceptArtworkSaleArgs {
+        max_price: ARTWORK_PRICE,
+    };
+    let ix = accept_artwork_sale(&program_id, &buyer.pubkey(), &artwork, args);
+    bench.measure("AcceptArtworkSale", ix, &[&buyer]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    assert_eq!(artwork.holder, buyer.pubkey());
+    assert_eq!(artwork.escrow_bump, None);
+
+    let now = bench.clock().await.unix_timestamp;
+    let args = InitArtworkDutchAuctionArgs {
+        start_price: 2 * ARTWORK_PRICE,
+        floor_price: ARTWORK_PRICE,
+        start_time: now,
+        end_time: now + 86400,
+        currency: Currency::SOL,
+        payment_account: buyer.pubkey(),
+    };
+    let ix = init_artwork_dutch_auction(&program_id, &buyer.pubkey(), &artwork, args);
+    bench
+        .measure("InitArtworkDutchAuction", ix, &[&buyer])
+        .await;
+
+    // secondary sale, creators are paid the seller fee
+    bench.warp(3600).await;
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = AcceptArtworkSaleArgs {
+        max_price: 2 * ARTWORK_PRICE,
+    };
+    let ix = accept_artwork_sale(&program_id, &collector.pubkey(), &artwork, args);
+    bench
+        .measure("AcceptArtworkSale (Dutch auction)", ix, &[&collector])
+        .await;
+
+    let ix = transfer_artwork(
+        &program_id,
+        &collector.pubkey(),
+        &artist.pubkey(),
+        &artwork_mint,
+    );
+    bench.measure("TransferArtwork", ix, &[&collector]).await;
+
+    // a plain token transfer leaves the metadata holder behind until it is synced
+    let ix = spl_token::instruction::transfer(
+        &spl_token::id(),
+        &get_associated_token_address(&artist.pubkey(), &artwork_mint),
+        &get_associated_token_address(&collector.pubkey(), &artwork_mint),
+        &artist.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+    bench.send(ix, &[&artist]).await;
+
+    let ix = sync_artwork_holder(&program_id, &collector.pubkey(), &artwork_mint);
+    bench.measure("SyncArtworkHolder", ix, &[]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    assert_eq!(artwork.holder, collector.pubkey());
+
+    bench.finish();
+}
+
+#[tokio::test]
+async fn artist_token_lifecycle() {
+    let mut program_test = program_test();
+    let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+    let fan = add_wallet(&mut program_test, 1_000_000 * AOVR);
+    let claimant = add_wallet(&mut program_test, 0);
+    let dao = Keypair::new();
+    add_allovr_state(&mut program_test, &dao.pubkey());
+    let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+    add_artwork_account(&mut program_test, &fan.pubkey(), &artwork_mint);
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = bench.program_id;
+    let artist_token_mint = find_artist_mint(&program_id, &artist.pubkey()).0;
+
+    let args = register_artist_args("BNCH", false);
+    let ix = register_artist(&program_id, &artist.pubkey(), args);
+    bench.measure("RegisterArtist", ix, &[&artist]).await;
+
+    let mut args = update_artist_args("BNCH", false);
+    args.profile = Some(ArtistProfileArgs {
+        links: vec![],
+        avatar_uri: Some("https://allovr.io/avatar.png".to_string()),
+        banner_uri: Some("https://allovr.io/banner.png".to_string()),
+        genres: vec!["Electronic".to_string()],
+        location: Some("London".to_string()),
+    });
+    let ix = update_artist(&program_id, &artist.pubkey(), args);
+    bench.measure("UpdateArtist", ix, &[&artist]).await;
+
+    let args = BuyArtistTokensArgs {
+        amount: 10_000,
+        max_cost: u64::MAX,
+    };
+    let ix = buy_artist_tokens(&program_id, &fan.pubkey(), &artist.pubkey(), args);
+    bench.measure("BuyArtistTokens", ix, &[&fan]).await;
+
+    let args = SellArtistTokensArgs {
+        amount: 1_000,
+        min_return: 0,
+    };
+    let ix = sell_artist_tokens(&program_id, &fan.pubkey(), &artist.pubkey(), args);
+    bench.measure("SellArtistTokens", ix, &[&fan]).await;
+
+    let args = SetArtistRevenueShareArgs {
+        share_basis_points: 2000,
+    };
+    let ix = set_artist_revenue_share(&program_id, &artist.pubkey(), args);
+    bench.measure("SetArtistRevenueShare", ix, &[&artist]).await;
+
+    let args = ArtistStakeArgs { amount: 5_000 };
+    let ix = stake_artist_tokens(&program_id, &fan.pubkey(), &artist.pubkey(), args);
+    bench.measure("StakeArtistTokens", ix, &[&fan]).await;
+
+    // the artist sells an artwork, sharing part of the proceeds with stakers
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = sale_args(&fan.pubkey(), &artist.pubkey());
+    let ix = init_artwork_sale(&program_id, &artist.pubkey(), &artwork, args);
+    bench.send(ix, &[&artist]).await;
+
+    let artwork = bench.artwork(&artwork_mint).await;
+    let args = AcceptArtworkSaleArgs {
+        max_price: ARTWORK_PRICE,
+    };
+    let ix = accept_artwork_sale(&program_id, &fan.pubkey(), &artwork, args);
+    bench
+        .measure("AcceptArtworkSale (revenue share)", ix, &[&fan])
+        .await;
+
+    let ix = claim_artist_revenue(&program_id, &fan.pubkey(), &artist.pubkey());
+    bench.measure("ClaimArtistRevenue", ix, &[&fan]).await;
+
+    let args = ArtistStakeArgs { amount: 5_000 };
+    let ix = unstake_artist_tokens(&program_id, &fan.pubkey(), &artist.pubkey(), args);
+    bench.measure("UnstakeArtistTokens", ix, &[&fan]).await;
+
+    bench.warp(30 * 86400).await;
+    let ix = claim_vested_artist_tokens(&program_id, &artist.pubkey());
+    bench
+        .measure("ClaimVestedArtistTokens", ix, &[&artist])
+        .await;
+
+    let args = DistributeArtistCommunityTokensArgs { amount: 1_000 };
+    let ix = distribute_artist_community_tokens(
+        &program_id,
+        &dao.pubkey(),
+        &artist.pubkey(),
+        &fan.pubkey(),
+        args,
+    );
+    bench
+        .measure("DistributeArtistCommunityTokens", ix, &[&dao])
+        .await;
+
+    let recipients: Vec<(Pubkey, u64)> = (1..=3).map(|i| (Pubkey::new_unique(), i * 10)).collect();
+    let ix = airdrop_artist_tokens(&program_id, &artist.pubkey(), &recipients);
+    bench
+        .measure("AirdropArtistTokens (3 recipients)", ix, &[&artist])
+        .await;
+
+    let claim_leaf = ovr_program::utils::artist_airdrop_leaf(0, &claimant.pubkey(), 100);
+    let other_leaf = ovr_program::utils::artist_airdrop_leaf(1, &Pubkey::new_unique(), 200);
+    let args = CreateArtistAirdropArgs {
+        airdrop_id: 1,
+        merkle_root: airdrop_leaf_hash(claim_leaf, other_leaf),
+        total_amount: 300,
+    };
+    let ix = create_artist_airdrop(&program_id, &artist.pubkey(), args);
+    bench.measure("CreateArtistAirdrop", ix, &[&artist]).await;
+
+    let args = ClaimArtistAirdropArgs {
+        index: 0,
+        amount: 100,
+        proof: vec![other_leaf],
+    };
+    let ix = claim_artist_airdrop(&program_id, &claimant.pubkey(), &artist.pubkey(), 1, args);
+    bench.measure("ClaimArtistAirdrop", ix, &[&claimant]).await;
+
+    let claimant_ata = get_associated_token_address(&claimant.pubkey(), &artist_token_mint);
+    assert_eq!(bench.token_amount(&claimant_ata).await, 100);
+
+    let ix = deregister_artist(&program_id, &artist.pubkey(), "BNCH");
+    bench.measure("DeregisterArtist", ix, &[&artist]).await;
+
+    bench.finish();
+}
+
+#[tokio::test]
+async fn artist_metaplex_lifecycle() {
+    let mut program_test = metaplex_program_test();
+    let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+    let dao = Keypair::new();
+    add_allovr_state(&mut program_test, &dao.pubkey());
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = bench.program_id;
+    let artwork_mint = find_artwork_mint(&program_id, "ART1", &artist.pubkey()).0;
+
+    let args = register_artist_args("MPLX", true);
+    let ix = register_artist(&program_id, &artist.pubkey(), args);
+    bench
+        .measure("RegisterArtist (Metaplex)", ix, &[&artist])
+        .await;
+
+    let args = update_artist_args("MPLX", true);
+    let ix = update_artist(&program_id, &artist.pubkey(), args);
+    bench
+        .measure("UpdateArtist (Metaplex)", ix, &[&artist])
+        .await;
+
+    let ix = verify_artist(&program_id, &dao.pubkey(), &artist.pubkey());
+    bench.measure("VerifyArtist", ix, &[&dao]).await;
+
+    let ix = revoke_artist_verification(&program_id, &dao.pubkey(), &artist.pubkey());
+    bench.measure("RevokeArtistVerification", ix, &[&dao]).await;
+
+    let args = SetDaoAuthorityArgs {
+        dao_authority: Pubkey::new_unique(),
+    };
+    let ix = set_dao_authority(&program_id, &dao.pubkey(), args);
+    bench.measure("SetDaoAuthority", ix, &[&dao]).await;
+
+    let args = CreateArtistCollectionArgs {
+        symbol: "COLL".to_string(),
+        name: "Benchmark Collection".to_string(),
+        uri: "https://allovr.io/collection.json".to_string(),
+    };
+    let ix = create_artist_collection(&program_id, &artist.pubkey(), args);
+    bench
+        .measure("CreateArtistCollection", ix, &[&artist])
+        .await;
+
+    let args = CreateArtworkArgs {
+        symbol: "ART1".to_string(),
+        description: "Created by the compute unit benchmarks".to_string(),
+        name: "Benchmark Artwork".to_string(),
+        uri: "https://allovr.io/artwork.json".to_string(),
+        seller_fee_basis_points: 500,
+        collection_symbol: Some("COLL".to_string()),
+        max_editions: 10,
+        content_uri: "ipfs://content".to_string(),
+        content_hash: [1; 32],
+        creators: vec![],
+    };
+    let ix = create_artwork(&program_id, &artist.pubkey(), args);
+    bench
+        .measure("CreateArtwork (collection)", ix, &[&artist])
+        .await;
+
+    let master = bench.artwork(&artwork_mint).await;
+    let ix = mint_artwork_print(&program_id, &artist.pubkey(), &master);
+    bench.measure("MintArtworkPrint", ix, &[&artist]).await;
+
+    let ix = deregister_artist(&program_id, &artist.pubkey(), "MPLX");
+    bench
+        .measure("DeregisterArtist (Metaplex)", ix, &[&artist])
+        .await;
+
+    bench.finish();
+}
+
+#[tokio::test]
+async fn artwork_swap_bundle_and_fractions() {
+    let mut program_test = program_test();
+    let artist = add_wallet(&mut program_test, 0);
+    let other_artist = add_wallet(&mut program_test, 0);
+    let fan = add_wallet(&mut program_test, 0);
+    let offered_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+    let requested_mint = add_artwork(&mut program_test, &other_artist.pubkey(), "ART1");
+    let bundle_mints = [
+        add_artwork(&mut program_test, &artist.pubkey(), "ART2"),
+        add_artwork(&mut program_test, &artist.pubkey(), "ART3"),
+    ];
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = bench.program_id;
+    let swap_address = find_artwork_swap(&program_id, &offered_mint, &requested_mint).0;
+
+    let offered = bench.artwork(&offered_mint).await;
+    let args = ProposeArtworkSwapArgs { top_up: None };
+    let ix = propose_artwork_swap(
+        &program_id,
+        &artist.pubkey(),
+        &offered,
+        &requested_mint,
+        args,
+    );
+    bench.measure("ProposeArtworkSwap", ix, &[&artist]).await;
+
+    let offered = bench.artwork(&offered_mint).await;
+    let swap: ArtworkSwap = bench.load(&swap_address).await;
+    let ix = cancel_artwork_swap(&program_id, &artist.pubkey(), &swap, &offered);
+    bench.measure("CancelArtworkSwap", ix, &[&artist]).await;
+
+    let offered = bench.artwork(&offered_mint).await;
+    let args = ProposeArtworkSwapArgs {
+        top_up: Some(ArtworkSwapTopUp {
+            amount: ARTWORK_PRICE,
+            currency: Currency::SOL,
+            payment_account: artist.pubkey(),
+            payer: ArtworkSwapTopUpPayer::Counterparty,
+        }),
+    };
+    let ix = propose_artwork_swap(
+        &program_id,
+        &artist.pubkey(),
+        &offered,
+        &requested_mint,
+        args,
+    );
+    bench.send(ix, &[&artist]).await;
+
+    let offered = bench.artwork(&offered_mint).await;
+    let swap: ArtworkSwap = bench.load(&swap_address).await;
+    let ix = accept_artwork_swap(&program_id, &other_artist.pubkey(), &swap, &offered);
+    bench
+        .measure("AcceptArtworkSwap (top-up)", ix, &[&other_artist])
+        .await;
+
+    assert_eq!(bench.artwork(&requested_mint).await.holder, artist.pubkey());
+
+    let mut artworks = vec![];
+    for artwork_mint in bundle_mints.iter() {
+        artworks.push(bench.artwork(artwork_mint).await);
+    }
+    let bundle_args = InitArtworkBundleSaleArgs {
+        buyer: Some(fan.pubkey()),
+        amount: 2 * ARTWORK_PRICE,
+        currency: Currency::SOL,
+        payment_account: artist.pubkey(),
+    };
+    let ix = init_artwork_bundle_sale(
+        &program_id,
+        &artist.pubkey(),
+        &artworks,
+        bundle_args.clone(),
+    );
+    bench
+        .measure("InitArtworkBundleSale (2 artworks)", ix, &[&artist])
+        .await;
+
+    let mut artworks = vec![];
+    for artwork_mint in bundle_mints.iter() {
+        artworks.push(bench.artwork(artwork_mint).await);
+    }
+    let ix = cancel_artwork_bundle_sale(&program_id, &artist.pubkey(), &artworks);
+    bench
+        .measure("CancelArtworkBundleSale (2 artworks)", ix, &[&artist])
+        .await;
+
+    let mut artworks = vec![];
+    for artwork_mint in bundle_mints.iter() {
+        artworks.push(bench.artwork(artwork_mint).await);
+    }
+    let ix = init_artwork_bundle_sale(&program_id, &artist.pubkey(), &artworks, bundle_args);
+    bench.send(ix, &[&artist]).await;
+
+    let mut artworks = vec![];
+    for artwork_mint in bundle_mints.iter() {
+        artworks.push(bench.artwork(artwork_mint).await);
+    }
+    let bundle: ArtworkBundle = bench
+        .load(&find_artwork_bundle(&program_id, &bundle_mints[0]).0)
+        .await;
+    let args = AcceptArtworkSaleArgs {
+        max_price: 2 * ARTWORK_PRICE,
+    };
+    let ix = accept_artwork_bundle_sale(&program_id, &fan.pubkey(), &bundle, &artworks, args);
+    bench
+        .measure("AcceptArtworkBundleSale (2 artworks)", ix, &[&fan])
+        .await;
+
+    // the artist fractionalises the artwork received in the swap
+    let vault_address = find_artwork_vault(&program_id, &requested_mint).0;
+    let fraction_args = FractionaliseArtworkArgs {
+        fraction_supply: 100,
+        reserve_price: ARTWORK_PRICE,
+        currency: Currency::SOL,
+    };
+    let ix = fractionalise_artwork(
+        &program_id,
+        &artist.pubkey(),
+        &requested_mint,
+        fraction_args.clone(),
+    );
+    bench.measure("FractionaliseArtwork", ix, &[&artist]).await;
+
+    let ix = redeem_artwork(&program_id, &artist.pubkey(), &requested_mint);
+    bench.measure("RedeemArtwork", ix, &[&artist]).await;
+
+    // fractionalising again reuses the fraction mint left by the redeemed vault
+    let ix = fractionalise_artwork(
+        &program_id,
+        &artist.pubkey(),
+        &requested_mint,
+        fraction_args,
+    );
+    bench.send(ix, &[&artist]).await;
+
+    let artwork = bench.artwork(&requested_mint).await;
+    let vault: ArtworkVault = bench.load(&vault_address).await;
+    let args = AcceptArtworkSaleArgs {
+        max_price: ARTWORK_PRICE,
+    };
+    let ix = buyout_artwork(&program_id, &fan.pubkey(), &artwork, &vault, args);
+    bench.measure("BuyoutArtwork", ix, &[&fan]).await;
+
+    let vault: ArtworkVault = bench.load(&vault_address).await;
+    let ix = claim_fraction_proceeds(&program_id, &artist.pubkey(), &vault);
+    bench.measure("ClaimFractionProceeds", ix, &[&artist]).await;
+
+    assert_eq!(bench.artwork(&requested_mint).await.holder, fan.pubkey());
+
+    bench.finish();
+}
+
+#[tokio::test]
+async fn anchor_aovr_and_staking() {
+    let program_test = anchor_program_test();
+    let aovr_state = anchor_test_key("allovr-state.json");
+    let aovr_mint = anchor_test_key("allovr-mint.json");
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = anchor_program_id();
+    let initialiser = bench.context.payer.pubkey();
+    let find = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program_id).0;
+    let mint_authority = find(&[b"ALLOVRMINT"]);
+    let stake_pool_registry = find(&[b"ALLOVRSTAKEPOOLREGISTRY"]);
+    let stake_pool = find(&[b"ALLOVRSTAKEPOOL", initialiser.as_ref(), &[0]]);
+    let stake = find(&[b"ALLOVRSTAKE", initialiser.as_ref()]);
+
+    let founders = [(); 8].map(|_| Pubkey::new_unique());
+    let ix = anchor_instruction(
+        "initialise_aovr",
+        &founders,
+        vec![
+            AccountMeta::new(aovr_state.pubkey(), true),
+            AccountMeta::new(aovr_mint.pubkey(), true),
+            AccountMeta::new(mint_authority, false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+    );
+    bench
+        .measure("anchor initialise_aovr", ix, &[&aovr_state, &aovr_mint])
+        .await;
+
+    let ix = anchor_instruction(
+        "mint_aovr",
+        &(),
+        vec![
+            AccountMeta::new(aovr_state.pubkey(), false),
+            AccountMeta::new(aovr_mint.pubkey(), false),
+            AccountMeta::new_readonly(mint_authority, false),
+            AccountMeta::new(Pubkey::from_str(ANCHOR_TREASURY_ID).unwrap(), false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+    );
+    bench.measure("anchor mint_aovr", ix, &[]).await;
+
+    let ix = anchor_instruction(
+        "initialise_staking",
+        &(),
+        vec![
+            AccountMeta::new(stake_pool_registry, false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    bench.measure("anchor initialise_staking", ix, &[]).await;
+
+    let ix = anchor_instruction(
+        "register_staking_pool",
+        &0u8,
+        vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_pool_registry, false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    bench.measure("anchor register_staking_pool", ix, &[]).await;
+
+    // pool_index, slot_index, amount, rebalance_pool_if_needed
+    let ix = anchor_instruction(
+        "stake",
+        &(0u8, 0u8, 100u64, false),
+        vec![
+            AccountMeta::new(stake, false),
+            AccountMeta::new(stake_pool_registry, false),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    bench.measure("anchor stake", ix, &[]).await;
+
+    let ix = anchor_instruction(
+        "rebalance_staking_pool",
+        &0u8,
+        vec![
+            AccountMeta::new(stake_pool_registry, false),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(initialiser, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    bench
+        .measure("anchor rebalance_staking_pool", ix, &[])
+        .await;
+
+    bench.finish();
+}
+
+#[tokio::test]
+async fn migrate_accounts() {
+    let mut program_test = program_test();
+    let artist = Keypair::new();
+    let artist_token_mint = find_artist_mint(&program_id(), &artist.pubkey()).0;
+    let artist_meta = find_artist_metadata(&program_id(), &artist_token_mint).0;
+    let artwork_mint = find_artwork_mint(&program_id(), "ART1", &artist.pubkey()).0;
+    let artwork_meta = find_artwork_metadata(&program_id(), &artwork_mint).0;
+
+    // accounts written before account headers, at the fixed legacy sizes
+    let legacy_artist = LegacyArtistMetadata {
+        name: "Benchmark Artist".to_string(),
+        description: "Registered by the compute unit benchmarks".to_string(),
+        symbol: "BNCH".to_string(),
+        uri: None,
+    };
+    program_test.add_account(
+        artist_meta,
+        legacy_account(&legacy_artist, LEGACY_ARTIST_METADATA_SIZE),
+    );
+
+    let artwork = artwork_metadata(&artist.pubkey(), "ART1");
+    let legacy_artwork = LegacyArtworkMetadata {
+        artist: artwork.artist,
+        symbol: artwork.symbol,
+        description: artwork.description,
+        holder: artwork.holder,
+        offer_count: artwork.offer_count,
+        offered_to: artwork.offered_to,
+        offer_price: artwork.offer_price,
+        offer_currency: artwork.offer_currency,
+        payment_account: artwork.payment_account,
+    };
+    program_test.add_account(
+        artwork_meta,
+        legacy_account(&legacy_artwork, LEGACY_ARTWORK_METADATA_SIZE),
+    );
+
+    let legacy_state = LegacyAllovrTokenState {
+        minted: true,
+        next_inflation_due: 0,
+        inflation_run_count: 0,
+        founder_1: Pubkey::new_unique(),
+        founder_2: Pubkey::new_unique(),
+        founder_3: Pubkey::new_unique(),
+        founder_4: Pubkey::new_unique(),
+        founder_5: Pubkey::new_unique(),
+        founder_6: Pubkey::new_unique(),
+        founder_7: Pubkey::new_unique(),
+        founder_8: Pubkey::new_unique(),
+    };
+    program_test.add_account(
+        allovr_state(),
+        legacy_account(&legacy_state, LEGACY_STATE_SIZE),
+    );
+
+    let mut bench = Bench::start(program_test).await;
+    let program_id = bench.program_id;
+    let payer = bench.context.payer.pubkey();
+
+    let ix = migrate_account(&program_id, &payer, &allovr_state());
+    bench.measure("MigrateAccount (state)", ix, &[]).await;
+
+    let state: AllovrTokenState = bench.load(&allovr_state()).await;
+    assert!(state.minted);
+    assert_eq!(state.dao_authority, Pubkey::default());
+
+    let ix = migrate_artist_metadata(&program_id, &payer, &artist.pubkey());
+    bench
+        .measure("MigrateAccount (artist metadata)", ix, &[])
+        .await;
+
+    let ix = migrate_account(&program_id, &payer, &artwork_meta);
+    bench
+        .measure("MigrateAccount (artwork metadata)", ix, &[])
+        .await;
+
+    let metadata: ArtistMetadata = bench.load(&artist_meta).await;
+    assert_eq!(
+        metadata.bump,
+        find_artist_metadata(&program_id, &artist_token_mint).1
+    );
+
+    let artwork: ArtworkMetadata = bench.load(&artwork_meta).await;
+    assert_eq!(
+        artwork.bump,
+        find_artwork_metadata(&program_id, &artwork_mint).1
+    );
+
+    bench.finish();
+}
+
+/// Program account without a header, as written before account headers
+fn legacy_account<T: BorshSerialize>(body: &T, size: usize) -> Account {
+    let mut data = vec![0; size];
+    body.serialize(&mut &mut data[..]).unwrap();
+
+    account(data, program_id())
+}
@@ -0,0 +1,1132 @@
+//! Instructions run end to end against the BPF build of the program, `cargo test-bpf` builds it
+//! and enables these tests.
+
+#![cfg(feature = "test-bpf")]
+
+mod common;
+
+mod create_artwork_test {
+    use crate::common::*;
+    use mpl_token_metadata::pda::{find_master_edition_account, find_metadata_account};
+    use ovr_program::instruction::{create_artwork, CreateArtworkArgs};
+    use ovr_program::pda::find_artwork_mint;
+    use ovr_program::{ALLOVR_AOVR_TREASURY_ID, ALLOVR_MINT_ID};
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+    use spl_associated_token_account::get_associated_token_address;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = metaplex_program_test();
+        let artist = add_wallet(&mut program_test, 2_000 * AOVR);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        let args = CreateArtworkArgs {
+            symbol: "ART1".to_string(),
+            description: "An artwork".to_string(),
+            name: "Artwork".to_string(),
+            uri: "https://allovr.io/artwork.json".to_string(),
+            seller_fee_basis_points: 500,
+            collection_symbol: None,
+            max_editions: 0,
+            content_uri: "ipfs://content".to_string(),
+            content_hash: [1; 32],
+            creators: vec![],
+        };
+        let ix = create_artwork(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artwork_mint = find_artwork_mint(&program_id, "ART1", &artist.pubkey()).0;
+        let artwork = test.artwork(&artwork_mint).await;
+        assert_eq!(artwork.artist, artist.pubkey());
+        assert_eq!(artwork.holder, artist.pubkey());
+        assert_eq!(artwork.content_hash, [1; 32]);
+        assert!(!artwork.primary_sale_happened);
+
+        // the artwork token went to the artist's new ATA, the fee to the treasury
+        let artist_artwork_ata = get_associated_token_address(&artist.pubkey(), &artwork_mint);
+        assert_eq!(test.token_amount(&artist_artwork_ata).await, 1);
+
+        let aovr_mint = Pubkey::from_str(ALLOVR_MINT_ID).unwrap();
+        let artist_aovr_ata = get_associated_token_address(&artist.pubkey(), &aovr_mint);
+        assert_eq!(test.token_amount(&artist_aovr_ata).await, 1_000 * AOVR);
+        let treasury = Pubkey::from_str(ALLOVR_AOVR_TREASURY_ID).unwrap();
+        assert_eq!(test.token_amount(&treasury).await, 1_000 * AOVR);
+
+        let metaplex_meta = test.account(&find_metadata_account(&artwork_mint).0).await;
+        assert_eq!(metaplex_meta.owner, mpl_token_metadata::ID);
+        let master_edition = test
+            .account(&find_master_edition_account(&artwork_mint).0)
+            .await;
+        assert_eq!(master_edition.owner, mpl_token_metadata::ID);
+    }
+}
+
+mod resale_after_gift_test {
+    use crate::common::*;
+    use ovr_program::instruction::{
+        accept_artwork_sale, init_artwork_sale, transfer_artwork, AcceptArtworkSaleArgs,
+    };
+    use solana_program::program_pack::Pack;
+    use solana_program::rent::Rent;
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 0);
+        let collector = add_wallet(&mut program_test, 0);
+        let buyer = add_wallet(&mut program_test, 0);
+        let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+        add_artwork_account(&mut program_test, &buyer.pubkey(), &artwork_mint);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        // a gift to a non-creator means the artist has already let the artwork go
+        let ix = transfer_artwork(
+            &program_id,
+            &artist.pubkey(),
+            &collector.pubkey(),
+            &artwork_mint,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert!(test.artwork(&artwork_mint).await.primary_sale_happened);
+
+        let artwork = test.artwork(&artwork_mint).await;
+        let args = sale_args(&buyer.pubkey(), &collector.pubkey());
+        let ix = init_artwork_sale(&program_id, &collector.pubkey(), &artwork, args);
+        test.process(&[ix], &[&collector]).await.unwrap();
+
+        let artist_lamports = test.account(&artist.pubkey()).await.lamports;
+        let collector_lamports = test.account(&collector.pubkey()).await.lamports;
+
+        let artwork = test.artwork(&artwork_mint).await;
+        let args = AcceptArtworkSaleArgs {
+            max_price: ARTWORK_PRICE,
+        };
+        let ix = accept_artwork_sale(&program_id, &buyer.pubkey(), &artwork, args);
+        test.process(&[ix], &[&buyer]).await.unwrap();
+
+        // the artist only gets the 5% seller fee, the collector the rest and the escrow rent
+        let royalty = ARTWORK_PRICE / 20;
+        let escrow_rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+        assert_eq!(
+            test.account(&artist.pubkey()).await.lamports,
+            artist_lamports + royalty
+        );
+        assert_eq!(
+            test.account(&collector.pubkey()).await.lamports,
+            collector_lamports + ARTWORK_PRICE - royalty + escrow_rent
+        );
+        assert_eq!(test.artwork(&artwork_mint).await.holder, buyer.pubkey());
+    }
+}
+
+mod verify_artist_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        register_artist, revoke_artist_verification, set_dao_authority, verify_artist,
+        SetDaoAuthorityArgs,
+    };
+    use ovr_program::pda::{find_artist_metadata, find_artist_mint};
+    use ovr_program::state::{AllovrTokenState, ArtistMetadata};
+    use solana_program_test::*;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let dao = Keypair::new();
+        add_allovr_state(&mut program_test, &dao.pubkey());
+        let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+        let impostor = add_wallet(&mut program_test, 0);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        let args = register_artist_args("VRFY", false);
+        let ix = register_artist(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artist_token_mint = find_artist_mint(&program_id, &artist.pubkey()).0;
+        let artist_meta = find_artist_metadata(&program_id, &artist_token_mint).0;
+
+        // only the authority recorded in state can verify
+        let ix = verify_artist(&program_id, &impostor.pubkey(), &artist.pubkey());
+        let err = test.process(&[ix], &[&impostor]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidDaoAuthority)
+        );
+
+        let ix = verify_artist(&program_id, &dao.pubkey(), &artist.pubkey());
+        test.process(&[ix], &[&dao]).await.unwrap();
+        assert!(test.load::<ArtistMetadata>(&artist_meta).await.verified);
+
+        // governance hands over to a new key, the old one loses its rights
+        let args = SetDaoAuthorityArgs {
+            dao_authority: impostor.pubkey(),
+        };
+        let ix = set_dao_authority(&program_id, &artist.pubkey(), args.clone());
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidDaoAuthority)
+        );
+
+        let ix = set_dao_authority(&program_id, &dao.pubkey(), args);
+        test.process(&[ix], &[&dao]).await.unwrap();
+        let state: AllovrTokenState = test.load(&allovr_state()).await;
+        assert_eq!(state.dao_authority, impostor.pubkey());
+
+        let ix = revoke_artist_verification(&program_id, &dao.pubkey(), &artist.pubkey());
+        let err = test.process(&[ix], &[&dao]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidDaoAuthority)
+        );
+
+        let ix = revoke_artist_verification(&program_id, &impostor.pubkey(), &artist.pubkey());
+        test.process(&[ix], &[&impostor]).await.unwrap();
+        assert!(!test.load::<ArtistMetadata>(&artist_meta).await.verified);
+    }
+}
+
+mod distribute_artist_community_tokens_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        distribute_artist_community_tokens, register_artist, DistributeArtistCommunityTokensArgs,
+    };
+    use ovr_program::pda::{find_artist_community, find_artist_mint};
+    use solana_program_test::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let dao = Keypair::new();
+        add_allovr_state(&mut program_test, &dao.pubkey());
+        let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+        let recipient = add_wallet(&mut program_test, 0);
+
+        let artist_token_mint = find_artist_mint(&program_id(), &artist.pubkey()).0;
+        let recipient_ata = get_associated_token_address(&recipient.pubkey(), &artist_token_mint);
+        program_test.add_account(
+            recipient_ata,
+            token_account(artist_token_mint, recipient.pubkey(), 0),
+        );
+
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        let args = register_artist_args("CMTY", false);
+        let ix = register_artist(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        // 30% of the 1 000 000 artist tokens went to the community treasury
+        let artist_community = find_artist_community(&program_id, &artist_token_mint).0;
+        let treasury_ata = get_associated_token_address(&artist_community, &artist_token_mint);
+        assert_eq!(test.token_amount(&treasury_ata).await, 300_000);
+
+        let args = DistributeArtistCommunityTokensArgs { amount: 1_000 };
+        let ix = distribute_artist_community_tokens(
+            &program_id,
+            &artist.pubkey(),
+            &artist.pubkey(),
+            &recipient.pubkey(),
+            args.clone(),
+        );
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidDaoAuthority)
+        );
+
+        let ix = distribute_artist_community_tokens(
+            &program_id,
+            &dao.pubkey(),
+            &artist.pubkey(),
+            &recipient.pubkey(),
+            args,
+        );
+        test.process(&[ix], &[&dao]).await.unwrap();
+        assert_eq!(test.token_amount(&recipient_ata).await, 1_000);
+        assert_eq!(test.token_amount(&treasury_ata).await, 299_000);
+
+        let args = DistributeArtistCommunityTokensArgs { amount: 300_000 };
+        let ix = distribute_artist_community_tokens(
+            &program_id,
+            &dao.pubkey(),
+            &artist.pubkey(),
+            &recipient.pubkey(),
+            args,
+        );
+        let err = test.process(&[ix], &[&dao]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InsufficientCommunityTokens)
+        );
+    }
+}
+
+mod transfer_artwork_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        cancel_artwork_sale, init_artwork_sale, sync_artwork_holder, transfer_artwork,
+    };
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 0);
+        let collector = add_wallet(&mut program_test, 0);
+        let buyer = add_wallet(&mut program_test, 0);
+        let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+        add_artwork_account(&mut program_test, &buyer.pubkey(), &artwork_mint);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        // an artwork in escrow can neither be transferred nor synced
+        let artwork = test.artwork(&artwork_mint).await;
+        let args = sale_args(&buyer.pubkey(), &artist.pubkey());
+        let ix = init_artwork_sale(&program_id, &artist.pubkey(), &artwork, args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let ix = transfer_artwork(
+            &program_id,
+            &artist.pubkey(),
+            &collector.pubkey(),
+            &artwork_mint,
+        );
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkUnderOffer)
+        );
+
+        let ix = sync_artwork_holder(&program_id, &artist.pubkey(), &artwork_mint);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkUnderOffer)
+        );
+
+        let artwork = test.artwork(&artwork_mint).await;
+        let ix = cancel_artwork_sale(&program_id, &artist.pubkey(), &artwork);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        // the collector has no artwork ATA yet, the transfer creates it
+        let ix = transfer_artwork(
+            &program_id,
+            &artist.pubkey(),
+            &collector.pubkey(),
+            &artwork_mint,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let collector_ata = get_associated_token_address(&collector.pubkey(), &artwork_mint);
+        assert_eq!(test.token_amount(&collector_ata).await, 1);
+        assert_eq!(test.artwork(&artwork_mint).await.holder, collector.pubkey());
+
+        // a plain token transfer leaves the metadata behind until it is synced
+        let buyer_ata = get_associated_token_address(&buyer.pubkey(), &artwork_mint);
+        let ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &collector_ata,
+            &buyer_ata,
+            &collector.pubkey(),
+            &[],
+            1,
+        )
+        .unwrap();
+        test.process(&[ix], &[&collector]).await.unwrap();
+        assert_eq!(test.artwork(&artwork_mint).await.holder, collector.pubkey());
+
+        let ix = sync_artwork_holder(&program_id, &collector.pubkey(), &artwork_mint);
+        let err = test.process(&[ix], &[]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::IncorrectArtworkHolder)
+        );
+
+        let ix = sync_artwork_holder(&program_id, &buyer.pubkey(), &artwork_mint);
+        test.process(&[ix], &[]).await.unwrap();
+        let artwork = test.artwork(&artwork_mint).await;
+        assert_eq!(artwork.holder, buyer.pubkey());
+        assert!(artwork.primary_sale_happened);
+    }
+}
+
+mod create_artist_collection_test {
+    use crate::common::*;
+    use mpl_token_metadata::pda::find_metadata_account;
+    use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        create_artist_collection, create_artwork, register_artist, CreateArtistCollectionArgs,
+        CreateArtworkArgs,
+    };
+    use ovr_program::pda::{find_artist_collection_mint, find_artist_mint, find_artwork_mint};
+    use solana_program_test::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    fn collection_args(symbol: &str) -> CreateArtistCollectionArgs {
+        CreateArtistCollectionArgs {
+            symbol: symbol.to_string(),
+            name: "Collection".to_string(),
+            uri: "https://allovr.io/collection.json".to_string(),
+        }
+    }
+
+    fn artwork_args(symbol: &str, collection_symbol: &str) -> CreateArtworkArgs {
+        CreateArtworkArgs {
+            symbol: symbol.to_string(),
+            description: "An artwork in a collection".to_string(),
+            name: "Artwork".to_string(),
+            uri: "https://allovr.io/artwork.json".to_string(),
+            seller_fee_basis_points: 500,
+            collection_symbol: Some(collection_symbol.to_string()),
+            max_editions: 0,
+            content_uri: "ipfs://content".to_string(),
+            content_hash: [1; 32],
+            creators: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = metaplex_program_test();
+        let dao = Keypair::new();
+        add_allovr_state(&mut program_test, &dao.pubkey());
+        let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+        let fan = add_wallet(&mut program_test, 0);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        // only registered artists have a token mint to hang collections off
+        let ix = create_artist_collection(&program_id, &fan.pubkey(), collection_args("COLL"));
+        let err = test.process(&[ix], &[&fan]).await.unwrap_err();
+        assert_eq!(err.unwrap(), program_error(0, AllovrError::IncorrectOwner));
+
+        let args = register_artist_args("COLA", true);
+        let ix = register_artist(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let ix = create_artist_collection(&program_id, &artist.pubkey(), collection_args(" "));
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidCollectionSymbol)
+        );
+
+        let ix = create_artist_collection(&program_id, &artist.pubkey(), collection_args("COLL"));
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artist_token_mint = find_artist_mint(&program_id, &artist.pubkey()).0;
+        let collection_mint =
+            find_artist_collection_mint(&program_id, "COLL", &artist_token_mint).0;
+        let artist_collection_ata =
+            get_associated_token_address(&artist.pubkey(), &collection_mint);
+        assert_eq!(test.token_amount(&artist_collection_ata).await, 1);
+
+        // the collection mint is a PDA, a second collection with the symbol cannot be created
+        let ix = create_artist_collection(&program_id, &artist.pubkey(), collection_args("COLL"));
+        assert!(test.process(&[ix], &[&artist]).await.is_err());
+
+        // artworks can only join a collection the artist has created
+        let ix = create_artwork(&program_id, &artist.pubkey(), artwork_args("ART1", "NONE"));
+        assert!(test.process(&[ix], &[&artist]).await.is_err());
+
+        let ix = create_artwork(&program_id, &artist.pubkey(), artwork_args("ART1", "COLL"));
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artwork_mint = find_artwork_mint(&program_id, "ART1", &artist.pubkey()).0;
+        let metaplex_meta = test.account(&find_metadata_account(&artwork_mint).0).await;
+        let metaplex_meta: Metadata = Metadata::safe_deserialize(&metaplex_meta.data).unwrap();
+        let collection = metaplex_meta.collection.unwrap();
+        assert_eq!(collection.key, collection_mint);
+        assert!(collection.verified);
+    }
+}
+
+mod mint_artwork_print_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        accept_artwork_sale, create_artwork, init_artwork_sale, mint_artwork_print,
+        AcceptArtworkSaleArgs, CreateArtworkArgs,
+    };
+    use ovr_program::pda::{find_artwork_mint, find_artwork_print_mint};
+    use ovr_program::state::ArtworkPrint;
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = metaplex_program_test();
+        let artist = add_wallet(&mut program_test, 2_000 * AOVR);
+        let buyer = add_wallet(&mut program_test, 0);
+        let artwork_mint = find_artwork_mint(&program_id(), "ART1", &artist.pubkey()).0;
+        let print_mint = find_artwork_print_mint(&program_id(), &artwork_mint, 1).0;
+        add_artwork_account(&mut program_test, &buyer.pubkey(), &print_mint);
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        let args = CreateArtworkArgs {
+            symbol: "ART1".to_string(),
+            description: "A limited edition artwork".to_string(),
+            name: "Artwork".to_string(),
+            uri: "https://allovr.io/artwork.json".to_string(),
+            seller_fee_basis_points: 500,
+            collection_symbol: None,
+            max_editions: 1,
+            content_uri: "ipfs://content".to_string(),
+            content_hash: [1; 32],
+            creators: vec![],
+        };
+        let ix = create_artwork(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let master = test.artwork(&artwork_mint).await;
+        let ix = mint_artwork_print(&program_id, &artist.pubkey(), &master);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        assert_eq!(test.artwork(&artwork_mint).await.editions_printed, 1);
+        let print = test.artwork(&print_mint).await;
+        assert_eq!(
+            print.print,
+            Some(ArtworkPrint {
+                parent: artwork_mint,
+                edition: 1,
+            })
+        );
+        assert_eq!(print.holder, artist.pubkey());
+        let artist_print_ata = get_associated_token_address(&artist.pubkey(), &print_mint);
+        assert_eq!(test.token_amount(&artist_print_ata).await, 1);
+
+        let master = test.artwork(&artwork_mint).await;
+        let ix = mint_artwork_print(&program_id, &artist.pubkey(), &master);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::MaxEditionsReached)
+        );
+
+        let ix = mint_artwork_print(&program_id, &artist.pubkey(), &print);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(err.unwrap(), program_error(0, AllovrError::ArtworkIsPrint));
+
+        // prints trade like any other artwork, with their own escrow
+        let args = sale_args(&buyer.pubkey(), &artist.pubkey());
+        let ix = init_artwork_sale(&program_id, &artist.pubkey(), &print, args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let print = test.artwork(&print_mint).await;
+        let args = AcceptArtworkSaleArgs {
+            max_price: ARTWORK_PRICE,
+        };
+        let ix = accept_artwork_sale(&program_id, &buyer.pubkey(), &print, args);
+        test.process(&[ix], &[&buyer]).await.unwrap();
+
+        let buyer_print_ata = get_associated_token_address(&buyer.pubkey(), &print_mint);
+        assert_eq!(test.token_amount(&buyer_print_ata).await, 1);
+        assert_eq!(test.artwork(&print_mint).await.holder, buyer.pubkey());
+        assert_eq!(test.artwork(&artwork_mint).await.holder, artist.pubkey());
+    }
+}
+
+mod artwork_swap_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        accept_artwork_swap, cancel_artwork_sale, cancel_artwork_swap, init_artwork_sale,
+        propose_artwork_swap, ProposeArtworkSwapArgs,
+    };
+    use ovr_program::pda::find_artwork_swap;
+    use ovr_program::state::{ArtworkSwap, ArtworkSwapTopUp, ArtworkSwapTopUpPayer, Currency};
+    use ovr_program::ARTWORK_SWAP_SIZE;
+    use solana_program::program_pack::Pack;
+    use solana_program::rent::Rent;
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 1_000 * AOVR);
+        let other_artist = add_wallet(&mut program_test, 0);
+        let fan = add_wallet(&mut program_test, 0);
+        let offered_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+        let requested_mint = add_artwork(&mut program_test, &other_artist.pubkey(), "ART1");
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+        let swap_address = find_artwork_swap(&program_id, &offered_mint, &requested_mint).0;
+        let artist_offered_ata = get_associated_token_address(&artist.pubkey(), &offered_mint);
+
+        let offered = test.artwork(&offered_mint).await;
+        let args = ProposeArtworkSwapArgs { top_up: None };
+        let ix = propose_artwork_swap(
+            &program_id,
+            &artist.pubkey(),
+            &offered,
+            &requested_mint,
+            args,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(test.token_amount(&artist_offered_ata).await, 0);
+
+        // the escrowed artwork can only leave through the swap instructions
+        let offered = test.artwork(&offered_mint).await;
+        let args = sale_args(&fan.pubkey(), &artist.pubkey());
+        let ix = init_artwork_sale(&program_id, &artist.pubkey(), &offered, args);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkUnderOffer)
+        );
+
+        let ix = cancel_artwork_sale(&program_id, &artist.pubkey(), &offered);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidArtworkSwap)
+        );
+
+        let swap: ArtworkSwap = test.load(&swap_address).await;
+        let ix = accept_artwork_swap(&program_id, &fan.pubkey(), &swap, &offered);
+        let err = test.process(&[ix], &[&fan]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::IncorrectArtworkHolder)
+        );
+
+        let ix = cancel_artwork_swap(&program_id, &artist.pubkey(), &swap, &offered);
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(test.token_amount(&artist_offered_ata).await, 1);
+        assert!(test.is_closed(&swap_address).await);
+        assert_eq!(test.artwork(&offered_mint).await.swap, None);
+
+        // an AOVR top-up from the proposer waits in the swap's ATA and is refunded on cancel
+        let artist_aovr_ata = get_associated_token_address(&artist.pubkey(), &aovr_mint());
+        let swap_aovr_ata = get_associated_token_address(&swap_address, &aovr_mint());
+        let offered = test.artwork(&offered_mint).await;
+        let args = ProposeArtworkSwapArgs {
+            top_up: Some(ArtworkSwapTopUp {
+                amount: 100 * AOVR,
+                currency: Currency::AOVR,
+                payment_account: artist_aovr_ata,
+                payer: ArtworkSwapTopUpPayer::Proposer,
+            }),
+        };
+        let ix = propose_artwork_swap(
+            &program_id,
+            &artist.pubkey(),
+            &offered,
+            &requested_mint,
+            args,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(test.token_amount(&artist_aovr_ata).await, 900 * AOVR);
+        assert_eq!(test.token_amount(&swap_aovr_ata).await, 100 * AOVR);
+
+        let offered = test.artwork(&offered_mint).await;
+        let swap: ArtworkSwap = test.load(&swap_address).await;
+        let ix = cancel_artwork_swap(&program_id, &artist.pubkey(), &swap, &offered);
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(test.token_amount(&artist_aovr_ata).await, 1_000 * AOVR);
+        assert!(test.is_closed(&swap_aovr_ata).await);
+
+        // a SOL top-up from the proposer is held by the swap account until accepted
+        let offered = test.artwork(&offered_mint).await;
+        let args = ProposeArtworkSwapArgs {
+            top_up: Some(ArtworkSwapTopUp {
+                amount: ARTWORK_PRICE,
+                currency: Currency::SOL,
+                payment_account: artist.pubkey(),
+                payer: ArtworkSwapTopUpPayer::Proposer,
+            }),
+        };
+        let ix = propose_artwork_swap(
+            &program_id,
+            &artist.pubkey(),
+            &offered,
+            &requested_mint,
+            args,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(
+            test.account(&swap_address).await.lamports,
+            Rent::default().minimum_balance(ARTWORK_SWAP_SIZE) + ARTWORK_PRICE
+        );
+
+        let other_lamports = test.account(&other_artist.pubkey()).await.lamports;
+        let offered = test.artwork(&offered_mint).await;
+        let swap: ArtworkSwap = test.load(&swap_address).await;
+        let ix = accept_artwork_swap(&program_id, &other_artist.pubkey(), &swap, &offered);
+        test.process(&[ix], &[&other_artist]).await.unwrap();
+
+        // the acceptor funded both new artwork ATAs
+        let ata_rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+        assert_eq!(
+            test.account(&other_artist.pubkey()).await.lamports,
+            other_lamports + ARTWORK_PRICE - 2 * ata_rent
+        );
+        assert!(test.is_closed(&swap_address).await);
+
+        let other_offered_ata = get_associated_token_address(&other_artist.pubkey(), &offered_mint);
+        let artist_requested_ata = get_associated_token_address(&artist.pubkey(), &requested_mint);
+        assert_eq!(test.token_amount(&other_offered_ata).await, 1);
+        assert_eq!(test.token_amount(&artist_requested_ata).await, 1);
+        assert_eq!(
+            test.artwork(&offered_mint).await.holder,
+            other_artist.pubkey()
+        );
+        assert_eq!(test.artwork(&requested_mint).await.holder, artist.pubkey());
+    }
+}
+
+mod artwork_bundle_sale_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        accept_artwork_bundle_sale, cancel_artwork_bundle_sale, cancel_artwork_sale,
+        init_artwork_bundle_sale, AcceptArtworkSaleArgs, InitArtworkBundleSaleArgs,
+    };
+    use ovr_program::pda::find_artwork_bundle;
+    use ovr_program::state::{ArtworkBundle, Currency};
+    use ovr_program::ARTWORK_BUNDLE_SIZE;
+    use solana_program::program_pack::Pack;
+    use solana_program::rent::Rent;
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 0);
+        let fan = add_wallet(&mut program_test, 0);
+        let other_fan = add_wallet(&mut program_test, 0);
+        let artwork_mints = [
+            add_artwork(&mut program_test, &artist.pubkey(), "ART1"),
+            add_artwork(&mut program_test, &artist.pubkey(), "ART2"),
+            add_artwork(&mut program_test, &artist.pubkey(), "ART3"),
+        ];
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+        let bundle_address = find_artwork_bundle(&program_id, &artwork_mints[0]).0;
+
+        let mut artworks = vec![];
+        for artwork_mint in artwork_mints.iter() {
+            artworks.push(test.artwork(artwork_mint).await);
+        }
+        let args = InitArtworkBundleSaleArgs {
+            buyer: Some(fan.pubkey()),
+            amount: 2 * ARTWORK_PRICE,
+            currency: Currency::SOL,
+            payment_account: artist.pubkey(),
+        };
+
+        let ix =
+            init_artwork_bundle_sale(&program_id, &artist.pubkey(), &artworks[..1], args.clone());
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidArtworkBundle)
+        );
+
+        let ix =
+            init_artwork_bundle_sale(&program_id, &artist.pubkey(), &artworks[..2], args.clone());
+        test.process(&[ix], &[&artist]).await.unwrap();
+        let artist_ata = get_associated_token_address(&artist.pubkey(), &artwork_mints[0]);
+        assert_eq!(test.token_amount(&artist_ata).await, 0);
+
+        // bundled artworks only leave escrow together
+        let artwork = test.artwork(&artwork_mints[0]).await;
+        let ix = cancel_artwork_sale(&program_id, &artist.pubkey(), &artwork);
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidArtworkBundle)
+        );
+
+        let bundled = vec![
+            test.artwork(&artwork_mints[2]).await,
+            test.artwork(&artwork_mints[1]).await,
+        ];
+        let ix = init_artwork_bundle_sale(&program_id, &artist.pubkey(), &bundled, args.clone());
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkUnderOffer)
+        );
+
+        let bundle: ArtworkBundle = test.load(&bundle_address).await;
+        let bundled = vec![
+            test.artwork(&artwork_mints[0]).await,
+            test.artwork(&artwork_mints[1]).await,
+        ];
+        let ix = accept_artwork_bundle_sale(
+            &program_id,
+            &other_fan.pubkey(),
+            &bundle,
+            &bundled,
+            AcceptArtworkSaleArgs {
+                max_price: 2 * ARTWORK_PRICE,
+            },
+        );
+        let err = test.process(&[ix], &[&other_fan]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::IncorrectArtworkBuyer)
+        );
+
+        let ix = accept_artwork_bundle_sale(
+            &program_id,
+            &fan.pubkey(),
+            &bundle,
+            &bundled,
+            AcceptArtworkSaleArgs {
+                max_price: ARTWORK_PRICE,
+            },
+        );
+        let err = test.process(&[ix], &[&fan]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::PriceAboveMaxPrice)
+        );
+
+        let ix = cancel_artwork_bundle_sale(&program_id, &artist.pubkey(), &bundled);
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(test.token_amount(&artist_ata).await, 1);
+        assert!(test.is_closed(&bundle_address).await);
+        assert_eq!(test.artwork(&artwork_mints[0]).await.bundle, None);
+
+        // listed again to anyone, the buyer receives both artworks and the artist the price
+        let bundled = vec![
+            test.artwork(&artwork_mints[0]).await,
+            test.artwork(&artwork_mints[1]).await,
+        ];
+        let args = InitArtworkBundleSaleArgs {
+            buyer: None,
+            ..args
+        };
+        let ix = init_artwork_bundle_sale(&program_id, &artist.pubkey(), &bundled, args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artist_lamports = test.account(&artist.pubkey()).await.lamports;
+        let bundle: ArtworkBundle = test.load(&bundle_address).await;
+        let bundled = vec![
+            test.artwork(&artwork_mints[0]).await,
+            test.artwork(&artwork_mints[1]).await,
+        ];
+        let ix = accept_artwork_bundle_sale(
+            &program_id,
+            &fan.pubkey(),
+            &bundle,
+            &bundled,
+            AcceptArtworkSaleArgs {
+                max_price: 2 * ARTWORK_PRICE,
+            },
+        );
+        test.process(&[ix], &[&fan]).await.unwrap();
+
+        let escrow_rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+        let bundle_rent = Rent::default().minimum_balance(ARTWORK_BUNDLE_SIZE);
+        assert_eq!(
+            test.account(&artist.pubkey()).await.lamports,
+            artist_lamports + 2 * ARTWORK_PRICE + 2 * escrow_rent + bundle_rent
+        );
+        assert!(test.is_closed(&bundle_address).await);
+        for artwork_mint in artwork_mints[..2].iter() {
+            let fan_ata = get_associated_token_address(&fan.pubkey(), artwork_mint);
+            assert_eq!(test.token_amount(&fan_ata).await, 1);
+            assert_eq!(test.artwork(artwork_mint).await.holder, fan.pubkey());
+        }
+        assert_eq!(
+            test.artwork(&artwork_mints[2]).await.holder,
+            artist.pubkey()
+        );
+    }
+}
+
+mod artwork_fraction_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        buyout_artwork, claim_fraction_proceeds, fractionalise_artwork, redeem_artwork,
+        transfer_artwork, AcceptArtworkSaleArgs, FractionaliseArtworkArgs,
+    };
+    use ovr_program::pda::{find_artwork_fraction_mint, find_artwork_vault};
+    use ovr_program::state::{ArtworkVault, ArtworkVaultState, Currency};
+    use ovr_program::ARTWORK_VAULT_SIZE;
+    use solana_program::pubkey::Pubkey;
+    use solana_program::rent::Rent;
+    use solana_program_test::*;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+    use spl_associated_token_account::instruction::create_associated_token_account;
+
+    const FRACTIONS: u64 = 100;
+
+    fn fraction_args(fraction_supply: u64) -> FractionaliseArtworkArgs {
+        FractionaliseArtworkArgs {
+            fraction_supply,
+            reserve_price: ARTWORK_PRICE,
+            currency: Currency::SOL,
+        }
+    }
+
+    async fn send_fractions(
+        test: &mut TestContext,
+        fraction_mint: &Pubkey,
+        from: &Keypair,
+        to: &Pubkey,
+        amount: u64,
+    ) {
+        let ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &get_associated_token_address(&from.pubkey(), fraction_mint),
+            &get_associated_token_address(to, fraction_mint),
+            &from.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        test.process(&[ix], &[from]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 0);
+        let collector = add_wallet(&mut program_test, 0);
+        let fan = add_wallet(&mut program_test, 0);
+        let buyer = add_wallet(&mut program_test, 0);
+        let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+        let vault_address = find_artwork_vault(&program_id, &artwork_mint).0;
+        let fraction_mint = find_artwork_fraction_mint(&program_id, &artwork_mint).0;
+        let collector_fractions = get_associated_token_address(&collector.pubkey(), &fraction_mint);
+
+        // after the primary sale only the royalty goes to the artist on a buyout
+        let ix = transfer_artwork(
+            &program_id,
+            &artist.pubkey(),
+            &collector.pubkey(),
+            &artwork_mint,
+        );
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let ix = fractionalise_artwork(
+            &program_id,
+            &collector.pubkey(),
+            &artwork_mint,
+            fraction_args(0),
+        );
+        let err = test.process(&[ix], &[&collector]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InvalidArtworkVault)
+        );
+
+        let ix = fractionalise_artwork(
+            &program_id,
+            &artist.pubkey(),
+            &artwork_mint,
+            fraction_args(FRACTIONS),
+        );
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::IncorrectArtworkHolder)
+        );
+
+        let ix = fractionalise_artwork(
+            &program_id,
+            &collector.pubkey(),
+            &artwork_mint,
+            fraction_args(FRACTIONS),
+        );
+        test.process(&[ix], &[&collector]).await.unwrap();
+        assert_eq!(test.token_amount(&collector_fractions).await, FRACTIONS);
+        assert_eq!(test.artwork(&artwork_mint).await.holder, vault_address);
+
+        let ix = create_associated_token_account(&fan.pubkey(), &fan.pubkey(), &fraction_mint);
+        test.process(&[ix], &[&fan]).await.unwrap();
+        send_fractions(&mut test, &fraction_mint, &collector, &fan.pubkey(), 25).await;
+
+        // redeeming needs every fraction, once they are back the vault closes
+        let ix = redeem_artwork(&program_id, &collector.pubkey(), &artwork_mint);
+        let err = test.process(&[ix], &[&collector]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::InsufficientFractions)
+        );
+
+        send_fractions(&mut test, &fraction_mint, &fan, &collector.pubkey(), 25).await;
+        let ix = redeem_artwork(&program_id, &collector.pubkey(), &artwork_mint);
+        test.process(&[ix], &[&collector]).await.unwrap();
+        assert!(test.is_closed(&vault_address).await);
+        assert_eq!(test.token_amount(&collector_fractions).await, 0);
+        assert_eq!(test.artwork(&artwork_mint).await.holder, collector.pubkey());
+
+        // fractionalised again, the burnt fraction mint is reused
+        let ix = fractionalise_artwork(
+            &program_id,
+            &collector.pubkey(),
+            &artwork_mint,
+            fraction_args(FRACTIONS),
+        );
+        test.process(&[ix], &[&collector]).await.unwrap();
+        send_fractions(&mut test, &fraction_mint, &collector, &fan.pubkey(), 25).await;
+
+        let vault: ArtworkVault = test.load(&vault_address).await;
+        let artwork = test.artwork(&artwork_mint).await;
+        let ix = buyout_artwork(
+            &program_id,
+            &buyer.pubkey(),
+            &artwork,
+            &vault,
+            AcceptArtworkSaleArgs {
+                max_price: ARTWORK_PRICE - 1,
+            },
+        );
+        let err = test.process(&[ix], &[&buyer]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::PriceAboveMaxPrice)
+        );
+
+        let artist_lamports = test.account(&artist.pubkey()).await.lamports;
+        let ix = buyout_artwork(
+            &program_id,
+            &buyer.pubkey(),
+            &artwork,
+            &vault,
+            AcceptArtworkSaleArgs {
+                max_price: ARTWORK_PRICE,
+            },
+        );
+        test.process(&[ix], &[&buyer]).await.unwrap();
+
+        let royalty = ARTWORK_PRICE * 500 / 10000;
+        assert_eq!(
+            test.account(&artist.pubkey()).await.lamports,
+            artist_lamports + royalty
+        );
+        let buyer_ata = get_associated_token_address(&buyer.pubkey(), &artwork_mint);
+        assert_eq!(test.token_amount(&buyer_ata).await, 1);
+        assert_eq!(test.artwork(&artwork_mint).await.holder, buyer.pubkey());
+        let vault: ArtworkVault = test.load(&vault_address).await;
+        assert_eq!(vault.state, ArtworkVaultState::BoughtOut);
+        assert_eq!(vault.proceeds, ARTWORK_PRICE - royalty);
+
+        let ix = redeem_artwork(&program_id, &collector.pubkey(), &artwork_mint);
+        let err = test.process(&[ix], &[&collector]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkVaultNotActive)
+        );
+
+        // fraction holders claim pro rata, the last claim takes the rest and closes the vault
+        let fan_lamports = test.account(&fan.pubkey()).await.lamports;
+        let ix = claim_fraction_proceeds(&program_id, &fan.pubkey(), &vault);
+        test.process(&[ix], &[&fan]).await.unwrap();
+        let fan_payout = vault.proceeds * 25 / FRACTIONS;
+        assert_eq!(
+            test.account(&fan.pubkey()).await.lamports,
+            fan_lamports + fan_payout
+        );
+
+        let collector_lamports = test.account(&collector.pubkey()).await.lamports;
+        let ix = claim_fraction_proceeds(&program_id, &collector.pubkey(), &vault);
+        test.process(&[ix], &[&collector]).await.unwrap();
+        let vault_rent = Rent::default().minimum_balance(ARTWORK_VAULT_SIZE);
+        assert_eq!(
+            test.account(&collector.pubkey()).await.lamports,
+            collector_lamports + vault.proceeds - fan_payout + vault_rent
+        );
+        assert_eq!(test.token_amount(&collector_fractions).await, 0);
+        assert!(test.is_closed(&vault_address).await);
+    }
+}
+
+mod deregister_artist_test {
+    use crate::common::*;
+    use ovr_program::error::AllovrError;
+    use ovr_program::instruction::{
+        cancel_artwork_sale, deregister_artist, init_artwork_sale, register_artist,
+    };
+    use ovr_program::pda::{
+        find_artist_market, find_artist_metadata, find_artist_mint, find_artist_symbol,
+    };
+    use ovr_program::state::{ArtistMarket, ArtistMetadata};
+    use solana_program_test::*;
+    use solana_sdk::signature::Signer;
+
+    #[tokio::test]
+    async fn test() {
+        let mut program_test = program_test();
+        let artist = add_wallet(&mut program_test, 20_000 * AOVR);
+        let buyer = add_wallet(&mut program_test, 0);
+        let artwork_mint = add_artwork(&mut program_test, &artist.pubkey(), "ART1");
+        let mut test = TestContext::start(program_test).await;
+        let program_id = test.program_id;
+
+        let args = register_artist_args("DRGR", false);
+        let ix = register_artist(&program_id, &artist.pubkey(), args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+
+        let artist_token_mint = find_artist_mint(&program_id, &artist.pubkey()).0;
+        let artist_meta = find_artist_metadata(&program_id, &artist_token_mint).0;
+        let artist_market = find_artist_market(&program_id, &artist_token_mint).0;
+        let artist_symbol = find_artist_symbol(&program_id, "DRGR").0;
+
+        // an open offer on one of the artist's artworks blocks deregistration
+        let artwork = test.artwork(&artwork_mint).await;
+        let args = sale_args(&buyer.pubkey(), &artist.pubkey());
+        let ix = init_artwork_sale(&program_id, &artist.pubkey(), &artwork, args);
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(
+            test.load::<ArtistMetadata>(&artist_meta).await.open_offers,
+            1
+        );
+
+        let ix = deregister_artist(&program_id, &artist.pubkey(), "DRGR");
+        let err = test.process(&[ix], &[&artist]).await.unwrap_err();
+        assert_eq!(
+            err.unwrap(),
+            program_error(0, AllovrError::ArtworkUnderOffer)
+        );
+
+        let artwork = test.artwork(&artwork_mint).await;
+        let ix = cancel_artwork_sale(&program_id, &artist.pubkey(), &artwork);
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert_eq!(
+            test.load::<ArtistMetadata>(&artist_meta).await.open_offers,
+            0
+        );
+
+        // the artist accounts close, the market is kept but marked inactive
+        let ix = deregister_artist(&program_id, &artist.pubkey(), "DRGR");
+        test.process(&[ix], &[&artist]).await.unwrap();
+        assert!(test.is_closed(&artist_meta).await);
+        assert!(test.is_closed(&artist_symbol).await);
+        assert!(!test.load::<ArtistMarket>(&artist_market).await.active);
+    }
+}
@@ -11,3 +11,832 @@ mod ui_amount_to_amount_test {
         assert_eq!(ui_amount_to_amount(0.00000000711), 7);
     }
 }
+
+mod santitise_artwork_data_test {
+    use ovr_program::instruction::CreateArtworkArgs;
+    use ovr_program::utils::santitise_artwork_data;
+    use solana_program_test::*;
+
+    fn args() -> CreateArtworkArgs {
+        CreateArtworkArgs {
+            symbol: " ABCD ".to_string(),
+            description: " A description ".to_string(),
+            name: "An artwork".to_string(),
+            uri: "https://arweave.net/metadata".to_string(),
+            seller_fee_basis_points: 500,
+            collection_symbol: None,
+            max_editions: 0,
+            content_uri: " ipfs://content ".to_string(),
+            content_hash: [1; 32],
+            creators: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let data = santitise_artwork_data(args()).unwrap();
+        assert_eq!(data.symbol, "ABCD");
+        assert_eq!(data.description, "A description");
+        assert_eq!(data.content_uri, "ipfs://content");
+
+        let mut missing_hash = args();
+        missing_hash.content_hash = [0; 32];
+        assert!(santitise_artwork_data(missing_hash).is_err());
+
+        let mut missing_content_uri = args();
+        missing_content_uri.content_uri = "  ".to_string();
+        assert!(santitise_artwork_data(missing_content_uri).is_err());
+
+        let mut long_content_uri = args();
+        long_content_uri.content_uri = "a".repeat(201);
+        assert!(santitise_artwork_data(long_content_uri).is_err());
+    }
+}
+
+mod calculate_creator_payouts_test {
+    use ovr_program::state::ArtworkCreator;
+    use ovr_program::utils::calculate_creator_payouts;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let creators = vec![
+            ArtworkCreator {
+                address: Pubkey::new_unique(),
+                share: 60,
+            },
+            ArtworkCreator {
+                address: Pubkey::new_unique(),
+                share: 40,
+            },
+        ];
+
+        // primary sale splits the whole price
+        assert_eq!(
+            calculate_creator_payouts(1000, &creators, 500, true).unwrap(),
+            vec![600, 400]
+        );
+
+        // secondary sale splits only the royalty
+        assert_eq!(
+            calculate_creator_payouts(1000, &creators, 500, false).unwrap(),
+            vec![30, 20]
+        );
+
+        assert!(calculate_creator_payouts(1000, &[], 500, true)
+            .unwrap()
+            .is_empty());
+    }
+}
+
+mod dutch_auction_price_test {
+    use ovr_program::state::DutchAuction;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let auction = DutchAuction {
+            start_price: 1000,
+            floor_price: 200,
+            start_time: 100,
+            end_time: 200,
+        };
+
+        assert_eq!(auction.price_at(50), 1000);
+        assert_eq!(auction.price_at(100), 1000);
+        assert_eq!(auction.price_at(150), 600);
+        assert_eq!(auction.price_at(175), 400);
+        assert_eq!(auction.price_at(200), 200);
+        assert_eq!(auction.price_at(1000), 200);
+    }
+}
+
+mod calculate_fraction_payout_test {
+    use ovr_program::utils::calculate_fraction_payout;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        assert_eq!(calculate_fraction_payout(1000, 250, 1000).unwrap(), 250);
+
+        // dust stays with the vault until the last claim
+        assert_eq!(calculate_fraction_payout(100, 1, 3).unwrap(), 33);
+        assert_eq!(calculate_fraction_payout(67, 2, 2).unwrap(), 67);
+
+        assert!(calculate_fraction_payout(100, 0, 3).is_err());
+        assert!(calculate_fraction_payout(100, 4, 3).is_err());
+    }
+}
+
+mod calculate_bonding_curve_cost_test {
+    use ovr_program::state::BondingCurve;
+    use ovr_program::utils::calculate_bonding_curve_cost;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let flat = BondingCurve {
+            base_price: 100,
+            slope: 0,
+        };
+        assert_eq!(
+            calculate_bonding_curve_cost(&flat, 0, 10, true).unwrap(),
+            1000
+        );
+        assert_eq!(
+            calculate_bonding_curve_cost(&flat, 500, 10, false).unwrap(),
+            1000
+        );
+
+        // each token sold adds 1 to the price of the next
+        let curve = BondingCurve {
+            base_price: 100,
+            slope: 1000000,
+        };
+        assert_eq!(
+            calculate_bonding_curve_cost(&curve, 0, 3, true).unwrap(),
+            303
+        );
+        assert_eq!(
+            calculate_bonding_curve_cost(&curve, 10, 1, true).unwrap(),
+            110
+        );
+
+        // buying in parts costs the same as buying at once
+        let whole = calculate_bonding_curve_cost(&curve, 0, 10, true).unwrap();
+        let first = calculate_bonding_curve_cost(&curve, 0, 4, true).unwrap();
+        let rest = calculate_bonding_curve_cost(&curve, 4, 6, true).unwrap();
+        assert_eq!(whole, first + rest);
+
+        // fractional prices round in the reserve's favour
+        let shallow = BondingCurve {
+            base_price: 1,
+            slope: 1,
+        };
+        assert_eq!(
+            calculate_bonding_curve_cost(&shallow, 0, 2, true).unwrap(),
+            3
+        );
+        assert_eq!(
+            calculate_bonding_curve_cost(&shallow, 0, 2, false).unwrap(),
+            2
+        );
+
+        assert!(calculate_bonding_curve_cost(&curve, 0, 0, true).is_err());
+        assert!(calculate_bonding_curve_cost(&curve, u64::MAX, u64::MAX, true).is_err());
+    }
+}
+
+mod artist_revenue_share_test {
+    use ovr_program::state::{ArtistRevenueStake, ArtistRevenueVault};
+    use ovr_program::utils::{
+        accrue_artist_revenue, calculate_revenue_share, calculate_reward_per_token,
+    };
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    fn stake(amount: u64, vault: &ArtistRevenueVault) -> ArtistRevenueStake {
+        ArtistRevenueStake {
+            holder: Pubkey::new_unique(),
+            artist_mint: vault.artist_mint,
+            amount,
+            sol_reward_per_token_paid: vault.sol_reward_per_token,
+            aovr_reward_per_token_paid: vault.aovr_reward_per_token,
+            sol_owed: 0,
+            aovr_owed: 0,
+            bump: 255,
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        assert_eq!(calculate_revenue_share(1000, 2500), 250);
+        assert_eq!(calculate_revenue_share(1000, 0), 0);
+        assert!(calculate_reward_per_token(100, 0).is_err());
+
+        let mut vault = ArtistRevenueVault {
+            artist: Pubkey::new_unique(),
+            artist_mint: Pubkey::new_unique(),
+            share_basis_points: 2500,
+            total_staked: 400,
+            sol_reward_per_token: 0,
+            aovr_reward_per_token: 0,
+            bump: 255,
+        };
+        let mut early = stake(100, &vault);
+        let mut big = stake(300, &vault);
+
+        vault.sol_reward_per_token += calculate_reward_per_token(1000, vault.total_staked).unwrap();
+
+        // a later staker earns nothing from revenue paid in before they staked
+        vault.total_staked += 400;
+        let mut late = stake(400, &vault);
+        vault.aovr_reward_per_token += calculate_reward_per_token(800, vault.total_staked).unwrap();
+
+        accrue_artist_revenue(&mut early, &vault).unwrap();
+        accrue_artist_revenue(&mut big, &vault).unwrap();
+        accrue_artist_revenue(&mut late, &vault).unwrap();
+
+        assert_eq!((early.sol_owed, early.aovr_owed), (250, 100));
+        assert_eq!((big.sol_owed, big.aovr_owed), (750, 300));
+        assert_eq!((late.sol_owed, late.aovr_owed), (0, 400));
+
+        // accruing again without new revenue changes nothing
+        accrue_artist_revenue(&mut early, &vault).unwrap();
+        assert_eq!((early.sol_owed, early.aovr_owed), (250, 100));
+    }
+}
+
+mod artist_token_allocation_test {
+    use ovr_program::instruction::ArtistTokenAllocation;
+    use ovr_program::state::ArtistVesting;
+    use ovr_program::utils::calculate_artist_token_allocation;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let allocation = ArtistTokenAllocation {
+            artist_basis_points: 3333,
+            community_basis_points: 3334,
+            bonding_curve_basis_points: 3333,
+        };
+
+        // rounding dust goes to the community treasury
+        assert_eq!(
+            calculate_artist_token_allocation(1001, &allocation).unwrap(),
+            (333, 335, 333)
+        );
+
+        let short = ArtistTokenAllocation {
+            artist_basis_points: 5000,
+            community_basis_points: 0,
+            bonding_curve_basis_points: 4000,
+        };
+        assert!(calculate_artist_token_allocation(1000, &short).is_err());
+
+        let vesting = ArtistVesting {
+            artist: Pubkey::new_unique(),
+            artist_mint: Pubkey::new_unique(),
+            total: 1000,
+            claimed: 0,
+            start_time: 100,
+            cliff_time: 150,
+            end_time: 300,
+            bump: 255,
+        };
+
+        assert_eq!(vesting.vested_at(149), 0);
+        assert_eq!(vesting.vested_at(150), 250);
+        assert_eq!(vesting.vested_at(200), 500);
+        assert_eq!(vesting.vested_at(300), 1000);
+        assert_eq!(vesting.vested_at(10000), 1000);
+    }
+}
+
+mod verify_merkle_proof_test {
+    use ovr_program::utils::{artist_airdrop_leaf, verify_merkle_proof};
+    use solana_program::keccak;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[&a, &b]).to_bytes()
+        } else {
+            keccak::hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let fans: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = fans
+            .iter()
+            .enumerate()
+            .map(|(i, fan)| artist_airdrop_leaf(i as u64, fan, 100 * (i as u64 + 1)))
+            .collect();
+
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[2], left], root, leaves[3]));
+
+        // wrong amount, wrong claimant or wrong proof
+        assert!(!verify_merkle_proof(
+            &[leaves[1], right],
+            root,
+            artist_airdrop_leaf(0, &fans[0], 1000)
+        ));
+        assert!(!verify_merkle_proof(
+            &[leaves[1], right],
+            root,
+            artist_airdrop_leaf(0, &fans[1], 100)
+        ));
+        assert!(!verify_merkle_proof(&[leaves[2], right], root, leaves[0]));
+    }
+}
+
+mod normalise_artist_symbol_test {
+    use ovr_program::utils::normalise_artist_symbol;
+    use solana_program_test::*;
+    #[tokio::test]
+    async fn test() {
+        assert_eq!(normalise_artist_symbol("ART"), "ART");
+        assert_eq!(normalise_artist_symbol("art"), "ART");
+        assert_eq!(normalise_artist_symbol(" aRt1 "), "ART1");
+    }
+}
+
+mod santitise_update_artist_data_test {
+    use ovr_program::instruction::{ArtistProfileArgs, UpdateArtistArgs};
+    use ovr_program::utils::santitise_update_artist_data;
+    use solana_program_test::*;
+
+    fn args() -> UpdateArtistArgs {
+        UpdateArtistArgs {
+            name: " Artist ".to_string(),
+            description: "An artist".to_string(),
+            token_symbol: "ART ".to_string(),
+            uri: None,
+            create_metaplex_metadata: Some(false),
+            profile: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        // only the details are checked, there is no token set up to send
+        let data = santitise_update_artist_data(args()).unwrap();
+        assert_eq!(data.name, "Artist");
+        assert_eq!(data.token_symbol, "ART");
+
+        let mut bad_symbol = args();
+        bad_symbol.token_symbol = "AR-T".to_string();
+        assert!(santitise_update_artist_data(bad_symbol).is_err());
+
+        let mut bad_profile = args();
+        bad_profile.profile = Some(ArtistProfileArgs {
+            links: vec![],
+            avatar_uri: None,
+            banner_uri: None,
+            genres: vec![],
+            location: Some("x".repeat(65)),
+        });
+        assert!(santitise_update_artist_data(bad_profile).is_err());
+    }
+}
+
+mod santitise_artist_profile_test {
+    use ovr_program::instruction::ArtistProfileArgs;
+    use ovr_program::state::{ArtistLink, ArtistLinkPlatform};
+    use ovr_program::utils::santitise_artist_profile;
+    use solana_program_test::*;
+
+    fn args() -> ArtistProfileArgs {
+        ArtistProfileArgs {
+            links: vec![ArtistLink {
+                platform: ArtistLinkPlatform::Website,
+                uri: " https://artist.example ".to_string(),
+            }],
+            avatar_uri: Some("https://arweave.net/avatar".to_string()),
+            banner_uri: Some("  ".to_string()),
+            genres: vec![" Jazz ".to_string(), "ambient".to_string()],
+            location: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let profile = santitise_artist_profile(args()).unwrap();
+        assert_eq!(profile.links[0].uri, "https://artist.example");
+        assert_eq!(profile.banner_uri, None);
+        assert_eq!(profile.genres, vec!["jazz", "ambient"]);
+
+        let mut duplicate_genre = args();
+        duplicate_genre.genres.push("JAZZ".to_string());
+        assert!(santitise_artist_profile(duplicate_genre).is_err());
+
+        let mut empty_link = args();
+        empty_link.links[0].uri = " ".to_string();
+        assert!(santitise_artist_profile(empty_link).is_err());
+
+        let mut long_location = args();
+        long_location.location = Some("x".repeat(65));
+        assert!(santitise_artist_profile(long_location).is_err());
+    }
+}
+
+mod account_size_test {
+    use ovr_program::*;
+    use solana_program_test::*;
+    #[tokio::test]
+    async fn test() {
+        // MigrateAccount recognises legacy accounts by size, no current layout may share one
+        let current = [
+            STATE_SIZE,
+            ARTIST_METADATA_SIZE,
+            ARTIST_PROFILE_SIZE,
+            ARTIST_SYMBOL_SIZE,
+            ARTIST_VESTING_SIZE,
+            ARTIST_AIRDROP_SIZE,
+            ARTIST_AIRDROP_CLAIM_SIZE,
+            ARTIST_MARKET_SIZE,
+            ARTIST_REVENUE_VAULT_SIZE,
+            ARTIST_REVENUE_STAKE_SIZE,
+            ARTWORK_METADATA_SIZE,
+            ARTWORK_SWAP_SIZE,
+            ARTWORK_BUNDLE_SIZE,
+            ARTWORK_VAULT_SIZE,
+        ];
+
+        for legacy in [
+            LEGACY_STATE_SIZE,
+            LEGACY_ARTIST_METADATA_SIZE,
+            LEGACY_ARTWORK_METADATA_SIZE,
+        ] {
+            assert!(!current.contains(&legacy));
+        }
+
+        // the headed state added the DAO authority
+        assert_eq!(
+            LEGACY_STATE_SIZE
+                + ACCOUNT_HEADER_SIZE
+                + std::mem::size_of::<solana_program::pubkey::Pubkey>(),
+            STATE_SIZE
+        );
+    }
+}
+
+mod validate_accounts_test {
+    use ovr_program::error::AllovrError;
+    use ovr_program::utils::{validate_accounts, AccountConstraint::*};
+    use ovr_program::ALLOVR_PROGRAM_ID;
+    use solana_program::account_info::AccountInfo;
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test() {
+        let program_id = Pubkey::from_str(ALLOVR_PROGRAM_ID).unwrap();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![];
+        let account = AccountInfo::new(
+            &key,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        assert!(validate_accounts(&program_id, &[(&account, &[Signer, ProgramOwned])]).is_ok());
+        assert_eq!(
+            validate_accounts(&program_id, &[(&account, &[Signer, Writable])]),
+            Err(ProgramError::from(AllovrError::AccountNotWritable))
+        );
+        assert_eq!(
+            validate_accounts(&program_id, &[(&account, &[SystemProgram])]),
+            Err(ProgramError::from(AllovrError::InvalidSystemProgramId))
+        );
+        assert_eq!(
+            validate_accounts(&Pubkey::new_unique(), &[(&account, &[Signer])]),
+            Err(ProgramError::from(AllovrError::InvalidProgramId))
+        );
+
+        // the DAO authority comes from state, any other signer or an unset one is rejected
+        assert!(validate_accounts(&program_id, &[(&account, &[DaoAuthority(&key)])]).is_ok());
+        assert_eq!(
+            validate_accounts(
+                &program_id,
+                &[(&account, &[DaoAuthority(&Pubkey::new_unique())])]
+            ),
+            Err(ProgramError::from(AllovrError::InvalidDaoAuthority))
+        );
+        assert_eq!(
+            validate_accounts(
+                &program_id,
+                &[(&account, &[DaoAuthority(&Pubkey::default())])]
+            ),
+            Err(ProgramError::from(AllovrError::InvalidDaoAuthority))
+        );
+    }
+}
+
+mod instruction_builders_test {
+    use ovr_program::instruction::{migrate_account, transfer_artwork, AllovrInstruction};
+    use solana_program::borsh::try_from_slice_unchecked;
+    use solana_program::pubkey::Pubkey;
+    use solana_program::system_program;
+    use solana_program_test::*;
+    use spl_associated_token_account::get_associated_token_address;
+
+    #[tokio::test]
+    async fn test() {
+        let program_id = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let artwork_mint = Pubkey::new_unique();
+
+        let ix = transfer_artwork(&program_id, &holder, &recipient, &artwork_mint);
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.accounts.len(), 10);
+        assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+        assert_eq!(
+            ix.accounts[1].pubkey,
+            get_associated_token_address(&holder, &artwork_mint)
+        );
+        assert_eq!(
+            ix.accounts[3].pubkey,
+            get_associated_token_address(&recipient, &artwork_mint)
+        );
+        let (artwork_meta, _) = Pubkey::find_program_address(
+            &[
+                ovr_program::ARTWORK_METADATA_SEED_PREFIX.as_bytes(),
+                artwork_mint.as_ref(),
+            ],
+            &program_id,
+        );
+        assert_eq!(ix.accounts[5].pubkey, artwork_meta);
+        assert!(!ix.accounts[4].is_writable && ix.accounts[5].is_writable);
+        assert!(matches!(
+            try_from_slice_unchecked::<AllovrInstruction>(&ix.data).unwrap(),
+            AllovrInstruction::TransferArtwork()
+        ));
+
+        let account = Pubkey::new_unique();
+        let ix = migrate_account(&program_id, &holder, &account);
+        assert_eq!(ix.accounts.len(), 3);
+        assert_eq!(ix.accounts[1].pubkey, account);
+        assert_eq!(ix.accounts[2].pubkey, system_program::id());
+        assert!(matches!(
+            try_from_slice_unchecked::<AllovrInstruction>(&ix.data).unwrap(),
+            AllovrInstruction::MigrateAccount()
+        ));
+    }
+}
+
+mod pda_test {
+    use ovr_program::pda::{artwork_mint_seeds, find_artist_symbol, find_artwork_mint};
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let program_id = Pubkey::new_unique();
+        let artist_wallet = Pubkey::new_unique();
+
+        let (artwork_mint, bump) = find_artwork_mint(&program_id, "ART", &artist_wallet);
+        let (expected, expected_bump) = Pubkey::find_program_address(
+            &[
+                ovr_program::ARTWORK_SEED_PREFIX.as_bytes(),
+                b"ART",
+                artist_wallet.as_ref(),
+            ],
+            &program_id,
+        );
+        assert_eq!((artwork_mint, bump), (expected, expected_bump));
+
+        let signer = artwork_mint_seeds("ART", &artist_wallet).with_bump(bump);
+        assert_eq!(
+            Pubkey::create_program_address(&signer.as_slices(), &program_id).unwrap(),
+            artwork_mint
+        );
+
+        assert_eq!(
+            find_artist_symbol(&program_id, "art"),
+            find_artist_symbol(&program_id, " ART ")
+        );
+    }
+}
+
+mod stored_bumps_test {
+    use ovr_program::error::AllovrError;
+    use ovr_program::pda::{find_artist_metadata, find_artist_mint};
+    use ovr_program::state::{ArtistMetadata, ProgramAccount};
+    use ovr_program::ARTIST_METADATA_SIZE;
+    use solana_program::account_info::AccountInfo;
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let program_id = Pubkey::new_unique();
+        let artist_wallet = Pubkey::new_unique();
+        let (artist_token_mint, mint_bump) = find_artist_mint(&program_id, &artist_wallet);
+        let (metadata_key, bump) = find_artist_metadata(&program_id, &artist_token_mint);
+
+        let mut lamports = 0;
+        let mut data = vec![0; ARTIST_METADATA_SIZE];
+        let account = AccountInfo::new(
+            &metadata_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let metadata = ArtistMetadata {
+            name: "Artist".to_string(),
+            description: String::new(),
+            symbol: "ART".to_string(),
+            uri: None,
+            verified: false,
+            bump,
+            mint_bump,
+            open_offers: 0,
+        };
+        metadata.save(&account).unwrap();
+
+        let (loaded, mint) =
+            ArtistMetadata::load_for_artist(&account, &program_id, &artist_wallet).unwrap();
+        assert_eq!(mint, artist_token_mint);
+        assert_eq!((loaded.bump, loaded.mint_bump), (bump, mint_bump));
+
+        assert_eq!(
+            ArtistMetadata::load_for_artist(&account, &program_id, &Pubkey::new_unique()).err(),
+            Some(ProgramError::from(AllovrError::InvalidPda))
+        );
+    }
+}
+
+mod artist_open_offers_test {
+    use ovr_program::error::AllovrError;
+    use ovr_program::pda::{find_artist_metadata, find_artist_mint};
+    use ovr_program::state::{ArtistMetadata, ProgramAccount};
+    use ovr_program::ARTIST_METADATA_SIZE;
+    use solana_program::account_info::AccountInfo;
+    use solana_program::program_error::ProgramError;
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let program_id = Pubkey::new_unique();
+        let artist_wallet = Pubkey::new_unique();
+        let (artist_token_mint, mint_bump) = find_artist_mint(&program_id, &artist_wallet);
+        let (metadata_key, bump) = find_artist_metadata(&program_id, &artist_token_mint);
+
+        let mut lamports = 0;
+        let mut data = vec![0; ARTIST_METADATA_SIZE];
+        let account = AccountInfo::new(
+            &metadata_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let metadata = ArtistMetadata {
+            name: "Artist".to_string(),
+            description: String::new(),
+            symbol: "ART".to_string(),
+            uri: None,
+            verified: false,
+            bump,
+            mint_bump,
+            open_offers: 0,
+        };
+        metadata.save(&account).unwrap();
+
+        let count = |opened| {
+            ArtistMetadata::count_open_offer(&account, &program_id, &artist_wallet, opened)
+        };
+        count(true).unwrap();
+        count(true).unwrap();
+        count(false).unwrap();
+        assert_eq!(
+            ArtistMetadata::load(&account, &program_id)
+                .unwrap()
+                .open_offers,
+            1
+        );
+
+        // offers opened before the count existed leave it at zero when they close
+        count(false).unwrap();
+        count(false).unwrap();
+        assert_eq!(
+            ArtistMetadata::load(&account, &program_id)
+                .unwrap()
+                .open_offers,
+            0
+        );
+
+        // another artist's artwork cannot be counted in this metadata
+        assert_eq!(
+            ArtistMetadata::count_open_offer(&account, &program_id, &Pubkey::new_unique(), true)
+                .err(),
+            Some(ProgramError::from(AllovrError::InvalidPda))
+        );
+
+        // a deregistered artist has no metadata, only the empty artist metadata PDA is accepted
+        let mut empty_lamports = 0;
+        let mut empty_data = vec![];
+        let system_program = Pubkey::default();
+        let empty = AccountInfo::new(
+            &metadata_key,
+            false,
+            true,
+            &mut empty_lamports,
+            &mut empty_data,
+            &system_program,
+            false,
+            0,
+        );
+        ArtistMetadata::count_open_offer(&empty, &program_id, &artist_wallet, false).unwrap();
+
+        let other_key = Pubkey::new_unique();
+        let mut other_lamports = 0;
+        let mut other_data = vec![];
+        let other = AccountInfo::new(
+            &other_key,
+            false,
+            true,
+            &mut other_lamports,
+            &mut other_data,
+            &system_program,
+            false,
+            0,
+        );
+        assert_eq!(
+            ArtistMetadata::count_open_offer(&other, &program_id, &artist_wallet, true).err(),
+            Some(ProgramError::from(AllovrError::InvalidPda))
+        );
+    }
+}
+
+mod artwork_set_holder_test {
+    use ovr_program::state::{ArtworkCreator, ArtworkMetadata};
+    use solana_program::pubkey::Pubkey;
+    use solana_program_test::*;
+
+    #[tokio::test]
+    async fn test() {
+        let artist = Pubkey::new_unique();
+        let co_creator = Pubkey::new_unique();
+        let mut metadata = ArtworkMetadata {
+            artist,
+            symbol: "ART1".to_string(),
+            description: String::new(),
+            holder: artist,
+            offer_count: 0,
+            offered_to: None,
+            offer_price: None,
+            offer_currency: None,
+            payment_account: None,
+            max_editions: 0,
+            editions_printed: 0,
+            print: None,
+            content_uri: "ipfs://content".to_string(),
+            content_hash: [1; 32],
+            creators: vec![
+                ArtworkCreator {
+                    address: artist,
+                    share: 60,
+                },
+                ArtworkCreator {
+                    address: co_creator,
+                    share: 40,
+                },
+            ],
+            seller_fee_basis_points: 500,
+            primary_sale_happened: false,
+            dutch_auction: None,
+            swap: None,
+            bundle: None,
+            bump: 255,
+            mint_bump: 255,
+            escrow_bump: None,
+            fraction_mint_bump: None,
+        };
+
+        // moving between creators keeps the first sale primary
+        metadata.set_holder(co_creator);
+        assert_eq!(metadata.holder, co_creator);
+        assert!(!metadata.primary_sale_happened);
+
+        let collector = Pubkey::new_unique();
+        metadata.set_holder(collector);
+        assert_eq!(metadata.holder, collector);
+        assert!(metadata.primary_sale_happened);
+
+        // a creator buying it back does not make the next sale primary again
+        metadata.set_holder(artist);
+        assert!(metadata.primary_sale_happened);
+    }
+}